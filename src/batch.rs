@@ -0,0 +1,192 @@
+use crate::c1::C1Params;
+use peroxide::fuga::*;
+
+/// Polynomial coefficients `[a_2, ..., a_7]` of `V(φ) = Σ a_k φ^k`
+///
+/// Solves the same linear conditions the closed form was derived from:
+/// V(1) = -1, V'(φ_0) = V'(1) = 0, V''(φ_1n) = V''(φ_1p) = 0 and V'''(φ_2) = 0.
+pub fn c1_coefficients(p: &C1Params) -> [f64; 6] {
+    // d^n/dφ^n φ^k evaluated at x
+    let dpow = |n: usize, k: usize, x: f64| -> f64 {
+        if n > k {
+            return 0f64;
+        }
+        let falling: f64 = (0..n).map(|j| (k - j) as f64).product();
+        falling * x.powi((k - n) as i32)
+    };
+    let conditions = [
+        (0, 1f64),
+        (1, p.phi_0),
+        (1, 1f64),
+        (2, p.phi_1n),
+        (2, p.phi_1p),
+        (3, p.phi_2),
+    ];
+    let mut a = vec![0f64; 36];
+    for (row, &(n, x)) in conditions.iter().enumerate() {
+        for k in 2..8 {
+            a[row * 6 + k - 2] = dpow(n, k, x);
+        }
+    }
+    let a = matrix(a, 6, 6, Row);
+    let mut b = vec![0f64; 6];
+    b[0] = -1f64;
+    let sol = a.solve(&b, LU);
+    [sol[0], sol[1], sol[2], sol[3], sol[4], sol[5]]
+}
+
+/// Evaluates many C1 potentials on one shared grid
+///
+/// The grid powers φ^k are tabulated once, so a batch reduces to two dense products
+/// `V = A P` and `V' = A' P'` with the N×6 coefficient matrices `A`, `A'`.
+#[derive(Debug, Clone)]
+pub struct BatchEvaluator {
+    phi: Vec<f64>,
+    /// 6×G, row k holds φ^(k+2)
+    pow: Matrix,
+    /// 6×G, row k holds φ^(k+1)
+    pow_deriv: Matrix,
+}
+
+/// N×G results of a batch, row i belonging to the i-th parameter set
+#[derive(Debug, Clone)]
+pub struct BatchOutput {
+    pub v: Matrix,
+    pub dv: Matrix,
+}
+
+impl BatchEvaluator {
+    pub fn new(phi: &[f64]) -> Self {
+        let g = phi.len();
+        let mut pow = zeros_shape(6, g, Row);
+        let mut pow_deriv = zeros_shape(6, g, Row);
+        for (j, &x) in phi.iter().enumerate() {
+            for k in 0..6 {
+                pow[(k, j)] = x.powi(k as i32 + 2);
+                pow_deriv[(k, j)] = x.powi(k as i32 + 1);
+            }
+        }
+        Self {
+            phi: phi.to_vec(),
+            pow,
+            pow_deriv,
+        }
+    }
+
+    pub fn grid(&self) -> &[f64] {
+        &self.phi
+    }
+
+    pub fn eval(&self, params: &[C1Params]) -> BatchOutput {
+        let n = params.len();
+        let g = self.phi.len();
+        let mut a = zeros_shape(n, 6, Row);
+        let mut a_deriv = zeros_shape(n, 6, Row);
+        for (i, p) in params.iter().enumerate() {
+            for (k, c) in c1_coefficients(p).into_iter().enumerate() {
+                a[(i, k)] = c;
+                a_deriv[(i, k)] = (k + 2) as f64 * c;
+            }
+        }
+        let mut v = zeros_shape(n, g, Row);
+        let mut dv = zeros_shape(n, g, Row);
+        gemm(1f64, &a, &self.pow, 0f64, &mut v);
+        gemm(1f64, &a_deriv, &self.pow_deriv, 0f64, &mut dv);
+        BatchOutput { v, dv }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: [C1Params; 3] = [
+        C1Params {
+            phi_0: 0.4,
+            phi_1n: 0.9,
+            phi_1p: 0.1,
+            phi_2: 0.6,
+        },
+        C1Params {
+            phi_0: 0.7,
+            phi_1n: 0.85,
+            phi_1p: 0.2,
+            phi_2: 0.35,
+        },
+        C1Params {
+            phi_0: 0.3,
+            phi_1n: 0.8,
+            phi_1p: 0.15,
+            phi_2: 0.5,
+        },
+    ];
+
+    // The expanded closed form itself loses up to a few 1e-9 to cancellation
+    fn assert_close(a: f64, b: f64, what: &str) {
+        assert!(
+            (a - b).abs() <= 1e-8 * (1f64 + b.abs()),
+            "{}: {} vs {}",
+            what,
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn coefficients_satisfy_conditions() {
+        let dpow = |n: u32, k: usize, x: f64| -> f64 {
+            let falling: f64 = (0..n as usize).map(|j| (k - j) as f64).product();
+            falling * x.powi(k as i32 - n as i32)
+        };
+        for p in &PARAMS {
+            let c = c1_coefficients(p);
+            let at = |n: u32, x: f64| -> f64 { (0..6).map(|k| c[k] * dpow(n, k + 2, x)).sum() };
+            assert!((at(0, 1f64) + 1f64).abs() < 1e-12);
+            for (n, x) in [
+                (1, p.phi_0),
+                (1, 1f64),
+                (2, p.phi_1n),
+                (2, p.phi_1p),
+                (3, p.phi_2),
+            ] {
+                assert!(
+                    at(n, x).abs() < 1e-10,
+                    "d^{} V({}) = {} for {:?}",
+                    n,
+                    x,
+                    at(n, x),
+                    p
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn coefficients_reproduce_closed_form() {
+        for p in &PARAMS {
+            let c = c1_coefficients(p);
+            let (f, df) = (p.potential(), p.deriv());
+            for x in [0f64, 0.1, 0.25, 0.5, 0.75, 0.9, 1f64] {
+                let v: f64 = (0..6).map(|k| c[k] * x.powi(k as i32 + 2)).sum();
+                let dv: f64 = (0..6)
+                    .map(|k| (k + 2) as f64 * c[k] * x.powi(k as i32 + 1))
+                    .sum();
+                assert_close(v, f(x), &format!("V({}) of {:?}", x, p));
+                assert_close(dv, df(x), &format!("V'({}) of {:?}", x, p));
+            }
+        }
+    }
+
+    #[test]
+    fn batch_matches_closed_form() {
+        let phi: Vec<f64> = (0..51).map(|i| i as f64 / 50f64).collect();
+        let out = BatchEvaluator::new(&phi).eval(&PARAMS);
+        for (i, p) in PARAMS.iter().enumerate() {
+            let (f, df) = (p.potential(), p.deriv());
+            for (j, &x) in phi.iter().enumerate() {
+                assert_close(out.v[(i, j)], f(x), &format!("V({}) of {:?}", x, p));
+                assert_close(out.dv[(i, j)], df(x), &format!("V'({}) of {:?}", x, p));
+            }
+        }
+    }
+}
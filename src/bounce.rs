@@ -0,0 +1,418 @@
+use crate::batch::c1_coefficients;
+use crate::c1::C1Params;
+use std::f64::consts::PI;
+
+/// Settings of the shooting solver for O(d)-symmetric bounces
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BounceOptions {
+    /// Number of Euclidean dimensions: 4 for vacuum decay, 3 for thermal transitions (S_3)
+    pub dim: usize,
+    /// Relative precision of the field at the bubble centre
+    pub tol: f64,
+    /// Most shots of the overshoot/undershoot bisection
+    pub max_shots: usize,
+}
+
+impl Default for BounceOptions {
+    fn default() -> Self {
+        Self {
+            dim: 4,
+            tol: 1e-15,
+            max_shots: 200,
+        }
+    }
+}
+
+/// Bounce solution: the radial profile φ(r) and its Euclidean action
+#[derive(Debug, Clone)]
+pub struct Bounce {
+    pub r: Vec<f64>,
+    pub phi: Vec<f64>,
+    pub dphi: Vec<f64>,
+    /// Field at the centre of the bubble, where it is released from rest
+    pub phi_release: f64,
+    /// `S = Ω_{d-1} / d ∫ r^{d-1} φ'^2 dr`, equal to the full action by Derrick's theorem
+    pub action: f64,
+}
+
+/// Bounce of a C1 potential from its false vacuum to the true vacuum at φ = 1
+///
+/// The false vacuum is the one of `c1_false_vacuum`. The potential is evaluated from its
+/// polynomial coefficients, which is much faster than the closed form in the thousands of
+/// steps of each shot.
+pub fn c1_bounce(params: &C1Params, opts: BounceOptions) -> Result<Bounce, String> {
+    let a = c1_coefficients(params);
+    let v = |phi: f64| phi * phi * a.iter().rev().fold(0f64, |acc, &c| acc * phi + c);
+    let dv = |phi: f64| c1_dv(&a, phi);
+    solve_bounce(&v, &dv, false_vacuum(&a)?, 1f64, opts)
+}
+
+/// False vacuum of a C1 potential: φ = 0 unless V''(0) < 0, in which case it is the first
+/// minimum after it
+pub fn c1_false_vacuum(params: &C1Params) -> Result<f64, String> {
+    false_vacuum(&c1_coefficients(params))
+}
+
+/// V' from the coefficients `[a_2, ..., a_7]`
+fn c1_dv(a: &[f64; 6], phi: f64) -> f64 {
+    phi * a
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(0f64, |acc, (k, &c)| acc * phi + (k + 2) as f64 * c)
+}
+
+fn false_vacuum(a: &[f64; 6]) -> Result<f64, String> {
+    if a[0] >= 0f64 {
+        return Ok(0f64);
+    }
+    const N_SCAN: usize = 1000;
+    let i = (1..=N_SCAN)
+        .find(|&i| c1_dv(a, i as f64 / N_SCAN as f64) >= 0f64)
+        .ok_or("the potential has no false vacuum")?;
+    let (mut lo, mut hi) = ((i - 1) as f64 / N_SCAN as f64, i as f64 / N_SCAN as f64);
+    for _ in 0..60 {
+        let mid = 0.5 * (lo + hi);
+        if c1_dv(a, mid) < 0f64 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(hi)
+}
+
+/// Solve `φ'' + (d - 1) φ' / r = V'(φ)` with `φ'(0) = 0` and `φ(∞) = phi_false` by shooting
+///
+/// The release point is bisected between the point past the barrier where V returns to
+/// `V(phi_false)` (undershoots) and the first minimum after it (overshoots), which is `phi_true`
+/// unless the potential has an intermediate minimum below the false vacuum. The profile is cut where the last
+/// undershooting trajectory turns back, which is within `tol` of the false vacuum in the
+/// thick-wall regime; deep thin-wall bounces need a release point closer to `phi_true` than
+/// f64 resolves and fail with an error.
+pub fn solve_bounce(
+    v: &dyn Fn(f64) -> f64,
+    dv: &dyn Fn(f64) -> f64,
+    phi_false: f64,
+    phi_true: f64,
+    opts: BounceOptions,
+) -> Result<Bounce, String> {
+    if opts.dim < 2 {
+        return Err(format!(
+            "bounces need at least 2 dimensions, got {}",
+            opts.dim
+        ));
+    }
+    let bracket = Bracket::new(v, dv, phi_false, phi_true)?;
+    let Bracket {
+        sign,
+        x_true,
+        x_exit,
+        x_target,
+        ..
+    } = bracket;
+    let shooter = Shooter {
+        dv,
+        phi_false,
+        sign,
+        dim: opts.dim,
+        length: bracket.length(),
+    };
+
+    let (mut lo, mut hi) = (x_exit, x_target);
+    let mut n_shots = 0;
+    while hi - lo > opts.tol * x_true {
+        if n_shots == opts.max_shots {
+            return Err(format!("no bounce found after {} shots", n_shots));
+        }
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        match shooter.shoot(mid, false).0 {
+            Shot::Undershoot => lo = mid,
+            Shot::Overshoot => hi = mid,
+        }
+        n_shots += 1;
+    }
+    if lo == x_exit {
+        return Err(
+            "every release point overshoots (thin-wall limit beyond f64 precision)".to_string(),
+        );
+    }
+
+    let (_, path) = shooter.shoot(lo, true);
+    let last = path.len() - 1;
+    if path[last].1[0] > 1e-3 * x_true {
+        return Err(format!(
+            "the bounce ends at {:.3e} from the false vacuum (thin-wall limit beyond f64 precision)",
+            path[last].1[0]
+        ));
+    }
+    Ok(Bounce {
+        r: path.iter().map(|(r, _)| *r).collect(),
+        phi: path.iter().map(|(_, y)| phi_false + sign * y[0]).collect(),
+        dphi: path.iter().map(|(_, y)| sign * y[1]).collect(),
+        phi_release: phi_false + sign * lo,
+        action: sphere_area(opts.dim) / opts.dim as f64 * path[last].1[2],
+    })
+}
+
+/// Tunnelling direction and the release points bracketing a bounce, in terms of the
+/// distance `x = |φ - phi_false|` from the false vacuum
+pub(crate) struct Bracket {
+    pub sign: f64,
+    pub x_true: f64,
+    /// Top of the barrier
+    pub x_top: f64,
+    /// First return to V(phi_false) past the barrier
+    pub x_exit: f64,
+    /// First minimum past the exit, which is `x_true` without intermediate minima
+    pub x_target: f64,
+    /// Largest |V - V(phi_false)| between the vacua
+    pub v_scale: f64,
+}
+
+impl Bracket {
+    pub fn new(
+        v: &dyn Fn(f64) -> f64,
+        dv: &dyn Fn(f64) -> f64,
+        phi_false: f64,
+        phi_true: f64,
+    ) -> Result<Self, String> {
+        let sign = (phi_true - phi_false).signum();
+        let x_true = (phi_true - phi_false).abs();
+        if x_true == 0f64 || !x_true.is_finite() {
+            return Err("the false and true vacuum coincide".to_string());
+        }
+        let v_false = v(phi_false);
+        if v(phi_true) >= v_false {
+            return Err(
+                "the false vacuum is not metastable (V(phi_true) >= V(phi_false))".to_string(),
+            );
+        }
+
+        const N_SCAN: usize = 1000;
+        let xs: Vec<f64> = (0..=N_SCAN)
+            .map(|i| x_true * i as f64 / N_SCAN as f64)
+            .collect();
+        let vs: Vec<f64> = xs
+            .iter()
+            .map(|&x| v(phi_false + sign * x) - v_false)
+            .collect();
+        let i_top = (0..=N_SCAN).fold(0, |i, j| if vs[j] > vs[i] { j } else { i });
+        if vs[i_top] <= 0f64 {
+            return Err("there is no barrier between the vacua".to_string());
+        }
+        let dv_x = |x: f64| sign * dv(phi_false + sign * x);
+        // Stationary point of V in [lo, hi], where V' goes from negative to positive if `rising`
+        let bisect = |mut lo: f64, mut hi: f64, rising: bool| {
+            for _ in 0..60 {
+                let mid = 0.5 * (lo + hi);
+                if (dv_x(mid) < 0f64) == rising {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        };
+        let x_top = bisect(xs[i_top - 1], xs[(i_top + 1).min(N_SCAN)], false);
+
+        // Exit point: first return to V(phi_false) past the top of the barrier
+        let i_exit = (i_top..=N_SCAN)
+            .find(|&i| vs[i] < 0f64)
+            .ok_or("V does not drop below V(phi_false) past the barrier")?;
+        let (mut lo, mut hi) = (xs[i_exit - 1], xs[i_exit]);
+        for _ in 0..60 {
+            let mid = 0.5 * (lo + hi);
+            if v(phi_false + sign * mid) >= v_false {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let x_exit = hi;
+
+        let x_target = match (i_exit..N_SCAN).find(|&i| vs[i + 1] >= vs[i]) {
+            Some(i) => bisect(xs[i - 1], xs[i + 1], true),
+            None => x_true,
+        };
+
+        Ok(Self {
+            sign,
+            x_true,
+            x_top,
+            x_exit,
+            x_target,
+            v_scale: vs.iter().fold(0f64, |a, &b| a.max(b.abs())),
+        })
+    }
+
+    /// Typical length of the problem, setting the first step and the largest radius
+    pub fn length(&self) -> f64 {
+        self.x_true / self.v_scale.sqrt()
+    }
+}
+
+/// Area `2 π^{d/2} / Γ(d/2)` of the unit sphere in d dimensions
+pub fn sphere_area(dim: usize) -> f64 {
+    // Γ(d/2) from Γ(1) = 1 or Γ(1/2) = √π
+    let (mut gamma, mut a) = if dim.is_multiple_of(2) {
+        (1f64, 1f64)
+    } else {
+        (PI.sqrt(), 0.5)
+    };
+    while a < dim as f64 / 2f64 {
+        gamma *= a;
+        a += 1f64;
+    }
+    2f64 * PI.powf(dim as f64 / 2f64) / gamma
+}
+
+enum Shot {
+    /// The field turns back before reaching the false vacuum
+    Undershoot,
+    /// The field passes the false vacuum
+    Overshoot,
+}
+
+/// State `[x, x', ∫ r^{d-1} x'^2 dr]` with `x = |φ - phi_false|` along the tunnelling direction
+type State = [f64; 3];
+
+struct Shooter<'a> {
+    dv: &'a dyn Fn(f64) -> f64,
+    phi_false: f64,
+    sign: f64,
+    dim: usize,
+    /// Typical length of the problem, setting the first step and the largest radius
+    length: f64,
+}
+
+impl Shooter<'_> {
+    fn rhs(&self, r: f64, y: &State) -> State {
+        let force = self.sign * (self.dv)(self.phi_false + self.sign * y[0]);
+        let d1 = (self.dim - 1) as f64;
+        [
+            y[1],
+            force - d1 / r * y[1],
+            r.powi(self.dim as i32 - 1) * y[1] * y[1],
+        ]
+    }
+
+    /// Integrate from rest at `x0` until the field over- or undershoots, recording the path if asked
+    fn shoot(&self, x0: f64, record: bool) -> (Shot, Vec<(f64, State)>) {
+        // Series solution x = x0 + a r^2 / (2d) away from the singular point r = 0
+        let a = self.sign * (self.dv)(self.phi_false + self.sign * x0);
+        let d = self.dim as f64;
+        let r0 = 1e-6 * self.length;
+        let mut y = [
+            x0 + a * r0 * r0 / (2f64 * d),
+            a * r0 / d,
+            (a / d).powi(2) * r0.powi(self.dim as i32 + 2) / (d + 2f64),
+        ];
+        let mut r = r0;
+        let mut h = 1e-4 * self.length;
+        let r_max = 1e4 * self.length;
+        let mut path = vec![(0f64, [x0, 0f64, 0f64])];
+        if record {
+            path.push((r, y));
+        }
+
+        let shot = loop {
+            let (y_new, err) = dormand_prince(|r, y| self.rhs(r, y), r, &y, h);
+            if err > 1f64 {
+                h *= (0.9 * err.powf(-0.2)).max(0.2);
+                continue;
+            }
+            r += h;
+            y = y_new;
+            h *= (0.9 * err.powf(-0.2)).min(5f64);
+            if y[0] < 0f64 {
+                break Shot::Overshoot;
+            }
+            if record {
+                path.push((r, y));
+            }
+            if y[1] > 0f64 || r > r_max {
+                break Shot::Undershoot;
+            }
+        };
+        if !record || path.len() == 1 {
+            path.push((r, y));
+        }
+        (shot, path)
+    }
+}
+
+/// One Dormand–Prince 5(4) step; returns the new state and the error relative to the tolerance
+pub(crate) fn dormand_prince<const N: usize, F: Fn(f64, &[f64; N]) -> [f64; N]>(
+    f: F,
+    r: f64,
+    y: &[f64; N],
+    h: f64,
+) -> ([f64; N], f64) {
+    const ATOL: f64 = 1e-14;
+    const RTOL: f64 = 1e-11;
+    let add = |k: &[(&[f64; N], f64)]| -> [f64; N] {
+        let mut out = *y;
+        for (ki, c) in k {
+            for j in 0..N {
+                out[j] += h * c * ki[j];
+            }
+        }
+        out
+    };
+    let k1 = f(r, y);
+    let k2 = f(r + h / 5f64, &add(&[(&k1, 1f64 / 5f64)]));
+    let k3 = f(
+        r + 3f64 * h / 10f64,
+        &add(&[(&k1, 3f64 / 40f64), (&k2, 9f64 / 40f64)]),
+    );
+    let k4 = f(
+        r + 4f64 * h / 5f64,
+        &add(&[
+            (&k1, 44f64 / 45f64),
+            (&k2, -56f64 / 15f64),
+            (&k3, 32f64 / 9f64),
+        ]),
+    );
+    let k5 = f(
+        r + 8f64 * h / 9f64,
+        &add(&[
+            (&k1, 19372f64 / 6561f64),
+            (&k2, -25360f64 / 2187f64),
+            (&k3, 64448f64 / 6561f64),
+            (&k4, -212f64 / 729f64),
+        ]),
+    );
+    let k6 = f(
+        r + h,
+        &add(&[
+            (&k1, 9017f64 / 3168f64),
+            (&k2, -355f64 / 33f64),
+            (&k3, 46732f64 / 5247f64),
+            (&k4, 49f64 / 176f64),
+            (&k5, -5103f64 / 18656f64),
+        ]),
+    );
+    let y_new = add(&[
+        (&k1, 35f64 / 384f64),
+        (&k3, 500f64 / 1113f64),
+        (&k4, 125f64 / 192f64),
+        (&k5, -2187f64 / 6784f64),
+        (&k6, 11f64 / 84f64),
+    ]);
+    let k7 = f(r + h, &y_new);
+    let mut err = 0f64;
+    for j in 0..N {
+        let e = h
+            * (71f64 / 57600f64 * k1[j] - 71f64 / 16695f64 * k3[j] + 71f64 / 1920f64 * k4[j]
+                - 17253f64 / 339200f64 * k5[j]
+                + 22f64 / 525f64 * k6[j]
+                - 1f64 / 40f64 * k7[j]);
+        let scale = ATOL + RTOL * y[j].abs().max(y_new[j].abs());
+        err = err.max(e.abs() / scale);
+    }
+    (y_new, err)
+}
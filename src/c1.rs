@@ -1,2400 +1,2584 @@
+use crate::dual::Dual;
+use peroxide::fuga::*;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Scalar types the expanded C1 expressions can be evaluated on (`f64`, peroxide's `AD`, ...)
+pub trait C1Scalar:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + PowOps
+{
+}
+
+impl<T> C1Scalar for T where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + PowOps
+{
+}
+
+/// The four C1 shape parameters
+///
+/// Valid members satisfy `phi_1p < min(phi_0, phi_2) <= max(phi_0, phi_2) < phi_1n` in (0, 1);
+/// `phi_0 > phi_2` is the swapped branch of the sort-and-swap construction.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct C1Params {
+    pub phi_0: f64,
+    pub phi_1n: f64,
+    pub phi_1p: f64,
+    pub phi_2: f64,
+}
+
+impl C1Params {
+    pub fn new(phi_0: f64, phi_1n: f64, phi_1p: f64, phi_2: f64) -> Self {
+        Self {
+            phi_0,
+            phi_1n,
+            phi_1p,
+            phi_2,
+        }
+    }
+
+    /// Map four ascending values in (0, 1) to parameters, swapping phi_0 and phi_2 if `swap`
+    pub fn from_sorted(us: [f64; 4], swap: bool) -> Self {
+        let (phi_1p, mut phi_0, mut phi_2, phi_1n) = (us[0], us[1], us[2], us[3]);
+        if swap {
+            std::mem::swap(&mut phi_0, &mut phi_2);
+        }
+        Self::new(phi_0, phi_1n, phi_1p, phi_2)
+    }
+
+    /// Inverse of `from_sorted`
+    pub fn to_sorted(&self) -> ([f64; 4], bool) {
+        let swap = self.is_swapped();
+        let (lo, hi) = if swap {
+            (self.phi_2, self.phi_0)
+        } else {
+            (self.phi_0, self.phi_2)
+        };
+        ([self.phi_1p, lo, hi, self.phi_1n], swap)
+    }
+
+    /// Whether the parameters satisfy the ordering constraints of the family
+    pub fn is_ordered(&self) -> bool {
+        let (lo, hi) = (self.phi_0.min(self.phi_2), self.phi_0.max(self.phi_2));
+        0f64 < self.phi_1p && self.phi_1p < lo && hi < self.phi_1n && self.phi_1n < 1f64
+    }
+
+    pub fn is_swapped(&self) -> bool {
+        self.phi_0 > self.phi_2
+    }
+
+    pub fn to_array(&self) -> [f64; 4] {
+        [self.phi_0, self.phi_1n, self.phi_1p, self.phi_2]
+    }
+
+    pub fn from_array(ps: [f64; 4]) -> Self {
+        Self::new(ps[0], ps[1], ps[2], ps[3])
+    }
+
+    pub fn potential(&self) -> Box<dyn Fn(f64) -> f64> {
+        c1_potential(self.phi_0, self.phi_1n, self.phi_1p, self.phi_2)
+    }
+
+    pub fn deriv(&self) -> Box<dyn Fn(f64) -> f64> {
+        c1_deriv(self.phi_0, self.phi_1n, self.phi_1p, self.phi_2)
+    }
+}
+
+/// Names one of the four C1 parameters
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum C1Param {
+    Phi0,
+    Phi1n,
+    Phi1p,
+    Phi2,
+}
+
+impl C1Param {
+    pub const ALL: [C1Param; 4] = [C1Param::Phi0, C1Param::Phi1n, C1Param::Phi1p, C1Param::Phi2];
+
+    /// Position in `C1Params::to_array`
+    pub fn index(&self) -> usize {
+        match self {
+            C1Param::Phi0 => 0,
+            C1Param::Phi1n => 1,
+            C1Param::Phi1p => 2,
+            C1Param::Phi2 => 3,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            C1Param::Phi0 => "phi_0",
+            C1Param::Phi1n => "phi_1n",
+            C1Param::Phi1p => "phi_1p",
+            C1Param::Phi2 => "phi_2",
+        }
+    }
+}
+
+impl std::fmt::Display for C1Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for C1Param {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        C1Param::ALL
+            .into_iter()
+            .find(|p| p.name() == s)
+            .ok_or_else(|| {
+                format!(
+                    "unknown parameter '{}' (expected phi_0, phi_1n, phi_1p or phi_2)",
+                    s
+                )
+            })
+    }
+}
+
 pub fn c1_potential(phi_0: f64, phi_1n: f64, phi_1p: f64, phi_2: f64) -> Box<dyn Fn(f64) -> f64> {
+    Box::new(move |phi: f64| c1_potential_expr(phi, phi_0, phi_1n, phi_1p, phi_2))
+}
+
+pub fn c1_deriv(phi_0: f64, phi_1n: f64, phi_1p: f64, phi_2: f64) -> Box<dyn Fn(f64) -> f64> {
+    Box::new(move |phi: f64| c1_deriv_expr(phi, phi_0, phi_1n, phi_1p, phi_2))
+}
+
+/// Exact gradient of V(φ) w.r.t. (phi_0, phi_1n, phi_1p, phi_2) via forward-mode AD
+pub fn c1_potential_grad(
+    phi_0: f64,
+    phi_1n: f64,
+    phi_1p: f64,
+    phi_2: f64,
+) -> Box<dyn Fn(f64) -> [f64; 4]> {
     Box::new(move |phi: f64| {
-        phi.powi(2)
-            * (phi.powi(5)
-                * (-720f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                    + 1440f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                    - 720f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
-                    + 240f64 * phi_0.powi(3) * phi_1n.powi(2)
-                    + 1440f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                    - 2160f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                    - 720f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
-                    + 240f64 * phi_0.powi(3) * phi_1n * phi_1p
-                    + 1080f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
-                    - 180f64 * phi_0.powi(3) * phi_1n
-                    - 720f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
-                    + 240f64 * phi_0.powi(3) * phi_1p.powi(2)
-                    + 1080f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
-                    - 180f64 * phi_0.powi(3) * phi_1p
-                    - 720f64 * phi_0.powi(3) * phi_2.powi(2)
-                    + 360f64 * phi_0.powi(3) * phi_2
-                    + 900f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
-                    - 1800f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                    + 900f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                    - 300f64 * phi_0.powi(2) * phi_1n.powi(3)
-                    + 900f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
-                    - 1800f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                    - 720f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                    + 2340f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                    - 300f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
-                    - 720f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
-                    + 240f64 * phi_0.powi(2) * phi_1n.powi(2)
-                    - 1800f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                    + 2340f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                    - 300f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
-                    + 3600f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                    - 2160f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                    - 720f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
-                    + 240f64 * phi_0.powi(2) * phi_1n * phi_1p
-                    - 1800f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
-                    + 1080f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
-                    + 900f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                    - 300f64 * phi_0.powi(2) * phi_1p.powi(3)
-                    - 720f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
-                    + 240f64 * phi_0.powi(2) * phi_1p.powi(2)
-                    - 1800f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
-                    + 1080f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
-                    + 1200f64 * phi_0.powi(2) * phi_2.powi(3)
-                    - 720f64 * phi_0.powi(2) * phi_2.powi(2)
-                    - 1200f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                    + 900f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
-                    + 3600f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                    - 1800f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
-                    - 1800f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
-                    + 900f64 * phi_0 * phi_1n.powi(3) * phi_2
-                    + 900f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
-                    + 3600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                    - 1800f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                    - 720f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
-                    - 4800f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                    - 1800f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                    + 2340f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
-                    + 2400f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
-                    - 720f64 * phi_0 * phi_1n.powi(2) * phi_2
-                    + 3600f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                    - 1800f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
-                    - 4800f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                    - 1800f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                    + 2340f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
-                    + 6000f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                    - 2160f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
-                    - 720f64 * phi_0 * phi_1n * phi_1p * phi_2
-                    - 1800f64 * phi_0 * phi_1n * phi_2.powi(3)
-                    + 1080f64 * phi_0 * phi_1n * phi_2.powi(2)
-                    - 1800f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
-                    + 900f64 * phi_0 * phi_1p.powi(3) * phi_2
-                    + 2400f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
-                    - 720f64 * phi_0 * phi_1p.powi(2) * phi_2
-                    - 1800f64 * phi_0 * phi_1p * phi_2.powi(3)
-                    + 1080f64 * phi_0 * phi_1p * phi_2.powi(2)
-                    + 3600f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                    - 1200f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                    - 5400f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                    + 900f64 * phi_1n.powi(3) * phi_1p.powi(2)
-                    + 3600f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                    - 1800f64 * phi_1n.powi(3) * phi_1p * phi_2
-                    - 5400f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                    + 900f64 * phi_1n.powi(2) * phi_1p.powi(3)
-                    + 7200f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                    + 3600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                    - 1800f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                    - 720f64 * phi_1n.powi(2) * phi_1p.powi(2)
-                    - 4800f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                    + 1440f64 * phi_1n.powi(2) * phi_1p * phi_2
-                    + 3600f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                    - 1800f64 * phi_1n * phi_1p.powi(3) * phi_2
-                    - 4800f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                    + 1440f64 * phi_1n * phi_1p.powi(2) * phi_2
-                    + 3600f64 * phi_1n * phi_1p * phi_2.powi(3)
-                    - 2160f64 * phi_1n * phi_1p * phi_2.powi(2))
-                + phi.powi(4)
-                    * (840f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 1680f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 840f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2
-                        - 280f64 * phi_0.powi(4) * phi_1n.powi(2)
-                        - 1680f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 2520f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
-                        + 840f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2
-                        - 280f64 * phi_0.powi(4) * phi_1n * phi_1p
-                        - 1260f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
-                        + 210f64 * phi_0.powi(4) * phi_1n
-                        + 840f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2
-                        - 280f64 * phi_0.powi(4) * phi_1p.powi(2)
-                        - 1260f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
-                        + 210f64 * phi_0.powi(4) * phi_1p
-                        + 840f64 * phi_0.powi(4) * phi_2.powi(2)
-                        - 420f64 * phi_0.powi(4) * phi_2
-                        + 840f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 1680f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 840f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
-                        - 280f64 * phi_0.powi(3) * phi_1n.powi(2)
-                        - 1680f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 2520f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                        + 840f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
-                        - 280f64 * phi_0.powi(3) * phi_1n * phi_1p
-                        - 1260f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
-                        + 210f64 * phi_0.powi(3) * phi_1n
-                        + 840f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
-                        - 280f64 * phi_0.powi(3) * phi_1p.powi(2)
-                        - 1260f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
-                        + 210f64 * phi_0.powi(3) * phi_1p
-                        + 840f64 * phi_0.powi(3) * phi_2.powi(2)
-                        - 420f64 * phi_0.powi(3) * phi_2
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
-                        + 2520f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
-                        + 420f64 * phi_0.powi(2) * phi_1n.powi(4)
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 2520f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                        + 420f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
-                        + 2520f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 1680f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 840f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
-                        - 280f64 * phi_0.powi(2) * phi_1n.powi(2)
-                        + 2520f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
-                        - 1260f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                        + 420f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
-                        - 1680f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                        - 6300f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                        + 2520f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                        + 840f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
-                        - 280f64 * phi_0.powi(2) * phi_1n * phi_1p
-                        + 3150f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
-                        - 1260f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
-                        - 1260f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
-                        + 420f64 * phi_0.powi(2) * phi_1p.powi(4)
-                        + 840f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
-                        - 280f64 * phi_0.powi(2) * phi_1p.powi(2)
-                        + 3150f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
-                        - 1260f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
-                        - 2100f64 * phi_0.powi(2) * phi_2.powi(4)
-                        + 840f64 * phi_0.powi(2) * phi_2.powi(2)
-                        + 1680f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 1260f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
-                        - 5040f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
-                        + 2520f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
-                        - 1260f64 * phi_0 * phi_1n.powi(4) * phi_2
-                        + 1680f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
-                        - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 5040f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
-                        - 1260f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
-                        - 5040f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 2520f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 2520f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 1260f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 840f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
-                        + 8400f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        - 1680f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
-                        - 4200f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
-                        + 840f64 * phi_0 * phi_1n.powi(2) * phi_2
-                        - 5040f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
-                        + 2520f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 1260f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
-                        + 8400f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        - 1680f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
-                        - 10500f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                        + 2520f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
-                        + 840f64 * phi_0 * phi_1n * phi_1p * phi_2
-                        + 3150f64 * phi_0 * phi_1n * phi_2.powi(4)
-                        - 1260f64 * phi_0 * phi_1n * phi_2.powi(2)
-                        + 2520f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
-                        - 1260f64 * phi_0 * phi_1p.powi(4) * phi_2
-                        - 4200f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
-                        + 840f64 * phi_0 * phi_1p.powi(2) * phi_2
-                        + 3150f64 * phi_0 * phi_1p * phi_2.powi(4)
-                        - 1260f64 * phi_0 * phi_1p * phi_2.powi(2)
-                        - 5040f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                        + 1680f64 * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 7560f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 1260f64 * phi_1n.powi(4) * phi_1p.powi(2)
-                        - 5040f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 2520f64 * phi_1n.powi(4) * phi_1p * phi_2
-                        - 5040f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                        + 1680f64 * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 7560f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 1260f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 5040f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 2520f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 7560f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 1260f64 * phi_1n.powi(2) * phi_1p.powi(4)
-                        - 5040f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 2520f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 12600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        + 840f64 * phi_1n.powi(2) * phi_1p.powi(2)
-                        + 8400f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        - 1680f64 * phi_1n.powi(2) * phi_1p * phi_2
-                        - 5040f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 2520f64 * phi_1n * phi_1p.powi(4) * phi_2
-                        + 8400f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        - 1680f64 * phi_1n * phi_1p.powi(2) * phi_2
-                        - 6300f64 * phi_1n * phi_1p * phi_2.powi(4)
-                        + 2520f64 * phi_1n * phi_1p * phi_2.powi(2))
-                + phi.powi(3)
-                    * (-1260f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2)
-                        + 2520f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2
-                        - 1260f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
-                        + 420f64 * phi_0.powi(4) * phi_1n.powi(3)
-                        - 1260f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3)
-                        + 2520f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 1260f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 420f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
-                        + 2520f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2
-                        - 1260f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 420f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
-                        - 5040f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 2520f64 * phi_0.powi(4) * phi_1n * phi_2.powi(3)
-                        - 252f64 * phi_0.powi(4) * phi_1n
-                        - 1260f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
-                        + 420f64 * phi_0.powi(4) * phi_1p.powi(3)
-                        + 2520f64 * phi_0.powi(4) * phi_1p * phi_2.powi(3)
-                        - 252f64 * phi_0.powi(4) * phi_1p
-                        - 1680f64 * phi_0.powi(4) * phi_2.powi(3)
-                        + 504f64 * phi_0.powi(4) * phi_2
-                        + 1512f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2)
-                        - 3024f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2
-                        + 1512f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
-                        - 504f64 * phi_0.powi(3) * phi_1n.powi(4)
-                        + 1512f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 3024f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 1260f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2)
-                        + 4032f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
-                        - 504f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
-                        - 1260f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
-                        + 420f64 * phi_0.powi(3) * phi_1n.powi(3)
-                        + 1512f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4)
-                        - 3024f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 1260f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3)
-                        + 4032f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 504f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 1260f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 420f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                        - 3024f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2
-                        + 4032f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
-                        - 504f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
-                        - 1260f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 420f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                        + 7560f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
-                        - 5040f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                        - 3780f64 * phi_0.powi(3) * phi_1n * phi_2.powi(4)
-                        + 2520f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
-                        + 1512f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
-                        - 504f64 * phi_0.powi(3) * phi_1p.powi(4)
-                        - 1260f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
-                        + 420f64 * phi_0.powi(3) * phi_1p.powi(3)
-                        - 3780f64 * phi_0.powi(3) * phi_1p * phi_2.powi(4)
-                        + 2520f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
-                        + 2520f64 * phi_0.powi(3) * phi_2.powi(4)
-                        - 1680f64 * phi_0.powi(3) * phi_2.powi(3)
-                        + 1512f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
-                        - 3024f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
-                        + 1512f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
-                        - 504f64 * phi_0.powi(2) * phi_1n.powi(4)
-                        + 1512f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 3024f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
-                        + 4032f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                        - 504f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                        + 420f64 * phi_0.powi(2) * phi_1n.powi(3)
-                        + 1512f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
-                        - 3024f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
-                        + 4032f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 504f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 420f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
-                        - 3024f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
-                        + 4032f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                        - 504f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
-                        - 1260f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 420f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
-                        + 7560f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                        - 5040f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                        - 3780f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
-                        + 2520f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
-                        + 1512f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
-                        - 504f64 * phi_0.powi(2) * phi_1p.powi(4)
-                        - 1260f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                        + 420f64 * phi_0.powi(2) * phi_1p.powi(3)
-                        - 3780f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
-                        + 2520f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
-                        + 2520f64 * phi_0.powi(2) * phi_2.powi(4)
-                        - 1680f64 * phi_0.powi(2) * phi_2.powi(3)
-                        - 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
-                        + 1512f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
-                        + 10080f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        - 3024f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
-                        - 5040f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
-                        + 1512f64 * phi_0 * phi_1n.powi(4) * phi_2
-                        + 1512f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 10080f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 3024f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
-                        - 12600f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        - 5040f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        + 4032f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
-                        + 6300f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
-                        - 1260f64 * phi_0 * phi_1n.powi(3) * phi_2
-                        + 1512f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
-                        + 10080f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 3024f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 1260f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
-                        - 12600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 5040f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 4032f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 6300f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        - 1260f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
-                        + 10080f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        - 3024f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
-                        - 12600f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        - 5040f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        + 4032f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
-                        + 6300f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        - 1260f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
-                        + 7560f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                        - 5040f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                        - 3780f64 * phi_0 * phi_1n * phi_2.powi(4)
-                        + 2520f64 * phi_0 * phi_1n * phi_2.powi(3)
-                        - 5040f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
-                        + 1512f64 * phi_0 * phi_1p.powi(4) * phi_2
-                        + 6300f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
-                        - 1260f64 * phi_0 * phi_1p.powi(3) * phi_2
-                        - 3780f64 * phi_0 * phi_1p * phi_2.powi(4)
-                        + 2520f64 * phi_0 * phi_1p * phi_2.powi(3)
-                        + 7560f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                        - 2520f64 * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 15120f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 1512f64 * phi_1n.powi(4) * phi_1p.powi(2)
-                        + 10080f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        - 3024f64 * phi_1n.powi(4) * phi_1p * phi_2
-                        - 15120f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 1512f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 18900f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                        + 10080f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 3024f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 1260f64 * phi_1n.powi(3) * phi_1p.powi(2)
-                        - 12600f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        + 2520f64 * phi_1n.powi(3) * phi_1p * phi_2
-                        - 15120f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                        + 1512f64 * phi_1n.powi(2) * phi_1p.powi(4)
-                        + 18900f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                        + 10080f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 3024f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 1260f64 * phi_1n.powi(2) * phi_1p.powi(3)
-                        - 12600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        + 2520f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 10080f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        - 3024f64 * phi_1n * phi_1p.powi(4) * phi_2
-                        - 12600f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        + 2520f64 * phi_1n * phi_1p.powi(3) * phi_2
-                        + 7560f64 * phi_1n * phi_1p * phi_2.powi(4)
-                        - 5040f64 * phi_1n * phi_1p * phi_2.powi(3))
-                + phi.powi(2)
-                    * (2100f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 3150f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2.powi(2)
-                        - 525f64 * phi_0.powi(4) * phi_1n.powi(3)
-                        - 6300f64
-                            * phi_0.powi(4)
-                            * phi_1n.powi(2)
-                            * phi_1p.powi(2)
-                            * phi_2.powi(2)
-                        + 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        + 3150f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        - 525f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
-                        - 4200f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(3)
-                        + 420f64 * phi_0.powi(4) * phi_1n.powi(2)
-                        - 6300f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 8400f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        + 3150f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        - 525f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
-                        - 4200f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 420f64 * phi_0.powi(4) * phi_1n * phi_1p
-                        + 3150f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 525f64 * phi_0.powi(4) * phi_1p.powi(3)
-                        - 4200f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 420f64 * phi_0.powi(4) * phi_1p.powi(2)
-                        + 2100f64 * phi_0.powi(4) * phi_2.powi(3)
-                        - 1260f64 * phi_0.powi(4) * phi_2.powi(2)
-                        - 2520f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        - 3780f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2.powi(2)
-                        + 630f64 * phi_0.powi(3) * phi_1n.powi(4)
-                        - 2520f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 2100f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 7560f64
-                            * phi_0.powi(3)
-                            * phi_1n.powi(3)
-                            * phi_1p.powi(2)
-                            * phi_2.powi(2)
-                        - 10080f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 630f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
-                        + 3150f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2.powi(2)
-                        - 525f64 * phi_0.powi(3) * phi_1n.powi(3)
-                        + 7560f64
-                            * phi_0.powi(3)
-                            * phi_1n.powi(2)
-                            * phi_1p.powi(3)
-                            * phi_2.powi(2)
-                        - 10080f64
-                            * phi_0.powi(3)
-                            * phi_1n.powi(2)
-                            * phi_1p.powi(2)
-                            * phi_2.powi(2)
-                        + 630f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        + 3150f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        - 525f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                        + 6300f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(4)
-                        - 4200f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
-                        + 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        - 10080f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 630f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
-                        - 12600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 8400f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        + 3150f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        - 525f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                        + 6300f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
-                        - 4200f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                        - 3780f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 630f64 * phi_0.powi(3) * phi_1p.powi(4)
-                        + 3150f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 525f64 * phi_0.powi(3) * phi_1p.powi(3)
-                        + 6300f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 4200f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 3150f64 * phi_0.powi(3) * phi_2.powi(4)
-                        + 2100f64 * phi_0.powi(3) * phi_2.powi(3)
-                        + 3150f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 2520f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        + 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 6300f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(3)
-                        - 3780f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
-                        - 2520f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 2100f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 12600f64
-                            * phi_0.powi(2)
-                            * phi_1n.powi(3)
-                            * phi_1p.powi(2)
-                            * phi_2.powi(3)
-                        + 7560f64
-                            * phi_0.powi(2)
-                            * phi_1n.powi(3)
-                            * phi_1p.powi(2)
-                            * phi_2.powi(2)
-                        + 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        + 6300f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        - 10080f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 7875f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(4)
-                        + 3150f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
-                        - 12600f64
-                            * phi_0.powi(2)
-                            * phi_1n.powi(2)
-                            * phi_1p.powi(3)
-                            * phi_2.powi(3)
-                        + 7560f64
-                            * phi_0.powi(2)
-                            * phi_1n.powi(2)
-                            * phi_1p.powi(3)
-                            * phi_2.powi(2)
-                        + 15750f64
-                            * phi_0.powi(2)
-                            * phi_1n.powi(2)
-                            * phi_1p.powi(2)
-                            * phi_2.powi(4)
-                        + 6300f64
-                            * phi_0.powi(2)
-                            * phi_1n.powi(2)
-                            * phi_1p.powi(2)
-                            * phi_2.powi(3)
-                        - 10080f64
-                            * phi_0.powi(2)
-                            * phi_1n.powi(2)
-                            * phi_1p.powi(2)
-                            * phi_2.powi(2)
-                        - 20475f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 8400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        + 3150f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        + 6300f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(4)
-                        - 4200f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
-                        - 12600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        + 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 15750f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        + 6300f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        - 10080f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 20475f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 8400f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        + 3150f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        + 6300f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                        - 4200f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 6300f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                        - 3780f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 7875f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                        + 3150f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 6300f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 4200f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 3150f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        + 7560f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 6300f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
-                        - 3780f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
-                        - 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 2100f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 12600f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 7560f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 15750f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        + 6300f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        - 10080f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 7875f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
-                        + 3150f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
-                        - 12600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 7560f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 15750f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        + 6300f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 10080f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 20475f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 8400f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        + 3150f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        + 6300f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
-                        - 4200f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
-                        - 12600f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        + 7560f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 15750f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        + 6300f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        - 10080f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 20475f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 8400f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        + 3150f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        + 6300f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                        - 4200f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                        + 6300f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
-                        - 3780f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
-                        - 7875f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
-                        + 3150f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
-                        + 6300f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
-                        - 4200f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
-                        - 18900f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 3150f64 * phi_1n.powi(4) * phi_1p.powi(4)
-                        + 25200f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 2520f64 * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 12600f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        + 7560f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 25200f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
-                        - 2520f64 * phi_1n.powi(3) * phi_1p.powi(4)
-                        - 31500f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
-                        + 2100f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 12600f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 7560f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 15750f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        - 6300f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 12600f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 7560f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 15750f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 6300f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 12600f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 8400f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        - 12600f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        + 7560f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 15750f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        - 6300f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 12600f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 8400f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3))
-                + 140f64
-                    * phi
-                    * phi_2
-                    * (-60f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 90f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 30f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
-                        + 15f64 * phi_0.powi(4) * phi_1n.powi(3)
-                        + 90f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 120f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 15f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
-                        + 40f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(2)
-                        - 12f64 * phi_0.powi(4) * phi_1n.powi(2)
-                        - 30f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 15f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
-                        + 40f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
-                        - 12f64 * phi_0.powi(4) * phi_1n * phi_1p
-                        - 30f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
-                        + 18f64 * phi_0.powi(4) * phi_1n * phi_2
-                        - 30f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
-                        + 15f64 * phi_0.powi(4) * phi_1p.powi(3)
-                        + 40f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 12f64 * phi_0.powi(4) * phi_1p.powi(2)
-                        - 30f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
-                        + 18f64 * phi_0.powi(4) * phi_1p * phi_2
-                        + 72f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 108f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
-                        + 36f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1n.powi(4)
-                        + 72f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
-                        - 108f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 90f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 36f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
-                        - 30f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
-                        + 15f64 * phi_0.powi(3) * phi_1n.powi(3)
-                        - 108f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
-                        + 90f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 180f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                        - 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
-                        - 30f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                        - 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                        + 45f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1p.powi(4)
-                        - 30f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
-                        + 15f64 * phi_0.powi(3) * phi_1p.powi(3)
-                        - 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 45f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
-                        - 90f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
-                        + 72f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 180f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 108f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
-                        + 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 180f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 108f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 225f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 90f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                        + 75f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                        + 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 108f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
-                        - 225f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 90f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 75f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(2)
-                        - 60f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                        + 75f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                        - 60f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
-                        + 75f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 180f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                        - 90f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 240f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 72f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 180f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 108f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
-                        - 240f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 300f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 180f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 108f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 225f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 90f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 108f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
-                        - 225f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 90f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 180f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                        - 90f64 * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 240f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 72f64 * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 180f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 108f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
-                        - 240f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 72f64 * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 300f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 108f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 225f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 90f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 180f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 108f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
-                        - 225f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 90f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 120f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2))
-                + 210f64
-                    * phi_0
-                    * phi_1n
-                    * phi_1p
-                    * (60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 20f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 90f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        + 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                        + 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
-                        - 90f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        + 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                        + 120f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
-                        - 12f64 * phi_0.powi(3) * phi_1n * phi_1p
-                        - 80f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
-                        + 24f64 * phi_0.powi(3) * phi_1n * phi_2
-                        + 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
-                        - 80f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
-                        + 24f64 * phi_0.powi(3) * phi_1p * phi_2
-                        + 60f64 * phi_0.powi(3) * phi_2.powi(3)
-                        - 36f64 * phi_0.powi(3) * phi_2.powi(2)
-                        - 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 24f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
-                        + 108f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
-                        - 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                        - 72f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 24f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
-                        + 108f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 38f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 162f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
-                        + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
-                        + 108f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 18f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
-                        - 162f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 15f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
-                        - 180f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                        + 120f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
-                        + 120f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
-                        - 80f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
-                        - 72f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                        + 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
-                        + 120f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
-                        - 80f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
-                        - 90f64 * phi_0.powi(2) * phi_2.powi(4)
-                        + 60f64 * phi_0.powi(2) * phi_2.powi(3)
-                        + 90f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 30f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 24f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
-                        - 180f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        + 108f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 120f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(3)
-                        - 72f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
-                        - 72f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 24f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
-                        - 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 108f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 20f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
-                        + 225f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 120f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        - 162f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        - 150f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
-                        + 60f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(2)
-                        - 180f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        + 108f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 225f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 120f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        - 162f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        - 330f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                        + 120f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                        + 60f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
-                        + 120f64 * phi_0 * phi_1n * phi_2.powi(4)
-                        - 80f64 * phi_0 * phi_1n * phi_2.powi(3)
-                        + 120f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(3)
-                        - 72f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
-                        - 150f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
-                        + 60f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(2)
-                        + 120f64 * phi_0 * phi_1p * phi_2.powi(4)
-                        - 80f64 * phi_0 * phi_1p * phi_2.powi(3)
-                        - 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 90f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        + 240f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 72f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 180f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        + 108f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 240f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 72f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 300f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 108f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 60f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 225f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        - 90f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        - 180f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        + 108f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 225f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        - 90f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        - 180f64 * phi_1n * phi_1p * phi_2.powi(4)
-                        + 120f64 * phi_1n * phi_1p * phi_2.powi(3)))
-            / (-4200f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                + 2100f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
-                + 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                - 1890f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2)
-                - 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                + 3780f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2
-                + 1050f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2.powi(2)
-                - 840f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
-                + 105f64 * phi_0.powi(4) * phi_1n.powi(3)
-                + 6300f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                - 1890f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3)
-                - 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                - 6300f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                + 3780f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                + 1680f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2)
-                + 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                - 4200f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
-                + 105f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
-                - 1400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(3)
-                + 840f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2
-                - 140f64 * phi_0.powi(4) * phi_1n.powi(2)
-                - 6300f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                + 3780f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2
-                + 8400f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                - 4200f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
-                + 105f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
-                - 8960f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
-                + 5040f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
-                + 840f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2
-                - 140f64 * phi_0.powi(4) * phi_1n * phi_1p
-                + 1680f64 * phi_0.powi(4) * phi_1n * phi_2.powi(3)
-                - 1260f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
-                + 42f64 * phi_0.powi(4) * phi_1n
-                + 1050f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
-                - 840f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
-                + 105f64 * phi_0.powi(4) * phi_1p.powi(3)
-                - 1400f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                + 840f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2
-                - 140f64 * phi_0.powi(4) * phi_1p.powi(2)
-                + 1680f64 * phi_0.powi(4) * phi_1p * phi_2.powi(3)
-                - 1260f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
-                + 42f64 * phi_0.powi(4) * phi_1p
-                - 420f64 * phi_0.powi(4) * phi_2.powi(3)
-                + 420f64 * phi_0.powi(4) * phi_2.powi(2)
-                - 84f64 * phi_0.powi(4) * phi_2
-                + 5040f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                - 2520f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                + 2268f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2)
-                + 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                - 4536f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2
-                - 1260f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2.powi(2)
-                + 1008f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
-                - 126f64 * phi_0.powi(3) * phi_1n.powi(4)
-                + 5040f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                - 2520f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                - 4200f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                + 4368f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
-                + 13860f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                - 4536f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                - 1890f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                + 4788f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
-                - 126f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
-                + 1050f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2.powi(2)
-                - 840f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
-                + 105f64 * phi_0.powi(3) * phi_1n.powi(3)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                + 2268f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4)
-                + 13860f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                - 4536f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                - 1890f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3)
-                + 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                - 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                + 4788f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                - 246f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                - 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                + 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                - 600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                + 105f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                + 2100f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(4)
-                - 1400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
-                + 40f64 * phi_0.powi(3) * phi_1n.powi(2)
-                + 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                - 4536f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2
-                - 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                + 4788f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
-                - 126f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
-                - 12600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                + 8400f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                - 600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                + 105f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                + 13440f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
-                - 8960f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                - 360f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                - 120f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
-                + 40f64 * phi_0.powi(3) * phi_1n * phi_1p
-                - 2520f64 * phi_0.powi(3) * phi_1n * phi_2.powi(4)
-                + 1680f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
-                + 180f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
-                - 30f64 * phi_0.powi(3) * phi_1n
-                - 1260f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
-                + 1008f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
-                - 126f64 * phi_0.powi(3) * phi_1p.powi(4)
-                + 1050f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                - 840f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
-                + 105f64 * phi_0.powi(3) * phi_1p.powi(3)
-                + 2100f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                - 1400f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
-                + 40f64 * phi_0.powi(3) * phi_1p.powi(2)
-                - 2520f64 * phi_0.powi(3) * phi_1p * phi_2.powi(4)
-                + 1680f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
-                + 180f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
-                - 30f64 * phi_0.powi(3) * phi_1p
-                + 630f64 * phi_0.powi(3) * phi_2.powi(4)
-                - 420f64 * phi_0.powi(3) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(3) * phi_2.powi(2)
-                + 60f64 * phi_0.powi(3) * phi_2
-                - 6300f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                + 3150f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
-                + 5040f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                - 2520f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
-                + 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                - 252f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
-                - 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                + 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                + 504f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
-                + 2100f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(3)
-                - 1260f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
-                + 84f64 * phi_0.powi(2) * phi_1n.powi(4)
-                + 5040f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                - 2520f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
-                + 12600f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                - 4200f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                + 1848f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                - 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                - 12600f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                + 13860f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                + 504f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                + 360f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
-                + 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                + 2100f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                - 972f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                + 84f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
-                - 2625f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(4)
-                + 1050f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
-                + 360f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                - 120f64 * phi_0.powi(2) * phi_1n.powi(3)
-                + 12600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
-                - 15750f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                - 12600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                + 13860f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                + 504f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                + 360f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
-                + 28350f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                - 6300f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                - 972f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                - 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                - 15225f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                + 8400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                + 600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
-                + 2100f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(4)
-                - 1400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
-                + 40f64 * phi_0.powi(2) * phi_1n.powi(2)
-                - 12600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                + 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                + 504f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
-                + 15750f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                + 2100f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                - 972f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                + 84f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
-                - 15225f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                + 8400f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                + 600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                - 120f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
-                + 840f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                + 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                - 360f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                - 120f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
-                + 40f64 * phi_0.powi(2) * phi_1n * phi_1p
-                + 630f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
-                - 720f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
-                + 180f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
-                + 2100f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                - 1260f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
-                + 84f64 * phi_0.powi(2) * phi_1p.powi(4)
-                - 2625f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                + 1050f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                + 360f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                - 120f64 * phi_0.powi(2) * phi_1p.powi(3)
-                + 2100f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                - 1400f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
-                + 40f64 * phi_0.powi(2) * phi_1p.powi(2)
-                + 630f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
-                - 720f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
-                + 180f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
-                - 420f64 * phi_0.powi(2) * phi_2.powi(4)
-                + 480f64 * phi_0.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(2) * phi_2.powi(2)
-                + 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
-                - 6300f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                - 630f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
-                - 16800f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
-                + 5040f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                + 840f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
-                + 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                - 7560f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                - 252f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
-                + 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                - 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                + 504f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
-                - 1260f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
-                + 1260f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0 * phi_1n.powi(4) * phi_2
-                - 16800f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
-                + 5040f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                + 840f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
-                + 21000f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
-                + 12600f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                - 7560f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                - 4200f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                - 1152f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                - 15750f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                + 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                + 3780f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                + 504f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                + 360f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
-                - 3150f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                + 3960f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                - 972f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
-                + 1575f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
-                - 1350f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
-                + 360f64 * phi_0 * phi_1n.powi(3) * phi_2
-                + 12600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                - 7560f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
-                - 15750f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                + 2520f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                + 3780f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                + 504f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                + 360f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
-                + 9450f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                - 9660f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                + 3960f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                - 972f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
-                + 5775f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                - 3600f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                - 1350f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                + 600f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
-                - 2100f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
-                + 1800f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0 * phi_1n.powi(2) * phi_2
-                + 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                - 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                + 504f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
-                - 3150f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                - 1260f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                + 3960f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                - 972f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
-                + 5775f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                - 3600f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                - 1350f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                + 600f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
-                - 3360f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                + 3240f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                - 360f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
-                - 120f64 * phi_0 * phi_1n * phi_1p * phi_2
-                + 630f64 * phi_0 * phi_1n * phi_2.powi(4)
-                - 720f64 * phi_0 * phi_1n * phi_2.powi(3)
-                + 180f64 * phi_0 * phi_1n * phi_2.powi(2)
-                - 1260f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
-                + 1260f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0 * phi_1p.powi(4) * phi_2
-                + 1575f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
-                - 1350f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
-                + 360f64 * phi_0 * phi_1p.powi(3) * phi_2
-                - 2100f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
-                + 1800f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0 * phi_1p.powi(2) * phi_2
-                + 630f64 * phi_0 * phi_1p * phi_2.powi(4)
-                - 720f64 * phi_0 * phi_1p * phi_2.powi(3)
-                + 180f64 * phi_0 * phi_1p * phi_2.powi(2)
-                - 6300f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
-                + 5040f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                - 630f64 * phi_1n.powi(4) * phi_1p.powi(4)
-                + 8400f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
-                - 5040f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                + 840f64 * phi_1n.powi(4) * phi_1p.powi(3)
-                - 10080f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                + 7560f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                - 252f64 * phi_1n.powi(4) * phi_1p.powi(2)
-                + 2520f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                - 2520f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                + 504f64 * phi_1n.powi(4) * phi_1p * phi_2
-                + 8400f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
-                - 5040f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                + 840f64 * phi_1n.powi(3) * phi_1p.powi(4)
-                - 10500f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
-                - 10080f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                + 7560f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                + 4800f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                - 1152f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                + 12600f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                + 2520f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                - 9720f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                + 504f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                + 360f64 * phi_1n.powi(3) * phi_1p.powi(2)
-                - 3150f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                + 2700f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                - 720f64 * phi_1n.powi(3) * phi_1p * phi_2
-                - 10080f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                + 7560f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_1n.powi(2) * phi_1p.powi(4)
-                + 12600f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                + 2520f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                - 9720f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                + 504f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                + 360f64 * phi_1n.powi(2) * phi_1p.powi(3)
-                - 15750f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                + 9600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                + 2700f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                - 720f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                - 120f64 * phi_1n.powi(2) * phi_1p.powi(2)
-                + 4200f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                - 3600f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                + 240f64 * phi_1n.powi(2) * phi_1p * phi_2
-                + 2520f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                - 2520f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                + 504f64 * phi_1n * phi_1p.powi(4) * phi_2
-                - 3150f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                + 2700f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                - 720f64 * phi_1n * phi_1p.powi(3) * phi_2
-                + 4200f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                - 3600f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                + 240f64 * phi_1n * phi_1p.powi(2) * phi_2
-                - 1260f64 * phi_1n * phi_1p * phi_2.powi(4)
-                + 1440f64 * phi_1n * phi_1p * phi_2.powi(3)
-                - 360f64 * phi_1n * phi_1p * phi_2.powi(2))
+        let (ps, phi) = seed_params([phi_0, phi_1n, phi_1p, phi_2], phi);
+        c1_potential_expr(phi, ps[0], ps[1], ps[2], ps[3]).dx
     })
 }
 
-
-pub fn c1_deriv(phi_0: f64, phi_1n: f64, phi_1p: f64, phi_2: f64) -> Box<dyn Fn(f64) -> f64> {
-    Box::new(move |phi| {
-        420f64 * phi
-            * (phi.powi(5)
-                * (-12f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                    + 24f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                    - 12f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
-                    + 4f64 * phi_0.powi(3) * phi_1n.powi(2)
-                    + 24f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                    - 36f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                    - 12f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
-                    + 4f64 * phi_0.powi(3) * phi_1n * phi_1p
-                    + 18f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
-                    - 3f64 * phi_0.powi(3) * phi_1n
-                    - 12f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
-                    + 4f64 * phi_0.powi(3) * phi_1p.powi(2)
-                    + 18f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
-                    - 3f64 * phi_0.powi(3) * phi_1p
-                    - 12f64 * phi_0.powi(3) * phi_2.powi(2)
-                    + 6f64 * phi_0.powi(3) * phi_2
-                    + 15f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
-                    - 30f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                    + 15f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                    - 5f64 * phi_0.powi(2) * phi_1n.powi(3)
-                    + 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
-                    - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                    - 12f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                    + 39f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                    - 5f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
-                    - 12f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
-                    + 4f64 * phi_0.powi(2) * phi_1n.powi(2)
-                    - 30f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                    + 39f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                    - 5f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
-                    + 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                    - 36f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                    - 12f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
-                    + 4f64 * phi_0.powi(2) * phi_1n * phi_1p
-                    - 30f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
-                    + 18f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
-                    + 15f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                    - 5f64 * phi_0.powi(2) * phi_1p.powi(3)
-                    - 12f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
-                    + 4f64 * phi_0.powi(2) * phi_1p.powi(2)
-                    - 30f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
-                    + 18f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
-                    + 20f64 * phi_0.powi(2) * phi_2.powi(3)
-                    - 12f64 * phi_0.powi(2) * phi_2.powi(2)
-                    - 20f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                    + 15f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
-                    + 60f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                    - 30f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
-                    - 30f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
-                    + 15f64 * phi_0 * phi_1n.powi(3) * phi_2
-                    + 15f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
-                    + 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                    - 30f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                    - 12f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
-                    - 80f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                    - 30f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                    + 39f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
-                    + 40f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
-                    - 12f64 * phi_0 * phi_1n.powi(2) * phi_2
-                    + 60f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                    - 30f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
-                    - 80f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                    - 30f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                    + 39f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
-                    + 100f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                    - 36f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
-                    - 12f64 * phi_0 * phi_1n * phi_1p * phi_2
-                    - 30f64 * phi_0 * phi_1n * phi_2.powi(3)
-                    + 18f64 * phi_0 * phi_1n * phi_2.powi(2)
-                    - 30f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
-                    + 15f64 * phi_0 * phi_1p.powi(3) * phi_2
-                    + 40f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
-                    - 12f64 * phi_0 * phi_1p.powi(2) * phi_2
-                    - 30f64 * phi_0 * phi_1p * phi_2.powi(3)
-                    + 18f64 * phi_0 * phi_1p * phi_2.powi(2)
-                    + 60f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                    - 20f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                    - 90f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                    + 15f64 * phi_1n.powi(3) * phi_1p.powi(2)
-                    + 60f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                    - 30f64 * phi_1n.powi(3) * phi_1p * phi_2
-                    - 90f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                    + 15f64 * phi_1n.powi(2) * phi_1p.powi(3)
-                    + 120f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                    + 60f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                    - 30f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                    - 12f64 * phi_1n.powi(2) * phi_1p.powi(2)
-                    - 80f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                    + 24f64 * phi_1n.powi(2) * phi_1p * phi_2
-                    + 60f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                    - 30f64 * phi_1n * phi_1p.powi(3) * phi_2
-                    - 80f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                    + 24f64 * phi_1n * phi_1p.powi(2) * phi_2
-                    + 60f64 * phi_1n * phi_1p * phi_2.powi(3)
-                    - 36f64 * phi_1n * phi_1p * phi_2.powi(2))
-                + phi.powi(4)
-                    * (12f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 24f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 12f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2
-                        - 4f64 * phi_0.powi(4) * phi_1n.powi(2)
-                        - 24f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 36f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
-                        + 12f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2
-                        - 4f64 * phi_0.powi(4) * phi_1n * phi_1p
-                        - 18f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
-                        + 3f64 * phi_0.powi(4) * phi_1n
-                        + 12f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2
-                        - 4f64 * phi_0.powi(4) * phi_1p.powi(2)
-                        - 18f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
-                        + 3f64 * phi_0.powi(4) * phi_1p
-                        + 12f64 * phi_0.powi(4) * phi_2.powi(2)
-                        - 6f64 * phi_0.powi(4) * phi_2
-                        + 12f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 24f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 12f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
-                        - 4f64 * phi_0.powi(3) * phi_1n.powi(2)
-                        - 24f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 36f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                        + 12f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
-                        - 4f64 * phi_0.powi(3) * phi_1n * phi_1p
-                        - 18f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
-                        + 3f64 * phi_0.powi(3) * phi_1n
-                        + 12f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
-                        - 4f64 * phi_0.powi(3) * phi_1p.powi(2)
-                        - 18f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
-                        + 3f64 * phi_0.powi(3) * phi_1p
-                        + 12f64 * phi_0.powi(3) * phi_2.powi(2)
-                        - 6f64 * phi_0.powi(3) * phi_2
-                        - 18f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
-                        - 18f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
-                        + 6f64 * phi_0.powi(2) * phi_1n.powi(4)
-                        - 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                        + 6f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
-                        - 18f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 18f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 18f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 24f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 12f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
-                        - 4f64 * phi_0.powi(2) * phi_1n.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
-                        - 18f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                        + 6f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
-                        - 24f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                        - 90f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                        + 36f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                        + 12f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
-                        - 4f64 * phi_0.powi(2) * phi_1n * phi_1p
-                        + 45f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
-                        - 18f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
-                        - 18f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
-                        + 6f64 * phi_0.powi(2) * phi_1p.powi(4)
-                        + 12f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
-                        - 4f64 * phi_0.powi(2) * phi_1p.powi(2)
-                        + 45f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
-                        - 18f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(2) * phi_2.powi(4)
-                        + 12f64 * phi_0.powi(2) * phi_2.powi(2)
-                        + 24f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 18f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
-                        - 72f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 36f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
-                        + 36f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
-                        - 18f64 * phi_0 * phi_1n.powi(4) * phi_2
-                        + 24f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
-                        - 18f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 36f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 18f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
-                        - 18f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
-                        - 72f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 36f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 36f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 18f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 12f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
-                        + 120f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        - 24f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
-                        - 60f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
-                        + 12f64 * phi_0 * phi_1n.powi(2) * phi_2
-                        - 72f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 36f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
-                        + 36f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 18f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
-                        + 120f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        - 24f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
-                        - 150f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                        + 36f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
-                        + 12f64 * phi_0 * phi_1n * phi_1p * phi_2
-                        + 45f64 * phi_0 * phi_1n * phi_2.powi(4)
-                        - 18f64 * phi_0 * phi_1n * phi_2.powi(2)
-                        + 36f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
-                        - 18f64 * phi_0 * phi_1p.powi(4) * phi_2
-                        - 60f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
-                        + 12f64 * phi_0 * phi_1p.powi(2) * phi_2
-                        + 45f64 * phi_0 * phi_1p * phi_2.powi(4)
-                        - 18f64 * phi_0 * phi_1p * phi_2.powi(2)
-                        - 72f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                        + 24f64 * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 108f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 18f64 * phi_1n.powi(4) * phi_1p.powi(2)
-                        - 72f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 36f64 * phi_1n.powi(4) * phi_1p * phi_2
-                        - 72f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                        + 24f64 * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 108f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 18f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 72f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 108f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 18f64 * phi_1n.powi(2) * phi_1p.powi(4)
-                        - 72f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 36f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        + 12f64 * phi_1n.powi(2) * phi_1p.powi(2)
-                        + 120f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        - 24f64 * phi_1n.powi(2) * phi_1p * phi_2
-                        - 72f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 36f64 * phi_1n * phi_1p.powi(4) * phi_2
-                        + 120f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        - 24f64 * phi_1n * phi_1p.powi(2) * phi_2
-                        - 90f64 * phi_1n * phi_1p * phi_2.powi(4)
-                        + 36f64 * phi_1n * phi_1p * phi_2.powi(2))
-                + phi.powi(3)
-                    * (-15f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2)
-                        + 30f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2
-                        - 15f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
-                        + 5f64 * phi_0.powi(4) * phi_1n.powi(3)
-                        - 15f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3)
-                        + 30f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 15f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 5f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
-                        + 30f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2
-                        - 15f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 5f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
-                        - 60f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(4) * phi_1n * phi_2.powi(3)
-                        - 3f64 * phi_0.powi(4) * phi_1n
-                        - 15f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
-                        + 5f64 * phi_0.powi(4) * phi_1p.powi(3)
-                        + 30f64 * phi_0.powi(4) * phi_1p * phi_2.powi(3)
-                        - 3f64 * phi_0.powi(4) * phi_1p
-                        - 20f64 * phi_0.powi(4) * phi_2.powi(3)
-                        + 6f64 * phi_0.powi(4) * phi_2
-                        + 18f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2)
-                        - 36f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2
-                        + 18f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
-                        - 6f64 * phi_0.powi(3) * phi_1n.powi(4)
-                        + 18f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 36f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 15f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2)
-                        + 48f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
-                        - 6f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
-                        - 15f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
-                        + 5f64 * phi_0.powi(3) * phi_1n.powi(3)
-                        + 18f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4)
-                        - 36f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3)
-                        + 48f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 6f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 5f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                        - 36f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2
-                        + 48f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
-                        - 6f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
-                        - 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 5f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                        + 90f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
-                        - 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                        - 45f64 * phi_0.powi(3) * phi_1n * phi_2.powi(4)
-                        + 30f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
-                        + 18f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
-                        - 6f64 * phi_0.powi(3) * phi_1p.powi(4)
-                        - 15f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
-                        + 5f64 * phi_0.powi(3) * phi_1p.powi(3)
-                        - 45f64 * phi_0.powi(3) * phi_1p * phi_2.powi(4)
-                        + 30f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(3) * phi_2.powi(4)
-                        - 20f64 * phi_0.powi(3) * phi_2.powi(3)
-                        + 18f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
-                        - 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
-                        + 18f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
-                        - 6f64 * phi_0.powi(2) * phi_1n.powi(4)
-                        + 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 15f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
-                        + 48f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                        - 6f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
-                        - 15f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                        + 5f64 * phi_0.powi(2) * phi_1n.powi(3)
-                        + 18f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
-                        - 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
-                        + 48f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 6f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 5f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
-                        - 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
-                        + 48f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                        - 6f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
-                        - 15f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 5f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
-                        + 90f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                        - 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                        - 45f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
-                        + 30f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
-                        + 18f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
-                        - 6f64 * phi_0.powi(2) * phi_1p.powi(4)
-                        - 15f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                        + 5f64 * phi_0.powi(2) * phi_1p.powi(3)
-                        - 45f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
-                        + 30f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(2) * phi_2.powi(4)
-                        - 20f64 * phi_0.powi(2) * phi_2.powi(3)
-                        - 30f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
-                        + 18f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
-                        + 120f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        - 36f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
-                        - 60f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
-                        + 18f64 * phi_0 * phi_1n.powi(4) * phi_2
-                        + 18f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 120f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 36f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 15f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
-                        - 150f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        - 60f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        + 48f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
-                        + 75f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
-                        - 15f64 * phi_0 * phi_1n.powi(3) * phi_2
-                        + 18f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
-                        + 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 36f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 15f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
-                        - 150f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 48f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 75f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        - 15f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
-                        + 120f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        - 36f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
-                        - 150f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        - 60f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        + 48f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
-                        + 75f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        - 15f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
-                        + 90f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                        - 60f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                        - 45f64 * phi_0 * phi_1n * phi_2.powi(4)
-                        + 30f64 * phi_0 * phi_1n * phi_2.powi(3)
-                        - 60f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
-                        + 18f64 * phi_0 * phi_1p.powi(4) * phi_2
-                        + 75f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
-                        - 15f64 * phi_0 * phi_1p.powi(3) * phi_2
-                        - 45f64 * phi_0 * phi_1p * phi_2.powi(4)
-                        + 30f64 * phi_0 * phi_1p * phi_2.powi(3)
-                        + 90f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                        - 30f64 * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 180f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 18f64 * phi_1n.powi(4) * phi_1p.powi(2)
-                        + 120f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        - 36f64 * phi_1n.powi(4) * phi_1p * phi_2
-                        - 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 18f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 225f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                        + 120f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 36f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 15f64 * phi_1n.powi(3) * phi_1p.powi(2)
-                        - 150f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        + 30f64 * phi_1n.powi(3) * phi_1p * phi_2
-                        - 180f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                        + 18f64 * phi_1n.powi(2) * phi_1p.powi(4)
-                        + 225f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                        + 120f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 36f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 15f64 * phi_1n.powi(2) * phi_1p.powi(3)
-                        - 150f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        + 30f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 120f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        - 36f64 * phi_1n * phi_1p.powi(4) * phi_2
-                        - 150f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        + 30f64 * phi_1n * phi_1p.powi(3) * phi_2
-                        + 90f64 * phi_1n * phi_1p * phi_2.powi(4)
-                        - 60f64 * phi_1n * phi_1p * phi_2.powi(3))
-                + phi.powi(2)
-                    * (20f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 60f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 30f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2.powi(2)
-                        - 5f64 * phi_0.powi(4) * phi_1n.powi(3)
-                        - 60f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 80f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        - 5f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
-                        - 40f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(3)
-                        + 4f64 * phi_0.powi(4) * phi_1n.powi(2)
-                        - 60f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 80f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        - 5f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
-                        - 40f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 4f64 * phi_0.powi(4) * phi_1n * phi_1p
-                        + 30f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 5f64 * phi_0.powi(4) * phi_1p.powi(3)
-                        - 40f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 4f64 * phi_0.powi(4) * phi_1p.powi(2)
-                        + 20f64 * phi_0.powi(4) * phi_2.powi(3)
-                        - 12f64 * phi_0.powi(4) * phi_2.powi(2)
-                        - 24f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 72f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        - 36f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2.powi(2)
-                        + 6f64 * phi_0.powi(3) * phi_1n.powi(4)
-                        - 24f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 20f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 72f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 96f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 6f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
-                        + 30f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2.powi(2)
-                        - 5f64 * phi_0.powi(3) * phi_1n.powi(3)
-                        + 72f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 96f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 6f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 80f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        - 5f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                        + 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(4)
-                        - 40f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
-                        + 72f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        - 96f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 6f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
-                        - 120f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 80f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        - 5f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                        + 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
-                        - 40f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                        - 36f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 6f64 * phi_0.powi(3) * phi_1p.powi(4)
-                        + 30f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 5f64 * phi_0.powi(3) * phi_1p.powi(3)
-                        + 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 40f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(3) * phi_2.powi(4)
-                        + 20f64 * phi_0.powi(3) * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 24f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 120f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        + 72f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 60f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(3)
-                        - 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
-                        - 24f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 20f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 120f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 150f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        + 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        - 96f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 75f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(4)
-                        + 30f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
-                        - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 72f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 150f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 96f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 195f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 80f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(4)
-                        - 40f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
-                        - 120f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        + 72f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 150f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        + 60f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        - 96f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 195f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 80f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        + 30f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        + 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                        - 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 60f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                        - 36f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 75f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                        + 30f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 40f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 30f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 24f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 120f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        + 72f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 60f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
-                        - 36f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
-                        - 24f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 20f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 120f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 150f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        + 60f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        - 96f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 75f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
-                        + 30f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
-                        - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 72f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 150f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        + 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 96f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 195f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 80f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        + 30f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        + 60f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
-                        - 40f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
-                        - 120f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        + 72f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 150f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        + 60f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        - 96f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 195f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 80f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        + 30f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        + 60f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                        - 40f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                        + 60f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
-                        - 36f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
-                        - 75f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
-                        + 30f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
-                        + 60f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
-                        - 40f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
-                        - 180f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 30f64 * phi_1n.powi(4) * phi_1p.powi(4)
-                        + 240f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 24f64 * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 120f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                        + 72f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                        + 240f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
-                        - 24f64 * phi_1n.powi(3) * phi_1p.powi(4)
-                        - 300f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
-                        + 20f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 120f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 72f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 150f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                        - 60f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 120f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 72f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 150f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 60f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 120f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 80f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        - 120f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                        + 72f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                        + 150f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                        - 60f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 120f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 80f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3))
-                + phi
-                    * phi_2
-                    * (-60f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 90f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 30f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
-                        + 15f64 * phi_0.powi(4) * phi_1n.powi(3)
-                        + 90f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 120f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 15f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
-                        + 40f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(2)
-                        - 12f64 * phi_0.powi(4) * phi_1n.powi(2)
-                        - 30f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 15f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
-                        + 40f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
-                        - 12f64 * phi_0.powi(4) * phi_1n * phi_1p
-                        - 30f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
-                        + 18f64 * phi_0.powi(4) * phi_1n * phi_2
-                        - 30f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
-                        + 15f64 * phi_0.powi(4) * phi_1p.powi(3)
-                        + 40f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 12f64 * phi_0.powi(4) * phi_1p.powi(2)
-                        - 30f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
-                        + 18f64 * phi_0.powi(4) * phi_1p * phi_2
-                        + 72f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
-                        - 108f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
-                        + 36f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1n.powi(4)
-                        + 72f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
-                        - 108f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
-                        + 90f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 36f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
-                        - 30f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
-                        + 15f64 * phi_0.powi(3) * phi_1n.powi(3)
-                        - 108f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
-                        + 90f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 180f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                        - 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
-                        - 30f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                        - 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                        + 45f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
-                        - 18f64 * phi_0.powi(3) * phi_1p.powi(4)
-                        - 30f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
-                        + 15f64 * phi_0.powi(3) * phi_1p.powi(3)
-                        - 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 45f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
-                        - 90f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
-                        + 72f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 180f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 108f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
-                        + 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 180f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 108f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 225f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 90f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                        + 75f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                        + 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 108f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
-                        - 225f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 90f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 75f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(2)
-                        - 60f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                        + 75f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                        - 60f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
-                        + 75f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 30f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 40f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 180f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                        - 90f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 240f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 72f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 180f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 108f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
-                        - 240f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 300f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 180f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 108f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 225f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 90f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 108f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
-                        - 225f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 90f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 180f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                        - 90f64 * phi_1n.powi(4) * phi_1p.powi(4)
-                        - 240f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 72f64 * phi_1n.powi(4) * phi_1p.powi(3)
-                        + 180f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 108f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
-                        - 240f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
-                        + 72f64 * phi_1n.powi(3) * phi_1p.powi(4)
-                        + 300f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        - 108f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 60f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 225f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 90f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 180f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                        - 108f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
-                        - 225f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        + 90f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 120f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2))
-                + phi_0
-                    * phi_1n
-                    * phi_1p
-                    * (60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 20f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 90f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        + 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                        + 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
-                        - 90f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        + 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                        + 120f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
-                        - 12f64 * phi_0.powi(3) * phi_1n * phi_1p
-                        - 80f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
-                        + 24f64 * phi_0.powi(3) * phi_1n * phi_2
-                        + 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
-                        - 80f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
-                        + 24f64 * phi_0.powi(3) * phi_1p * phi_2
-                        + 60f64 * phi_0.powi(3) * phi_2.powi(3)
-                        - 36f64 * phi_0.powi(3) * phi_2.powi(2)
-                        - 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 24f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
-                        + 108f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        - 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
-                        - 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                        - 72f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 24f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
-                        + 108f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 38f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                        - 162f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                        + 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
-                        + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
-                        + 108f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        - 18f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
-                        - 162f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                        + 15f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
-                        - 180f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                        + 120f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                        + 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
-                        + 120f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
-                        - 80f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
-                        - 72f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 36f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                        + 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        - 30f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
-                        + 120f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
-                        - 80f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
-                        - 90f64 * phi_0.powi(2) * phi_2.powi(4)
-                        + 60f64 * phi_0.powi(2) * phi_2.powi(3)
-                        + 90f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        - 30f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                        - 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        + 24f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
-                        - 180f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        + 108f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 120f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(3)
-                        - 72f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
-                        - 72f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        + 24f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
-                        - 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 108f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        - 20f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
-                        + 225f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        + 120f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                        - 162f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        - 150f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
-                        + 60f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(2)
-                        - 180f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        + 108f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 225f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        + 120f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                        - 162f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        - 330f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                        + 120f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                        + 60f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
-                        + 120f64 * phi_0 * phi_1n * phi_2.powi(4)
-                        - 80f64 * phi_0 * phi_1n * phi_2.powi(3)
-                        + 120f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(3)
-                        - 72f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
-                        - 150f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
-                        + 60f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(2)
-                        + 120f64 * phi_0 * phi_1p * phi_2.powi(4)
-                        - 80f64 * phi_0 * phi_1p * phi_2.powi(3)
-                        - 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                        + 90f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                        + 240f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                        - 72f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                        - 180f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                        + 108f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                        + 240f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                        - 72f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                        - 300f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                        - 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                        + 108f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                        + 60f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                        + 225f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                        - 90f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                        - 180f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                        + 108f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                        + 225f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                        - 90f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                        - 180f64 * phi_1n * phi_1p * phi_2.powi(4)
-                        + 120f64 * phi_1n * phi_1p * phi_2.powi(3)))
-            / (-4200f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                + 2100f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
-                + 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                - 1890f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2)
-                - 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                + 3780f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2
-                + 1050f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2.powi(2)
-                - 840f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
-                + 105f64 * phi_0.powi(4) * phi_1n.powi(3)
-                + 6300f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                - 1890f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3)
-                - 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                - 6300f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                + 3780f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                + 1680f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2)
-                + 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                - 4200f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
-                + 105f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
-                - 1400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(3)
-                + 840f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2
-                - 140f64 * phi_0.powi(4) * phi_1n.powi(2)
-                - 6300f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                + 3780f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2
-                + 8400f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                - 4200f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
-                + 105f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
-                - 8960f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
-                + 5040f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
-                + 840f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2
-                - 140f64 * phi_0.powi(4) * phi_1n * phi_1p
-                + 1680f64 * phi_0.powi(4) * phi_1n * phi_2.powi(3)
-                - 1260f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
-                + 42f64 * phi_0.powi(4) * phi_1n
-                + 1050f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
-                - 840f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
-                + 105f64 * phi_0.powi(4) * phi_1p.powi(3)
-                - 1400f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                + 840f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2
-                - 140f64 * phi_0.powi(4) * phi_1p.powi(2)
-                + 1680f64 * phi_0.powi(4) * phi_1p * phi_2.powi(3)
-                - 1260f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
-                + 42f64 * phi_0.powi(4) * phi_1p
-                - 420f64 * phi_0.powi(4) * phi_2.powi(3)
-                + 420f64 * phi_0.powi(4) * phi_2.powi(2)
-                - 84f64 * phi_0.powi(4) * phi_2
-                + 5040f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                - 2520f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                + 2268f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2)
-                + 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                - 4536f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2
-                - 1260f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2.powi(2)
-                + 1008f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
-                - 126f64 * phi_0.powi(3) * phi_1n.powi(4)
-                + 5040f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                - 2520f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                - 4200f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                + 4368f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
-                + 13860f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                - 4536f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                - 1890f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                + 4788f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
-                - 126f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
-                + 1050f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2.powi(2)
-                - 840f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
-                + 105f64 * phi_0.powi(3) * phi_1n.powi(3)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                + 2268f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4)
-                + 13860f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                - 4536f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                - 1890f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3)
-                + 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                - 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                + 4788f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                - 246f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
-                - 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                + 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                - 600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
-                + 105f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
-                + 2100f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(4)
-                - 1400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
-                + 40f64 * phi_0.powi(3) * phi_1n.powi(2)
-                + 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                - 4536f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2
-                - 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                + 4788f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
-                - 126f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
-                - 12600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                + 8400f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                - 600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
-                + 105f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
-                + 13440f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
-                - 8960f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
-                - 360f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
-                - 120f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
-                + 40f64 * phi_0.powi(3) * phi_1n * phi_1p
-                - 2520f64 * phi_0.powi(3) * phi_1n * phi_2.powi(4)
-                + 1680f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
-                + 180f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
-                - 30f64 * phi_0.powi(3) * phi_1n
-                - 1260f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
-                + 1008f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
-                - 126f64 * phi_0.powi(3) * phi_1p.powi(4)
-                + 1050f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                - 840f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
-                + 105f64 * phi_0.powi(3) * phi_1p.powi(3)
-                + 2100f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                - 1400f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
-                + 40f64 * phi_0.powi(3) * phi_1p.powi(2)
-                - 2520f64 * phi_0.powi(3) * phi_1p * phi_2.powi(4)
-                + 1680f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
-                + 180f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
-                - 30f64 * phi_0.powi(3) * phi_1p
-                + 630f64 * phi_0.powi(3) * phi_2.powi(4)
-                - 420f64 * phi_0.powi(3) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(3) * phi_2.powi(2)
-                + 60f64 * phi_0.powi(3) * phi_2
-                - 6300f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                + 3150f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
-                + 5040f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                - 2520f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
-                + 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                - 252f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
-                - 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                + 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                + 504f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
-                + 2100f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(3)
-                - 1260f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
-                + 84f64 * phi_0.powi(2) * phi_1n.powi(4)
-                + 5040f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                - 2520f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
-                + 12600f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                - 4200f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                + 1848f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
-                - 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                - 12600f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                + 13860f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                + 504f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                + 360f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
-                + 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                + 2100f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                - 972f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
-                + 84f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
-                - 2625f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(4)
-                + 1050f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
-                + 360f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
-                - 120f64 * phi_0.powi(2) * phi_1n.powi(3)
-                + 12600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
-                - 15750f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                - 12600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                + 13860f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                + 504f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                + 360f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
-                + 28350f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                - 6300f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                - 972f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                - 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
-                - 15225f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                + 8400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                + 600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
-                - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
-                + 2100f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(4)
-                - 1400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
-                + 40f64 * phi_0.powi(2) * phi_1n.powi(2)
-                - 12600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                + 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                + 504f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
-                + 15750f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                + 2100f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                - 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                - 972f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
-                + 84f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
-                - 15225f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                + 8400f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                + 1050f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                + 600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
-                - 120f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
-                + 840f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
-                + 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
-                - 360f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
-                - 120f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
-                + 40f64 * phi_0.powi(2) * phi_1n * phi_1p
-                + 630f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
-                - 720f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
-                + 180f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
-                + 2100f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                - 1260f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
-                + 84f64 * phi_0.powi(2) * phi_1p.powi(4)
-                - 2625f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                + 1050f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                + 360f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
-                - 120f64 * phi_0.powi(2) * phi_1p.powi(3)
-                + 2100f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                - 1400f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
-                + 40f64 * phi_0.powi(2) * phi_1p.powi(2)
-                + 630f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
-                - 720f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
-                + 180f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
-                - 420f64 * phi_0.powi(2) * phi_2.powi(4)
-                + 480f64 * phi_0.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0.powi(2) * phi_2.powi(2)
-                + 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
-                - 6300f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                - 630f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
-                - 16800f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
-                + 5040f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                + 840f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
-                + 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                - 7560f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                - 252f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
-                + 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                - 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                + 504f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
-                - 1260f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
-                + 1260f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0 * phi_1n.powi(4) * phi_2
-                - 16800f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
-                + 5040f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                + 840f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
-                + 21000f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
-                + 12600f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                - 7560f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                - 4200f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                - 1152f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
-                - 15750f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                + 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                + 3780f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                + 504f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                + 360f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
-                - 3150f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
-                + 3960f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                - 972f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
-                + 1575f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
-                - 1350f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
-                + 360f64 * phi_0 * phi_1n.powi(3) * phi_2
-                + 12600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                - 7560f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
-                - 15750f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                + 2520f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                + 3780f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                + 504f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                + 360f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
-                + 9450f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                - 9660f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                + 3960f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                - 972f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
-                + 5775f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                - 3600f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                - 1350f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
-                + 600f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
-                - 2100f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
-                + 1800f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0 * phi_1n.powi(2) * phi_2
-                + 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                - 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                + 504f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
-                - 3150f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                - 1260f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
-                + 3960f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                - 972f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
-                + 5775f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                - 3600f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                - 1350f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
-                + 600f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
-                - 3360f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
-                + 3240f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
-                - 360f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
-                - 120f64 * phi_0 * phi_1n * phi_1p * phi_2
-                + 630f64 * phi_0 * phi_1n * phi_2.powi(4)
-                - 720f64 * phi_0 * phi_1n * phi_2.powi(3)
-                + 180f64 * phi_0 * phi_1n * phi_2.powi(2)
-                - 1260f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
-                + 1260f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_0 * phi_1p.powi(4) * phi_2
-                + 1575f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
-                - 1350f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
-                + 360f64 * phi_0 * phi_1p.powi(3) * phi_2
-                - 2100f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
-                + 1800f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
-                - 120f64 * phi_0 * phi_1p.powi(2) * phi_2
-                + 630f64 * phi_0 * phi_1p * phi_2.powi(4)
-                - 720f64 * phi_0 * phi_1p * phi_2.powi(3)
-                + 180f64 * phi_0 * phi_1p * phi_2.powi(2)
-                - 6300f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
-                + 5040f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
-                - 630f64 * phi_1n.powi(4) * phi_1p.powi(4)
-                + 8400f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
-                - 5040f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
-                + 840f64 * phi_1n.powi(4) * phi_1p.powi(3)
-                - 10080f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
-                + 7560f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
-                - 252f64 * phi_1n.powi(4) * phi_1p.powi(2)
-                + 2520f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
-                - 2520f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
-                + 504f64 * phi_1n.powi(4) * phi_1p * phi_2
-                + 8400f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
-                - 5040f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
-                + 840f64 * phi_1n.powi(3) * phi_1p.powi(4)
-                - 10500f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
-                - 10080f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
-                + 7560f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
-                + 4800f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
-                - 1152f64 * phi_1n.powi(3) * phi_1p.powi(3)
-                + 12600f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
-                + 2520f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
-                - 9720f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
-                + 504f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
-                + 360f64 * phi_1n.powi(3) * phi_1p.powi(2)
-                - 3150f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
-                + 2700f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
-                - 720f64 * phi_1n.powi(3) * phi_1p * phi_2
-                - 10080f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
-                + 7560f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
-                - 252f64 * phi_1n.powi(2) * phi_1p.powi(4)
-                + 12600f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
-                + 2520f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
-                - 9720f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
-                + 504f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
-                + 360f64 * phi_1n.powi(2) * phi_1p.powi(3)
-                - 15750f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
-                + 9600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
-                + 2700f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
-                - 720f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
-                - 120f64 * phi_1n.powi(2) * phi_1p.powi(2)
-                + 4200f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
-                - 3600f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
-                + 240f64 * phi_1n.powi(2) * phi_1p * phi_2
-                + 2520f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
-                - 2520f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
-                + 504f64 * phi_1n * phi_1p.powi(4) * phi_2
-                - 3150f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
-                + 2700f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
-                - 720f64 * phi_1n * phi_1p.powi(3) * phi_2
-                + 4200f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
-                - 3600f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
-                + 240f64 * phi_1n * phi_1p.powi(2) * phi_2
-                - 1260f64 * phi_1n * phi_1p * phi_2.powi(4)
-                + 1440f64 * phi_1n * phi_1p * phi_2.powi(3)
-                - 360f64 * phi_1n * phi_1p * phi_2.powi(2))
+/// Exact gradient of V'(φ) w.r.t. (phi_0, phi_1n, phi_1p, phi_2) via forward-mode AD
+pub fn c1_deriv_grad(
+    phi_0: f64,
+    phi_1n: f64,
+    phi_1p: f64,
+    phi_2: f64,
+) -> Box<dyn Fn(f64) -> [f64; 4]> {
+    Box::new(move |phi: f64| {
+        let (ps, phi) = seed_params([phi_0, phi_1n, phi_1p, phi_2], phi);
+        c1_deriv_expr(phi, ps[0], ps[1], ps[2], ps[3]).dx
     })
 }
+
+fn seed_params(ps: [f64; 4], phi: f64) -> ([Dual<4>; 4], Dual<4>) {
+    (
+        std::array::from_fn(|i| Dual::var(ps[i], i)),
+        Dual::constant(phi),
+    )
+}
+
+/// Expanded closed form of the C1 potential, generic over the scalar type
+pub fn c1_potential_expr<T: C1Scalar>(phi: T, phi_0: T, phi_1n: T, phi_1p: T, phi_2: T) -> T
+where
+    f64: Mul<T, Output = T>,
+{
+    phi.powi(2)
+        * (phi.powi(5)
+            * (-720f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                + 1440f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+                - 720f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
+                + 240f64 * phi_0.powi(3) * phi_1n.powi(2)
+                + 1440f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+                - 2160f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+                - 720f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
+                + 240f64 * phi_0.powi(3) * phi_1n * phi_1p
+                + 1080f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
+                - 180f64 * phi_0.powi(3) * phi_1n
+                - 720f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
+                + 240f64 * phi_0.powi(3) * phi_1p.powi(2)
+                + 1080f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
+                - 180f64 * phi_0.powi(3) * phi_1p
+                - 720f64 * phi_0.powi(3) * phi_2.powi(2)
+                + 360f64 * phi_0.powi(3) * phi_2
+                + 900f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
+                - 1800f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+                + 900f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+                - 300f64 * phi_0.powi(2) * phi_1n.powi(3)
+                + 900f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
+                - 1800f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                - 720f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+                + 2340f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                - 300f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
+                - 720f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
+                + 240f64 * phi_0.powi(2) * phi_1n.powi(2)
+                - 1800f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+                + 2340f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                - 300f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
+                + 3600f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                - 2160f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+                - 720f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
+                + 240f64 * phi_0.powi(2) * phi_1n * phi_1p
+                - 1800f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
+                + 1080f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
+                + 900f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+                - 300f64 * phi_0.powi(2) * phi_1p.powi(3)
+                - 720f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
+                + 240f64 * phi_0.powi(2) * phi_1p.powi(2)
+                - 1800f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
+                + 1080f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
+                + 1200f64 * phi_0.powi(2) * phi_2.powi(3)
+                - 720f64 * phi_0.powi(2) * phi_2.powi(2)
+                - 1200f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                + 900f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
+                + 3600f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                - 1800f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
+                - 1800f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
+                + 900f64 * phi_0 * phi_1n.powi(3) * phi_2
+                + 900f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
+                + 3600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                - 1800f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                - 720f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
+                - 4800f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                - 1800f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                + 2340f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
+                + 2400f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
+                - 720f64 * phi_0 * phi_1n.powi(2) * phi_2
+                + 3600f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                - 1800f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
+                - 4800f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                - 1800f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                + 2340f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
+                + 6000f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+                - 2160f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
+                - 720f64 * phi_0 * phi_1n * phi_1p * phi_2
+                - 1800f64 * phi_0 * phi_1n * phi_2.powi(3)
+                + 1080f64 * phi_0 * phi_1n * phi_2.powi(2)
+                - 1800f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
+                + 900f64 * phi_0 * phi_1p.powi(3) * phi_2
+                + 2400f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
+                - 720f64 * phi_0 * phi_1p.powi(2) * phi_2
+                - 1800f64 * phi_0 * phi_1p * phi_2.powi(3)
+                + 1080f64 * phi_0 * phi_1p * phi_2.powi(2)
+                + 3600f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                - 1200f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                - 5400f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                + 900f64 * phi_1n.powi(3) * phi_1p.powi(2)
+                + 3600f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                - 1800f64 * phi_1n.powi(3) * phi_1p * phi_2
+                - 5400f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                + 900f64 * phi_1n.powi(2) * phi_1p.powi(3)
+                + 7200f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                + 3600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                - 1800f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                - 720f64 * phi_1n.powi(2) * phi_1p.powi(2)
+                - 4800f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                + 1440f64 * phi_1n.powi(2) * phi_1p * phi_2
+                + 3600f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                - 1800f64 * phi_1n * phi_1p.powi(3) * phi_2
+                - 4800f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                + 1440f64 * phi_1n * phi_1p.powi(2) * phi_2
+                + 3600f64 * phi_1n * phi_1p * phi_2.powi(3)
+                - 2160f64 * phi_1n * phi_1p * phi_2.powi(2))
+            + phi.powi(4)
+                * (840f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 1680f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 840f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2
+                    - 280f64 * phi_0.powi(4) * phi_1n.powi(2)
+                    - 1680f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 2520f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
+                    + 840f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2
+                    - 280f64 * phi_0.powi(4) * phi_1n * phi_1p
+                    - 1260f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
+                    + 210f64 * phi_0.powi(4) * phi_1n
+                    + 840f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2
+                    - 280f64 * phi_0.powi(4) * phi_1p.powi(2)
+                    - 1260f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
+                    + 210f64 * phi_0.powi(4) * phi_1p
+                    + 840f64 * phi_0.powi(4) * phi_2.powi(2)
+                    - 420f64 * phi_0.powi(4) * phi_2
+                    + 840f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 1680f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 840f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
+                    - 280f64 * phi_0.powi(3) * phi_1n.powi(2)
+                    - 1680f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 2520f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+                    + 840f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
+                    - 280f64 * phi_0.powi(3) * phi_1n * phi_1p
+                    - 1260f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
+                    + 210f64 * phi_0.powi(3) * phi_1n
+                    + 840f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
+                    - 280f64 * phi_0.powi(3) * phi_1p.powi(2)
+                    - 1260f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
+                    + 210f64 * phi_0.powi(3) * phi_1p
+                    + 840f64 * phi_0.powi(3) * phi_2.powi(2)
+                    - 420f64 * phi_0.powi(3) * phi_2
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
+                    + 2520f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
+                    + 420f64 * phi_0.powi(2) * phi_1n.powi(4)
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 2520f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+                    + 420f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
+                    + 2520f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 1680f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 840f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
+                    - 280f64 * phi_0.powi(2) * phi_1n.powi(2)
+                    + 2520f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
+                    - 1260f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+                    + 420f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
+                    - 1680f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                    - 6300f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+                    + 2520f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+                    + 840f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
+                    - 280f64 * phi_0.powi(2) * phi_1n * phi_1p
+                    + 3150f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
+                    - 1260f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
+                    - 1260f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
+                    + 420f64 * phi_0.powi(2) * phi_1p.powi(4)
+                    + 840f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
+                    - 280f64 * phi_0.powi(2) * phi_1p.powi(2)
+                    + 3150f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
+                    - 1260f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
+                    - 2100f64 * phi_0.powi(2) * phi_2.powi(4)
+                    + 840f64 * phi_0.powi(2) * phi_2.powi(2)
+                    + 1680f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 1260f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
+                    - 5040f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
+                    + 2520f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
+                    - 1260f64 * phi_0 * phi_1n.powi(4) * phi_2
+                    + 1680f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
+                    - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 5040f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
+                    - 1260f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
+                    - 5040f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 2520f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 2520f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 1260f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 840f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
+                    + 8400f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    - 1680f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
+                    - 4200f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
+                    + 840f64 * phi_0 * phi_1n.powi(2) * phi_2
+                    - 5040f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
+                    + 2520f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 1260f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
+                    + 8400f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    - 1680f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
+                    - 10500f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+                    + 2520f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
+                    + 840f64 * phi_0 * phi_1n * phi_1p * phi_2
+                    + 3150f64 * phi_0 * phi_1n * phi_2.powi(4)
+                    - 1260f64 * phi_0 * phi_1n * phi_2.powi(2)
+                    + 2520f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
+                    - 1260f64 * phi_0 * phi_1p.powi(4) * phi_2
+                    - 4200f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
+                    + 840f64 * phi_0 * phi_1p.powi(2) * phi_2
+                    + 3150f64 * phi_0 * phi_1p * phi_2.powi(4)
+                    - 1260f64 * phi_0 * phi_1p * phi_2.powi(2)
+                    - 5040f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+                    + 1680f64 * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 7560f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 1260f64 * phi_1n.powi(4) * phi_1p.powi(2)
+                    - 5040f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 2520f64 * phi_1n.powi(4) * phi_1p * phi_2
+                    - 5040f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+                    + 1680f64 * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 7560f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 1260f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 5040f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 2520f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 7560f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 1260f64 * phi_1n.powi(2) * phi_1p.powi(4)
+                    - 5040f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 2520f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 12600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 840f64 * phi_1n.powi(2) * phi_1p.powi(2)
+                    + 8400f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    - 1680f64 * phi_1n.powi(2) * phi_1p * phi_2
+                    - 5040f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 2520f64 * phi_1n * phi_1p.powi(4) * phi_2
+                    + 8400f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    - 1680f64 * phi_1n * phi_1p.powi(2) * phi_2
+                    - 6300f64 * phi_1n * phi_1p * phi_2.powi(4)
+                    + 2520f64 * phi_1n * phi_1p * phi_2.powi(2))
+            + phi.powi(3)
+                * (-1260f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2)
+                    + 2520f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2
+                    - 1260f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
+                    + 420f64 * phi_0.powi(4) * phi_1n.powi(3)
+                    - 1260f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3)
+                    + 2520f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 1260f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 420f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
+                    + 2520f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2
+                    - 1260f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 420f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
+                    - 5040f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 2520f64 * phi_0.powi(4) * phi_1n * phi_2.powi(3)
+                    - 252f64 * phi_0.powi(4) * phi_1n
+                    - 1260f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
+                    + 420f64 * phi_0.powi(4) * phi_1p.powi(3)
+                    + 2520f64 * phi_0.powi(4) * phi_1p * phi_2.powi(3)
+                    - 252f64 * phi_0.powi(4) * phi_1p
+                    - 1680f64 * phi_0.powi(4) * phi_2.powi(3)
+                    + 504f64 * phi_0.powi(4) * phi_2
+                    + 1512f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2)
+                    - 3024f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2
+                    + 1512f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
+                    - 504f64 * phi_0.powi(3) * phi_1n.powi(4)
+                    + 1512f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 3024f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 1260f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2)
+                    + 4032f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
+                    - 504f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
+                    - 1260f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
+                    + 420f64 * phi_0.powi(3) * phi_1n.powi(3)
+                    + 1512f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4)
+                    - 3024f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 1260f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3)
+                    + 4032f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 504f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 1260f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 420f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+                    - 3024f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2
+                    + 4032f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
+                    - 504f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
+                    - 1260f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 420f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+                    + 7560f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
+                    - 5040f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+                    - 3780f64 * phi_0.powi(3) * phi_1n * phi_2.powi(4)
+                    + 2520f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
+                    + 1512f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
+                    - 504f64 * phi_0.powi(3) * phi_1p.powi(4)
+                    - 1260f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
+                    + 420f64 * phi_0.powi(3) * phi_1p.powi(3)
+                    - 3780f64 * phi_0.powi(3) * phi_1p * phi_2.powi(4)
+                    + 2520f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
+                    + 2520f64 * phi_0.powi(3) * phi_2.powi(4)
+                    - 1680f64 * phi_0.powi(3) * phi_2.powi(3)
+                    + 1512f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
+                    - 3024f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
+                    + 1512f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
+                    - 504f64 * phi_0.powi(2) * phi_1n.powi(4)
+                    + 1512f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 3024f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
+                    + 4032f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+                    - 504f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+                    + 420f64 * phi_0.powi(2) * phi_1n.powi(3)
+                    + 1512f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
+                    - 3024f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
+                    + 4032f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 504f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 1260f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 420f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
+                    - 3024f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
+                    + 4032f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+                    - 504f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
+                    - 1260f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 420f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
+                    + 7560f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+                    - 5040f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                    - 3780f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
+                    + 2520f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
+                    + 1512f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
+                    - 504f64 * phi_0.powi(2) * phi_1p.powi(4)
+                    - 1260f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+                    + 420f64 * phi_0.powi(2) * phi_1p.powi(3)
+                    - 3780f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
+                    + 2520f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
+                    + 2520f64 * phi_0.powi(2) * phi_2.powi(4)
+                    - 1680f64 * phi_0.powi(2) * phi_2.powi(3)
+                    - 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
+                    + 1512f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
+                    + 10080f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    - 3024f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
+                    - 5040f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
+                    + 1512f64 * phi_0 * phi_1n.powi(4) * phi_2
+                    + 1512f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 10080f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 3024f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
+                    - 12600f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    - 5040f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    + 4032f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
+                    + 6300f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
+                    - 1260f64 * phi_0 * phi_1n.powi(3) * phi_2
+                    + 1512f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
+                    + 10080f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 3024f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 1260f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
+                    - 12600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 5040f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 4032f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 6300f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    - 1260f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
+                    + 10080f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    - 3024f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
+                    - 12600f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    - 5040f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    + 4032f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
+                    + 6300f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    - 1260f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
+                    + 7560f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+                    - 5040f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+                    - 3780f64 * phi_0 * phi_1n * phi_2.powi(4)
+                    + 2520f64 * phi_0 * phi_1n * phi_2.powi(3)
+                    - 5040f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
+                    + 1512f64 * phi_0 * phi_1p.powi(4) * phi_2
+                    + 6300f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
+                    - 1260f64 * phi_0 * phi_1p.powi(3) * phi_2
+                    - 3780f64 * phi_0 * phi_1p * phi_2.powi(4)
+                    + 2520f64 * phi_0 * phi_1p * phi_2.powi(3)
+                    + 7560f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+                    - 2520f64 * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 15120f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 1512f64 * phi_1n.powi(4) * phi_1p.powi(2)
+                    + 10080f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    - 3024f64 * phi_1n.powi(4) * phi_1p * phi_2
+                    - 15120f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 1512f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 18900f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 10080f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 3024f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 1260f64 * phi_1n.powi(3) * phi_1p.powi(2)
+                    - 12600f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    + 2520f64 * phi_1n.powi(3) * phi_1p * phi_2
+                    - 15120f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+                    + 1512f64 * phi_1n.powi(2) * phi_1p.powi(4)
+                    + 18900f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+                    + 10080f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 3024f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 1260f64 * phi_1n.powi(2) * phi_1p.powi(3)
+                    - 12600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 2520f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 10080f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    - 3024f64 * phi_1n * phi_1p.powi(4) * phi_2
+                    - 12600f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    + 2520f64 * phi_1n * phi_1p.powi(3) * phi_2
+                    + 7560f64 * phi_1n * phi_1p * phi_2.powi(4)
+                    - 5040f64 * phi_1n * phi_1p * phi_2.powi(3))
+            + phi.powi(2)
+                * (2100f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 3150f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2.powi(2)
+                    - 525f64 * phi_0.powi(4) * phi_1n.powi(3)
+                    - 6300f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    + 3150f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    - 525f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
+                    - 4200f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(3)
+                    + 420f64 * phi_0.powi(4) * phi_1n.powi(2)
+                    - 6300f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 8400f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    + 3150f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    - 525f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
+                    - 4200f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 420f64 * phi_0.powi(4) * phi_1n * phi_1p
+                    + 3150f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 525f64 * phi_0.powi(4) * phi_1p.powi(3)
+                    - 4200f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 420f64 * phi_0.powi(4) * phi_1p.powi(2)
+                    + 2100f64 * phi_0.powi(4) * phi_2.powi(3)
+                    - 1260f64 * phi_0.powi(4) * phi_2.powi(2)
+                    - 2520f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    - 3780f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2.powi(2)
+                    + 630f64 * phi_0.powi(3) * phi_1n.powi(4)
+                    - 2520f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 2100f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 7560f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 10080f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 630f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
+                    + 3150f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2.powi(2)
+                    - 525f64 * phi_0.powi(3) * phi_1n.powi(3)
+                    + 7560f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 10080f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 630f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    + 3150f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    - 525f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+                    + 6300f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(4)
+                    - 4200f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
+                    + 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    - 10080f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 630f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
+                    - 12600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 8400f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    + 3150f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    - 525f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+                    + 6300f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
+                    - 4200f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+                    - 3780f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 630f64 * phi_0.powi(3) * phi_1p.powi(4)
+                    + 3150f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 525f64 * phi_0.powi(3) * phi_1p.powi(3)
+                    + 6300f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 4200f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 3150f64 * phi_0.powi(3) * phi_2.powi(4)
+                    + 2100f64 * phi_0.powi(3) * phi_2.powi(3)
+                    + 3150f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 2520f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    + 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 6300f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(3)
+                    - 3780f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
+                    - 2520f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 2100f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 12600f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 7560f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    + 6300f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    - 10080f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 7875f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(4)
+                    + 3150f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
+                    - 12600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 7560f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 15750f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 6300f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 10080f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 20475f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 8400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    + 3150f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    + 6300f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(4)
+                    - 4200f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
+                    - 12600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    + 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 15750f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    + 6300f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    - 10080f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 20475f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 8400f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    + 3150f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    + 6300f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+                    - 4200f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 6300f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+                    - 3780f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 7875f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+                    + 3150f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 6300f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 4200f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 3150f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    + 7560f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 6300f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
+                    - 3780f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
+                    - 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 2100f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 12600f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 7560f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 15750f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    + 6300f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    - 10080f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 7875f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
+                    + 3150f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
+                    - 12600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 7560f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 15750f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 6300f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 10080f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 20475f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 8400f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    + 3150f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    + 6300f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
+                    - 4200f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
+                    - 12600f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    + 7560f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 15750f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    + 6300f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    - 10080f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 20475f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 8400f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    + 3150f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    + 6300f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+                    - 4200f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+                    + 6300f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
+                    - 3780f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
+                    - 7875f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
+                    + 3150f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
+                    + 6300f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
+                    - 4200f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
+                    - 18900f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 3150f64 * phi_1n.powi(4) * phi_1p.powi(4)
+                    + 25200f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 2520f64 * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 12600f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    + 7560f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 25200f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
+                    - 2520f64 * phi_1n.powi(3) * phi_1p.powi(4)
+                    - 31500f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
+                    + 2100f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 12600f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 7560f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 15750f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    - 6300f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 12600f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 7560f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 15750f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 6300f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 12600f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 8400f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    - 12600f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    + 7560f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 15750f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    - 6300f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 12600f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 8400f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3))
+            + 140f64
+                * phi
+                * phi_2
+                * (-60f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 90f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 30f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
+                    + 15f64 * phi_0.powi(4) * phi_1n.powi(3)
+                    + 90f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 120f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 15f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
+                    + 40f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(2)
+                    - 12f64 * phi_0.powi(4) * phi_1n.powi(2)
+                    - 30f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 15f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
+                    + 40f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
+                    - 12f64 * phi_0.powi(4) * phi_1n * phi_1p
+                    - 30f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
+                    + 18f64 * phi_0.powi(4) * phi_1n * phi_2
+                    - 30f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
+                    + 15f64 * phi_0.powi(4) * phi_1p.powi(3)
+                    + 40f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 12f64 * phi_0.powi(4) * phi_1p.powi(2)
+                    - 30f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
+                    + 18f64 * phi_0.powi(4) * phi_1p * phi_2
+                    + 72f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 108f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
+                    + 36f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1n.powi(4)
+                    + 72f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
+                    - 108f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 90f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 36f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
+                    - 30f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
+                    + 15f64 * phi_0.powi(3) * phi_1n.powi(3)
+                    - 108f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
+                    + 90f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 180f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+                    - 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
+                    - 30f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+                    - 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+                    + 45f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1p.powi(4)
+                    - 30f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
+                    + 15f64 * phi_0.powi(3) * phi_1p.powi(3)
+                    - 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 45f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
+                    - 90f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
+                    + 72f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 180f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 108f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
+                    + 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 180f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 108f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 225f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 90f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+                    + 75f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+                    + 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 108f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
+                    - 225f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 90f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 75f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(2)
+                    - 60f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+                    + 75f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+                    - 60f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
+                    + 75f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 180f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+                    - 90f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 240f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 72f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 180f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 108f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
+                    - 240f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 300f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 180f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 108f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 225f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 90f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 108f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
+                    - 225f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 90f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 180f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+                    - 90f64 * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 240f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 72f64 * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 180f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 108f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
+                    - 240f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 72f64 * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 300f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 108f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 225f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 90f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 180f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 108f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
+                    - 225f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 90f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 120f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2))
+            + 210f64
+                * phi_0
+                * phi_1n
+                * phi_1p
+                * (60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 20f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 90f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    + 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+                    + 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
+                    - 90f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    + 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+                    + 120f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
+                    - 12f64 * phi_0.powi(3) * phi_1n * phi_1p
+                    - 80f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
+                    + 24f64 * phi_0.powi(3) * phi_1n * phi_2
+                    + 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
+                    - 80f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
+                    + 24f64 * phi_0.powi(3) * phi_1p * phi_2
+                    + 60f64 * phi_0.powi(3) * phi_2.powi(3)
+                    - 36f64 * phi_0.powi(3) * phi_2.powi(2)
+                    - 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 24f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
+                    + 108f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
+                    - 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+                    - 72f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 24f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
+                    + 108f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 38f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 162f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
+                    + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
+                    + 108f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 18f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
+                    - 162f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 15f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
+                    - 180f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+                    + 120f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
+                    + 120f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
+                    - 80f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
+                    - 72f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+                    + 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
+                    + 120f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
+                    - 80f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
+                    - 90f64 * phi_0.powi(2) * phi_2.powi(4)
+                    + 60f64 * phi_0.powi(2) * phi_2.powi(3)
+                    + 90f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 30f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 24f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
+                    - 180f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    + 108f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 120f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(3)
+                    - 72f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
+                    - 72f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 24f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
+                    - 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 108f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 20f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
+                    + 225f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 120f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    - 162f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    - 150f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
+                    + 60f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(2)
+                    - 180f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    + 108f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 225f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 120f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    - 162f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    - 330f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+                    + 120f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+                    + 60f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
+                    + 120f64 * phi_0 * phi_1n * phi_2.powi(4)
+                    - 80f64 * phi_0 * phi_1n * phi_2.powi(3)
+                    + 120f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(3)
+                    - 72f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
+                    - 150f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
+                    + 60f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(2)
+                    + 120f64 * phi_0 * phi_1p * phi_2.powi(4)
+                    - 80f64 * phi_0 * phi_1p * phi_2.powi(3)
+                    - 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 90f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    + 240f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 72f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 180f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    + 108f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 240f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 72f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 300f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 108f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 60f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 225f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    - 90f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    - 180f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    + 108f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 225f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    - 90f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    - 180f64 * phi_1n * phi_1p * phi_2.powi(4)
+                    + 120f64 * phi_1n * phi_1p * phi_2.powi(3)))
+        / (-4200f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            + 2100f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
+            + 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            - 1890f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2)
+            - 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            + 3780f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2
+            + 1050f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2.powi(2)
+            - 840f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
+            + 105f64 * phi_0.powi(4) * phi_1n.powi(3)
+            + 6300f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            - 1890f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3)
+            - 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            - 6300f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            + 3780f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            + 1680f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2)
+            + 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+            - 4200f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
+            + 105f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
+            - 1400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(3)
+            + 840f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2
+            - 140f64 * phi_0.powi(4) * phi_1n.powi(2)
+            - 6300f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            + 3780f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2
+            + 8400f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+            - 4200f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
+            + 105f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
+            - 8960f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
+            + 5040f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
+            + 840f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2
+            - 140f64 * phi_0.powi(4) * phi_1n * phi_1p
+            + 1680f64 * phi_0.powi(4) * phi_1n * phi_2.powi(3)
+            - 1260f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
+            + 42f64 * phi_0.powi(4) * phi_1n
+            + 1050f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
+            - 840f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
+            + 105f64 * phi_0.powi(4) * phi_1p.powi(3)
+            - 1400f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+            + 840f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2
+            - 140f64 * phi_0.powi(4) * phi_1p.powi(2)
+            + 1680f64 * phi_0.powi(4) * phi_1p * phi_2.powi(3)
+            - 1260f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
+            + 42f64 * phi_0.powi(4) * phi_1p
+            - 420f64 * phi_0.powi(4) * phi_2.powi(3)
+            + 420f64 * phi_0.powi(4) * phi_2.powi(2)
+            - 84f64 * phi_0.powi(4) * phi_2
+            + 5040f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+            - 2520f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+            + 2268f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2)
+            + 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+            - 4536f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2
+            - 1260f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2.powi(2)
+            + 1008f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
+            - 126f64 * phi_0.powi(3) * phi_1n.powi(4)
+            + 5040f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+            - 2520f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            - 4200f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            + 4368f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
+            + 13860f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            - 4536f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+            - 1890f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            + 4788f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
+            - 126f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
+            + 1050f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2.powi(2)
+            - 840f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
+            + 105f64 * phi_0.powi(3) * phi_1n.powi(3)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            + 2268f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4)
+            + 13860f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            - 4536f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+            - 1890f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3)
+            + 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            - 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            + 4788f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            - 246f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+            - 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+            + 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+            - 600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+            + 105f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+            + 2100f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(4)
+            - 1400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
+            + 40f64 * phi_0.powi(3) * phi_1n.powi(2)
+            + 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+            - 4536f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2
+            - 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            + 4788f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
+            - 126f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
+            - 12600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+            + 8400f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+            - 600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+            + 105f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+            + 13440f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
+            - 8960f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+            - 360f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+            - 120f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
+            + 40f64 * phi_0.powi(3) * phi_1n * phi_1p
+            - 2520f64 * phi_0.powi(3) * phi_1n * phi_2.powi(4)
+            + 1680f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
+            + 180f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
+            - 30f64 * phi_0.powi(3) * phi_1n
+            - 1260f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
+            + 1008f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
+            - 126f64 * phi_0.powi(3) * phi_1p.powi(4)
+            + 1050f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            - 840f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
+            + 105f64 * phi_0.powi(3) * phi_1p.powi(3)
+            + 2100f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+            - 1400f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
+            + 40f64 * phi_0.powi(3) * phi_1p.powi(2)
+            - 2520f64 * phi_0.powi(3) * phi_1p * phi_2.powi(4)
+            + 1680f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
+            + 180f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
+            - 30f64 * phi_0.powi(3) * phi_1p
+            + 630f64 * phi_0.powi(3) * phi_2.powi(4)
+            - 420f64 * phi_0.powi(3) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(3) * phi_2.powi(2)
+            + 60f64 * phi_0.powi(3) * phi_2
+            - 6300f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+            + 3150f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
+            + 5040f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+            - 2520f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
+            + 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+            - 252f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
+            - 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+            + 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+            + 504f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
+            + 2100f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(3)
+            - 1260f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
+            + 84f64 * phi_0.powi(2) * phi_1n.powi(4)
+            + 5040f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+            - 2520f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
+            + 12600f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            - 4200f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            + 1848f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+            - 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+            - 12600f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+            + 13860f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            + 504f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+            + 360f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
+            + 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+            + 2100f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            - 972f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+            + 84f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
+            - 2625f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(4)
+            + 1050f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
+            + 360f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+            - 120f64 * phi_0.powi(2) * phi_1n.powi(3)
+            + 12600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
+            - 15750f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+            - 12600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+            + 13860f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            + 504f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+            + 360f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
+            + 28350f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            - 6300f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            - 972f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            - 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+            - 15225f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+            + 8400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+            + 600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+            - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
+            + 2100f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(4)
+            - 1400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
+            + 40f64 * phi_0.powi(2) * phi_1n.powi(2)
+            - 12600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+            + 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+            + 504f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
+            + 15750f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+            + 2100f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            - 972f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+            + 84f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
+            - 15225f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+            + 8400f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+            + 600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+            - 120f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
+            + 840f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+            + 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+            - 360f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+            - 120f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
+            + 40f64 * phi_0.powi(2) * phi_1n * phi_1p
+            + 630f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
+            - 720f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
+            + 180f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
+            + 2100f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+            - 1260f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
+            + 84f64 * phi_0.powi(2) * phi_1p.powi(4)
+            - 2625f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+            + 1050f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            + 360f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+            - 120f64 * phi_0.powi(2) * phi_1p.powi(3)
+            + 2100f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            - 1400f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
+            + 40f64 * phi_0.powi(2) * phi_1p.powi(2)
+            + 630f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
+            - 720f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
+            + 180f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
+            - 420f64 * phi_0.powi(2) * phi_2.powi(4)
+            + 480f64 * phi_0.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(2) * phi_2.powi(2)
+            + 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
+            - 6300f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+            - 630f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
+            - 16800f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
+            + 5040f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+            + 840f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
+            + 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+            - 7560f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+            - 252f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
+            + 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+            - 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+            + 504f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
+            - 1260f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
+            + 1260f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0 * phi_1n.powi(4) * phi_2
+            - 16800f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
+            + 5040f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+            + 840f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
+            + 21000f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
+            + 12600f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+            - 7560f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            - 4200f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            - 1152f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+            - 15750f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+            + 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+            + 3780f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            + 504f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+            + 360f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
+            - 3150f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+            - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+            + 3960f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            - 972f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
+            + 1575f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
+            - 1350f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
+            + 360f64 * phi_0 * phi_1n.powi(3) * phi_2
+            + 12600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+            - 7560f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
+            - 15750f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+            + 2520f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+            + 3780f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            + 504f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+            + 360f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
+            + 9450f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            - 9660f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            + 3960f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            - 972f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
+            + 5775f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+            - 3600f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            - 1350f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+            + 600f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
+            - 2100f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
+            + 1800f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0 * phi_1n.powi(2) * phi_2
+            + 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+            - 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+            + 504f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
+            - 3150f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+            - 1260f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+            + 3960f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            - 972f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
+            + 5775f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+            - 3600f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            - 1350f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+            + 600f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
+            - 3360f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+            + 3240f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+            - 360f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
+            - 120f64 * phi_0 * phi_1n * phi_1p * phi_2
+            + 630f64 * phi_0 * phi_1n * phi_2.powi(4)
+            - 720f64 * phi_0 * phi_1n * phi_2.powi(3)
+            + 180f64 * phi_0 * phi_1n * phi_2.powi(2)
+            - 1260f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
+            + 1260f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0 * phi_1p.powi(4) * phi_2
+            + 1575f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
+            - 1350f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
+            + 360f64 * phi_0 * phi_1p.powi(3) * phi_2
+            - 2100f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
+            + 1800f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0 * phi_1p.powi(2) * phi_2
+            + 630f64 * phi_0 * phi_1p * phi_2.powi(4)
+            - 720f64 * phi_0 * phi_1p * phi_2.powi(3)
+            + 180f64 * phi_0 * phi_1p * phi_2.powi(2)
+            - 6300f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
+            + 5040f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+            - 630f64 * phi_1n.powi(4) * phi_1p.powi(4)
+            + 8400f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
+            - 5040f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+            + 840f64 * phi_1n.powi(4) * phi_1p.powi(3)
+            - 10080f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+            + 7560f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+            - 252f64 * phi_1n.powi(4) * phi_1p.powi(2)
+            + 2520f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+            - 2520f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+            + 504f64 * phi_1n.powi(4) * phi_1p * phi_2
+            + 8400f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
+            - 5040f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+            + 840f64 * phi_1n.powi(3) * phi_1p.powi(4)
+            - 10500f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
+            - 10080f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+            + 7560f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            + 4800f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            - 1152f64 * phi_1n.powi(3) * phi_1p.powi(3)
+            + 12600f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+            + 2520f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+            - 9720f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            + 504f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+            + 360f64 * phi_1n.powi(3) * phi_1p.powi(2)
+            - 3150f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+            + 2700f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            - 720f64 * phi_1n.powi(3) * phi_1p * phi_2
+            - 10080f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+            + 7560f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_1n.powi(2) * phi_1p.powi(4)
+            + 12600f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+            + 2520f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+            - 9720f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            + 504f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+            + 360f64 * phi_1n.powi(2) * phi_1p.powi(3)
+            - 15750f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            + 9600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            + 2700f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            - 720f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            - 120f64 * phi_1n.powi(2) * phi_1p.powi(2)
+            + 4200f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+            - 3600f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            + 240f64 * phi_1n.powi(2) * phi_1p * phi_2
+            + 2520f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+            - 2520f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+            + 504f64 * phi_1n * phi_1p.powi(4) * phi_2
+            - 3150f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+            + 2700f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            - 720f64 * phi_1n * phi_1p.powi(3) * phi_2
+            + 4200f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+            - 3600f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            + 240f64 * phi_1n * phi_1p.powi(2) * phi_2
+            - 1260f64 * phi_1n * phi_1p * phi_2.powi(4)
+            + 1440f64 * phi_1n * phi_1p * phi_2.powi(3)
+            - 360f64 * phi_1n * phi_1p * phi_2.powi(2))
+}
+
+/// Expanded closed form of dV/dφ for the C1 potential, generic over the scalar type
+pub fn c1_deriv_expr<T: C1Scalar>(phi: T, phi_0: T, phi_1n: T, phi_1p: T, phi_2: T) -> T
+where
+    f64: Mul<T, Output = T>,
+{
+    420f64
+        * phi
+        * (phi.powi(5)
+            * (-12f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                + 24f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+                - 12f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
+                + 4f64 * phi_0.powi(3) * phi_1n.powi(2)
+                + 24f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+                - 36f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+                - 12f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
+                + 4f64 * phi_0.powi(3) * phi_1n * phi_1p
+                + 18f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
+                - 3f64 * phi_0.powi(3) * phi_1n
+                - 12f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
+                + 4f64 * phi_0.powi(3) * phi_1p.powi(2)
+                + 18f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
+                - 3f64 * phi_0.powi(3) * phi_1p
+                - 12f64 * phi_0.powi(3) * phi_2.powi(2)
+                + 6f64 * phi_0.powi(3) * phi_2
+                + 15f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
+                - 30f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+                + 15f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+                - 5f64 * phi_0.powi(2) * phi_1n.powi(3)
+                + 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
+                - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                - 12f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+                + 39f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                - 5f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
+                - 12f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
+                + 4f64 * phi_0.powi(2) * phi_1n.powi(2)
+                - 30f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+                + 39f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                - 5f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
+                + 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                - 36f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+                - 12f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
+                + 4f64 * phi_0.powi(2) * phi_1n * phi_1p
+                - 30f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
+                + 18f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
+                + 15f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+                - 5f64 * phi_0.powi(2) * phi_1p.powi(3)
+                - 12f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
+                + 4f64 * phi_0.powi(2) * phi_1p.powi(2)
+                - 30f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
+                + 18f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
+                + 20f64 * phi_0.powi(2) * phi_2.powi(3)
+                - 12f64 * phi_0.powi(2) * phi_2.powi(2)
+                - 20f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                + 15f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
+                + 60f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                - 30f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
+                - 30f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
+                + 15f64 * phi_0 * phi_1n.powi(3) * phi_2
+                + 15f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
+                + 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                - 30f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                - 12f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
+                - 80f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                - 30f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                + 39f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
+                + 40f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
+                - 12f64 * phi_0 * phi_1n.powi(2) * phi_2
+                + 60f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                - 30f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
+                - 80f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                - 30f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                + 39f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
+                + 100f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+                - 36f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
+                - 12f64 * phi_0 * phi_1n * phi_1p * phi_2
+                - 30f64 * phi_0 * phi_1n * phi_2.powi(3)
+                + 18f64 * phi_0 * phi_1n * phi_2.powi(2)
+                - 30f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
+                + 15f64 * phi_0 * phi_1p.powi(3) * phi_2
+                + 40f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
+                - 12f64 * phi_0 * phi_1p.powi(2) * phi_2
+                - 30f64 * phi_0 * phi_1p * phi_2.powi(3)
+                + 18f64 * phi_0 * phi_1p * phi_2.powi(2)
+                + 60f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                - 20f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                - 90f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                + 15f64 * phi_1n.powi(3) * phi_1p.powi(2)
+                + 60f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                - 30f64 * phi_1n.powi(3) * phi_1p * phi_2
+                - 90f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                + 15f64 * phi_1n.powi(2) * phi_1p.powi(3)
+                + 120f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                + 60f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                - 30f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                - 12f64 * phi_1n.powi(2) * phi_1p.powi(2)
+                - 80f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                + 24f64 * phi_1n.powi(2) * phi_1p * phi_2
+                + 60f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                - 30f64 * phi_1n * phi_1p.powi(3) * phi_2
+                - 80f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                + 24f64 * phi_1n * phi_1p.powi(2) * phi_2
+                + 60f64 * phi_1n * phi_1p * phi_2.powi(3)
+                - 36f64 * phi_1n * phi_1p * phi_2.powi(2))
+            + phi.powi(4)
+                * (12f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 24f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 12f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2
+                    - 4f64 * phi_0.powi(4) * phi_1n.powi(2)
+                    - 24f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 36f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
+                    + 12f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2
+                    - 4f64 * phi_0.powi(4) * phi_1n * phi_1p
+                    - 18f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
+                    + 3f64 * phi_0.powi(4) * phi_1n
+                    + 12f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2
+                    - 4f64 * phi_0.powi(4) * phi_1p.powi(2)
+                    - 18f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
+                    + 3f64 * phi_0.powi(4) * phi_1p
+                    + 12f64 * phi_0.powi(4) * phi_2.powi(2)
+                    - 6f64 * phi_0.powi(4) * phi_2
+                    + 12f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 24f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 12f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
+                    - 4f64 * phi_0.powi(3) * phi_1n.powi(2)
+                    - 24f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 36f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+                    + 12f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
+                    - 4f64 * phi_0.powi(3) * phi_1n * phi_1p
+                    - 18f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
+                    + 3f64 * phi_0.powi(3) * phi_1n
+                    + 12f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
+                    - 4f64 * phi_0.powi(3) * phi_1p.powi(2)
+                    - 18f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
+                    + 3f64 * phi_0.powi(3) * phi_1p
+                    + 12f64 * phi_0.powi(3) * phi_2.powi(2)
+                    - 6f64 * phi_0.powi(3) * phi_2
+                    - 18f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
+                    - 18f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
+                    + 6f64 * phi_0.powi(2) * phi_1n.powi(4)
+                    - 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+                    + 6f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
+                    - 18f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 18f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 18f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 24f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 12f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
+                    - 4f64 * phi_0.powi(2) * phi_1n.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
+                    - 18f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+                    + 6f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
+                    - 24f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                    - 90f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+                    + 36f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+                    + 12f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
+                    - 4f64 * phi_0.powi(2) * phi_1n * phi_1p
+                    + 45f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
+                    - 18f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
+                    - 18f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
+                    + 6f64 * phi_0.powi(2) * phi_1p.powi(4)
+                    + 12f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
+                    - 4f64 * phi_0.powi(2) * phi_1p.powi(2)
+                    + 45f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
+                    - 18f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(2) * phi_2.powi(4)
+                    + 12f64 * phi_0.powi(2) * phi_2.powi(2)
+                    + 24f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 18f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
+                    - 72f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 36f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
+                    + 36f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
+                    - 18f64 * phi_0 * phi_1n.powi(4) * phi_2
+                    + 24f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
+                    - 18f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 36f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 18f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
+                    - 18f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
+                    - 72f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 36f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 36f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 18f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 12f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
+                    + 120f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    - 24f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
+                    - 60f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
+                    + 12f64 * phi_0 * phi_1n.powi(2) * phi_2
+                    - 72f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 36f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
+                    + 36f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 18f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
+                    + 120f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    - 24f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
+                    - 150f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+                    + 36f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
+                    + 12f64 * phi_0 * phi_1n * phi_1p * phi_2
+                    + 45f64 * phi_0 * phi_1n * phi_2.powi(4)
+                    - 18f64 * phi_0 * phi_1n * phi_2.powi(2)
+                    + 36f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
+                    - 18f64 * phi_0 * phi_1p.powi(4) * phi_2
+                    - 60f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
+                    + 12f64 * phi_0 * phi_1p.powi(2) * phi_2
+                    + 45f64 * phi_0 * phi_1p * phi_2.powi(4)
+                    - 18f64 * phi_0 * phi_1p * phi_2.powi(2)
+                    - 72f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+                    + 24f64 * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 108f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 18f64 * phi_1n.powi(4) * phi_1p.powi(2)
+                    - 72f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 36f64 * phi_1n.powi(4) * phi_1p * phi_2
+                    - 72f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+                    + 24f64 * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 108f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 18f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 72f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 108f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 18f64 * phi_1n.powi(2) * phi_1p.powi(4)
+                    - 72f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 36f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 12f64 * phi_1n.powi(2) * phi_1p.powi(2)
+                    + 120f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    - 24f64 * phi_1n.powi(2) * phi_1p * phi_2
+                    - 72f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 36f64 * phi_1n * phi_1p.powi(4) * phi_2
+                    + 120f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    - 24f64 * phi_1n * phi_1p.powi(2) * phi_2
+                    - 90f64 * phi_1n * phi_1p * phi_2.powi(4)
+                    + 36f64 * phi_1n * phi_1p * phi_2.powi(2))
+            + phi.powi(3)
+                * (-15f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2)
+                    + 30f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2
+                    - 15f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
+                    + 5f64 * phi_0.powi(4) * phi_1n.powi(3)
+                    - 15f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3)
+                    + 30f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 15f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 5f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
+                    + 30f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2
+                    - 15f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 5f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
+                    - 60f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(4) * phi_1n * phi_2.powi(3)
+                    - 3f64 * phi_0.powi(4) * phi_1n
+                    - 15f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
+                    + 5f64 * phi_0.powi(4) * phi_1p.powi(3)
+                    + 30f64 * phi_0.powi(4) * phi_1p * phi_2.powi(3)
+                    - 3f64 * phi_0.powi(4) * phi_1p
+                    - 20f64 * phi_0.powi(4) * phi_2.powi(3)
+                    + 6f64 * phi_0.powi(4) * phi_2
+                    + 18f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2)
+                    - 36f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2
+                    + 18f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
+                    - 6f64 * phi_0.powi(3) * phi_1n.powi(4)
+                    + 18f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 36f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 15f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2)
+                    + 48f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
+                    - 6f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
+                    - 15f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
+                    + 5f64 * phi_0.powi(3) * phi_1n.powi(3)
+                    + 18f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4)
+                    - 36f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3)
+                    + 48f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 6f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 5f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+                    - 36f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2
+                    + 48f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
+                    - 6f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
+                    - 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 5f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+                    + 90f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
+                    - 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+                    - 45f64 * phi_0.powi(3) * phi_1n * phi_2.powi(4)
+                    + 30f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
+                    + 18f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
+                    - 6f64 * phi_0.powi(3) * phi_1p.powi(4)
+                    - 15f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
+                    + 5f64 * phi_0.powi(3) * phi_1p.powi(3)
+                    - 45f64 * phi_0.powi(3) * phi_1p * phi_2.powi(4)
+                    + 30f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(3) * phi_2.powi(4)
+                    - 20f64 * phi_0.powi(3) * phi_2.powi(3)
+                    + 18f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
+                    - 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
+                    + 18f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
+                    - 6f64 * phi_0.powi(2) * phi_1n.powi(4)
+                    + 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 15f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
+                    + 48f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+                    - 6f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
+                    - 15f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+                    + 5f64 * phi_0.powi(2) * phi_1n.powi(3)
+                    + 18f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
+                    - 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
+                    + 48f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 6f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 5f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
+                    - 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
+                    + 48f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+                    - 6f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
+                    - 15f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 5f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
+                    + 90f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+                    - 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                    - 45f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
+                    + 30f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
+                    + 18f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
+                    - 6f64 * phi_0.powi(2) * phi_1p.powi(4)
+                    - 15f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+                    + 5f64 * phi_0.powi(2) * phi_1p.powi(3)
+                    - 45f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
+                    + 30f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(2) * phi_2.powi(4)
+                    - 20f64 * phi_0.powi(2) * phi_2.powi(3)
+                    - 30f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
+                    + 18f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
+                    + 120f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    - 36f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
+                    - 60f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
+                    + 18f64 * phi_0 * phi_1n.powi(4) * phi_2
+                    + 18f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 120f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 36f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 15f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
+                    - 150f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    - 60f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    + 48f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
+                    + 75f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
+                    - 15f64 * phi_0 * phi_1n.powi(3) * phi_2
+                    + 18f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
+                    + 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 36f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 15f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
+                    - 150f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 48f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 75f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    - 15f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
+                    + 120f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    - 36f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
+                    - 150f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    - 60f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    + 48f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
+                    + 75f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    - 15f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
+                    + 90f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+                    - 60f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+                    - 45f64 * phi_0 * phi_1n * phi_2.powi(4)
+                    + 30f64 * phi_0 * phi_1n * phi_2.powi(3)
+                    - 60f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
+                    + 18f64 * phi_0 * phi_1p.powi(4) * phi_2
+                    + 75f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
+                    - 15f64 * phi_0 * phi_1p.powi(3) * phi_2
+                    - 45f64 * phi_0 * phi_1p * phi_2.powi(4)
+                    + 30f64 * phi_0 * phi_1p * phi_2.powi(3)
+                    + 90f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+                    - 30f64 * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 180f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 18f64 * phi_1n.powi(4) * phi_1p.powi(2)
+                    + 120f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    - 36f64 * phi_1n.powi(4) * phi_1p * phi_2
+                    - 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 18f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 225f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 120f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 36f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 15f64 * phi_1n.powi(3) * phi_1p.powi(2)
+                    - 150f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    + 30f64 * phi_1n.powi(3) * phi_1p * phi_2
+                    - 180f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+                    + 18f64 * phi_1n.powi(2) * phi_1p.powi(4)
+                    + 225f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+                    + 120f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 36f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 15f64 * phi_1n.powi(2) * phi_1p.powi(3)
+                    - 150f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 30f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 120f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    - 36f64 * phi_1n * phi_1p.powi(4) * phi_2
+                    - 150f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    + 30f64 * phi_1n * phi_1p.powi(3) * phi_2
+                    + 90f64 * phi_1n * phi_1p * phi_2.powi(4)
+                    - 60f64 * phi_1n * phi_1p * phi_2.powi(3))
+            + phi.powi(2)
+                * (20f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 60f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 30f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2.powi(2)
+                    - 5f64 * phi_0.powi(4) * phi_1n.powi(3)
+                    - 60f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 80f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    - 5f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
+                    - 40f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(3)
+                    + 4f64 * phi_0.powi(4) * phi_1n.powi(2)
+                    - 60f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 80f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    - 5f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
+                    - 40f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 4f64 * phi_0.powi(4) * phi_1n * phi_1p
+                    + 30f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 5f64 * phi_0.powi(4) * phi_1p.powi(3)
+                    - 40f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 4f64 * phi_0.powi(4) * phi_1p.powi(2)
+                    + 20f64 * phi_0.powi(4) * phi_2.powi(3)
+                    - 12f64 * phi_0.powi(4) * phi_2.powi(2)
+                    - 24f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 72f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    - 36f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2.powi(2)
+                    + 6f64 * phi_0.powi(3) * phi_1n.powi(4)
+                    - 24f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 20f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 72f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 96f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 6f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
+                    + 30f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2.powi(2)
+                    - 5f64 * phi_0.powi(3) * phi_1n.powi(3)
+                    + 72f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 96f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 6f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 80f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    - 5f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+                    + 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(4)
+                    - 40f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
+                    + 72f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    - 96f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 6f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
+                    - 120f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 80f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    - 5f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+                    + 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
+                    - 40f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+                    - 36f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 6f64 * phi_0.powi(3) * phi_1p.powi(4)
+                    + 30f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 5f64 * phi_0.powi(3) * phi_1p.powi(3)
+                    + 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 40f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(3) * phi_2.powi(4)
+                    + 20f64 * phi_0.powi(3) * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 24f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 120f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    + 72f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 60f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(3)
+                    - 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
+                    - 24f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 20f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 120f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 150f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    + 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    - 96f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 75f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(4)
+                    + 30f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
+                    - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 72f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 150f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 96f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 195f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 80f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(4)
+                    - 40f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
+                    - 120f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    + 72f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 150f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    + 60f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    - 96f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 195f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 80f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    + 30f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    + 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+                    - 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 60f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+                    - 36f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 75f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+                    + 30f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 40f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 30f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 24f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 120f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    + 72f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 60f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
+                    - 36f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
+                    - 24f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 20f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 120f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 150f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    + 60f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    - 96f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 75f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
+                    + 30f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
+                    - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 72f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 150f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    + 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 96f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 195f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 80f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    + 30f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    + 60f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
+                    - 40f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
+                    - 120f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    + 72f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 150f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    + 60f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    - 96f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 195f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 80f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    + 30f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    + 60f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+                    - 40f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+                    + 60f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
+                    - 36f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
+                    - 75f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
+                    + 30f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
+                    + 60f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
+                    - 40f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
+                    - 180f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 30f64 * phi_1n.powi(4) * phi_1p.powi(4)
+                    + 240f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 24f64 * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 120f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+                    + 72f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+                    + 240f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
+                    - 24f64 * phi_1n.powi(3) * phi_1p.powi(4)
+                    - 300f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
+                    + 20f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 120f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 72f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 150f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+                    - 60f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 120f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 72f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 150f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 60f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 120f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 80f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    - 120f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+                    + 72f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+                    + 150f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+                    - 60f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 120f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 80f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3))
+            + phi
+                * phi_2
+                * (-60f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 90f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 30f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
+                    + 15f64 * phi_0.powi(4) * phi_1n.powi(3)
+                    + 90f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 120f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 15f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
+                    + 40f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(2)
+                    - 12f64 * phi_0.powi(4) * phi_1n.powi(2)
+                    - 30f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 15f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
+                    + 40f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
+                    - 12f64 * phi_0.powi(4) * phi_1n * phi_1p
+                    - 30f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
+                    + 18f64 * phi_0.powi(4) * phi_1n * phi_2
+                    - 30f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
+                    + 15f64 * phi_0.powi(4) * phi_1p.powi(3)
+                    + 40f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 12f64 * phi_0.powi(4) * phi_1p.powi(2)
+                    - 30f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
+                    + 18f64 * phi_0.powi(4) * phi_1p * phi_2
+                    + 72f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
+                    - 108f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
+                    + 36f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1n.powi(4)
+                    + 72f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
+                    - 108f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
+                    + 90f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 36f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
+                    - 30f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
+                    + 15f64 * phi_0.powi(3) * phi_1n.powi(3)
+                    - 108f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
+                    + 90f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 180f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+                    - 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
+                    - 30f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+                    - 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+                    + 45f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
+                    - 18f64 * phi_0.powi(3) * phi_1p.powi(4)
+                    - 30f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
+                    + 15f64 * phi_0.powi(3) * phi_1p.powi(3)
+                    - 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 45f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
+                    - 90f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
+                    + 72f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 180f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 108f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
+                    + 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 180f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 108f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 225f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 90f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+                    + 75f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+                    + 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 108f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
+                    - 225f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 90f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 180f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 75f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(2)
+                    - 60f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+                    + 75f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+                    - 60f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
+                    + 75f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 30f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 40f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 180f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+                    - 90f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 240f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 72f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 180f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 108f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
+                    - 240f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 300f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 180f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 108f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 225f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 90f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 108f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
+                    - 225f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 90f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 180f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+                    - 90f64 * phi_1n.powi(4) * phi_1p.powi(4)
+                    - 240f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 72f64 * phi_1n.powi(4) * phi_1p.powi(3)
+                    + 180f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 108f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2
+                    - 240f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
+                    + 72f64 * phi_1n.powi(3) * phi_1p.powi(4)
+                    + 300f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    - 108f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 60f64 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 225f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 90f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 180f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+                    - 108f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2
+                    - 225f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    + 90f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 120f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2))
+            + phi_0
+                * phi_1n
+                * phi_1p
+                * (60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 20f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 90f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    + 15f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+                    + 60f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
+                    - 90f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    + 15f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+                    + 120f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 60f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
+                    - 12f64 * phi_0.powi(3) * phi_1n * phi_1p
+                    - 80f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
+                    + 24f64 * phi_0.powi(3) * phi_1n * phi_2
+                    + 60f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
+                    - 80f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
+                    + 24f64 * phi_0.powi(3) * phi_1p * phi_2
+                    + 60f64 * phi_0.powi(3) * phi_2.powi(3)
+                    - 36f64 * phi_0.powi(3) * phi_2.powi(2)
+                    - 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 24f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
+                    + 108f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    - 18f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
+                    - 72f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+                    - 72f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 24f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
+                    + 108f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 38f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+                    - 162f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+                    + 15f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
+                    + 60f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
+                    + 108f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    - 18f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
+                    - 162f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+                    + 15f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
+                    - 180f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+                    + 120f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+                    + 60f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
+                    + 120f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
+                    - 80f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
+                    - 72f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 36f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+                    + 60f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    - 30f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
+                    + 120f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
+                    - 80f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
+                    - 90f64 * phi_0.powi(2) * phi_2.powi(4)
+                    + 60f64 * phi_0.powi(2) * phi_2.powi(3)
+                    + 90f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    - 30f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+                    - 72f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    + 24f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
+                    - 180f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    + 108f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 120f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(3)
+                    - 72f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
+                    - 72f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    + 24f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
+                    - 180f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 108f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 60f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    - 20f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
+                    + 225f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    + 120f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+                    - 162f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    - 150f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
+                    + 60f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(2)
+                    - 180f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    + 108f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 225f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    + 120f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+                    - 162f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    - 330f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+                    + 120f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+                    + 60f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
+                    + 120f64 * phi_0 * phi_1n * phi_2.powi(4)
+                    - 80f64 * phi_0 * phi_1n * phi_2.powi(3)
+                    + 120f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(3)
+                    - 72f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
+                    - 150f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
+                    + 60f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(2)
+                    + 120f64 * phi_0 * phi_1p * phi_2.powi(4)
+                    - 80f64 * phi_0 * phi_1p * phi_2.powi(3)
+                    - 180f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+                    + 90f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+                    + 240f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+                    - 72f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+                    - 180f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+                    + 108f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+                    + 240f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+                    - 72f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+                    - 300f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+                    - 180f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+                    + 108f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+                    + 60f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+                    + 225f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+                    - 90f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+                    - 180f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+                    + 108f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+                    + 225f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+                    - 90f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+                    - 180f64 * phi_1n * phi_1p * phi_2.powi(4)
+                    + 120f64 * phi_1n * phi_1p * phi_2.powi(3)))
+        / (-4200f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            + 2100f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(3)
+            + 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            - 1890f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p.powi(2)
+            - 6300f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            + 3780f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_1p * phi_2
+            + 1050f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2.powi(2)
+            - 840f64 * phi_0.powi(4) * phi_1n.powi(3) * phi_2
+            + 105f64 * phi_0.powi(4) * phi_1n.powi(3)
+            + 6300f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            - 1890f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(3)
+            - 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            - 6300f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            + 3780f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            + 1680f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p.powi(2)
+            + 8400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+            - 4200f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p * phi_2
+            + 105f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_1p
+            - 1400f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2.powi(3)
+            + 840f64 * phi_0.powi(4) * phi_1n.powi(2) * phi_2
+            - 140f64 * phi_0.powi(4) * phi_1n.powi(2)
+            - 6300f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            + 3780f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(3) * phi_2
+            + 8400f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+            - 4200f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2) * phi_2
+            + 105f64 * phi_0.powi(4) * phi_1n * phi_1p.powi(2)
+            - 8960f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(3)
+            + 5040f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2.powi(2)
+            + 840f64 * phi_0.powi(4) * phi_1n * phi_1p * phi_2
+            - 140f64 * phi_0.powi(4) * phi_1n * phi_1p
+            + 1680f64 * phi_0.powi(4) * phi_1n * phi_2.powi(3)
+            - 1260f64 * phi_0.powi(4) * phi_1n * phi_2.powi(2)
+            + 42f64 * phi_0.powi(4) * phi_1n
+            + 1050f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2.powi(2)
+            - 840f64 * phi_0.powi(4) * phi_1p.powi(3) * phi_2
+            + 105f64 * phi_0.powi(4) * phi_1p.powi(3)
+            - 1400f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+            + 840f64 * phi_0.powi(4) * phi_1p.powi(2) * phi_2
+            - 140f64 * phi_0.powi(4) * phi_1p.powi(2)
+            + 1680f64 * phi_0.powi(4) * phi_1p * phi_2.powi(3)
+            - 1260f64 * phi_0.powi(4) * phi_1p * phi_2.powi(2)
+            + 42f64 * phi_0.powi(4) * phi_1p
+            - 420f64 * phi_0.powi(4) * phi_2.powi(3)
+            + 420f64 * phi_0.powi(4) * phi_2.powi(2)
+            - 84f64 * phi_0.powi(4) * phi_2
+            + 5040f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+            - 2520f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(3)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+            + 2268f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p.powi(2)
+            + 7560f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+            - 4536f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_1p * phi_2
+            - 1260f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2.powi(2)
+            + 1008f64 * phi_0.powi(3) * phi_1n.powi(4) * phi_2
+            - 126f64 * phi_0.powi(3) * phi_1n.powi(4)
+            + 5040f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+            - 2520f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(4)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            - 4200f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            + 4368f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(3)
+            + 13860f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            - 4536f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+            - 1890f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p.powi(2)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            + 4788f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p * phi_2
+            - 126f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_1p
+            + 1050f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2.powi(2)
+            - 840f64 * phi_0.powi(3) * phi_1n.powi(3) * phi_2
+            + 105f64 * phi_0.powi(3) * phi_1n.powi(3)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            + 2268f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(4)
+            + 13860f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            - 4536f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+            - 1890f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(3)
+            + 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            - 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            + 4788f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            - 246f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p.powi(2)
+            - 12600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+            + 8400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+            - 600f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p * phi_2
+            + 105f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_1p
+            + 2100f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(4)
+            - 1400f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(3) * phi_1n.powi(2) * phi_2
+            + 40f64 * phi_0.powi(3) * phi_1n.powi(2)
+            + 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+            - 4536f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(4) * phi_2
+            - 7560f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            + 4788f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3) * phi_2
+            - 126f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(3)
+            - 12600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+            + 8400f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+            - 600f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2) * phi_2
+            + 105f64 * phi_0.powi(3) * phi_1n * phi_1p.powi(2)
+            + 13440f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(4)
+            - 8960f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(3)
+            - 360f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2.powi(2)
+            - 120f64 * phi_0.powi(3) * phi_1n * phi_1p * phi_2
+            + 40f64 * phi_0.powi(3) * phi_1n * phi_1p
+            - 2520f64 * phi_0.powi(3) * phi_1n * phi_2.powi(4)
+            + 1680f64 * phi_0.powi(3) * phi_1n * phi_2.powi(3)
+            + 180f64 * phi_0.powi(3) * phi_1n * phi_2.powi(2)
+            - 30f64 * phi_0.powi(3) * phi_1n
+            - 1260f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2.powi(2)
+            + 1008f64 * phi_0.powi(3) * phi_1p.powi(4) * phi_2
+            - 126f64 * phi_0.powi(3) * phi_1p.powi(4)
+            + 1050f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            - 840f64 * phi_0.powi(3) * phi_1p.powi(3) * phi_2
+            + 105f64 * phi_0.powi(3) * phi_1p.powi(3)
+            + 2100f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+            - 1400f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(3) * phi_1p.powi(2) * phi_2
+            + 40f64 * phi_0.powi(3) * phi_1p.powi(2)
+            - 2520f64 * phi_0.powi(3) * phi_1p * phi_2.powi(4)
+            + 1680f64 * phi_0.powi(3) * phi_1p * phi_2.powi(3)
+            + 180f64 * phi_0.powi(3) * phi_1p * phi_2.powi(2)
+            - 30f64 * phi_0.powi(3) * phi_1p
+            + 630f64 * phi_0.powi(3) * phi_2.powi(4)
+            - 420f64 * phi_0.powi(3) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(3) * phi_2.powi(2)
+            + 60f64 * phi_0.powi(3) * phi_2
+            - 6300f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+            + 3150f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(4)
+            + 5040f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+            - 2520f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(3)
+            + 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+            - 252f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p.powi(2)
+            - 12600f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+            + 7560f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+            + 504f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_1p * phi_2
+            + 2100f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(3)
+            - 1260f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0.powi(2) * phi_1n.powi(4) * phi_2
+            + 84f64 * phi_0.powi(2) * phi_1n.powi(4)
+            + 5040f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+            - 2520f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(4)
+            + 12600f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            - 4200f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            + 1848f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(3)
+            - 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+            - 12600f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+            + 13860f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            + 504f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+            + 360f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p.powi(2)
+            + 15750f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+            + 2100f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            - 972f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p * phi_2
+            + 84f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_1p
+            - 2625f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(4)
+            + 1050f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2.powi(2)
+            + 360f64 * phi_0.powi(2) * phi_1n.powi(3) * phi_2
+            - 120f64 * phi_0.powi(2) * phi_1n.powi(3)
+            + 12600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(4)
+            - 15750f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+            - 12600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+            + 13860f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            + 504f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+            + 360f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(3)
+            + 28350f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            - 6300f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            - 972f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            - 36f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p.powi(2)
+            - 15225f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+            + 8400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+            + 600f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p * phi_2
+            - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_1p
+            + 2100f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(4)
+            - 1400f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(2) * phi_1n.powi(2) * phi_2
+            + 40f64 * phi_0.powi(2) * phi_1n.powi(2)
+            - 12600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+            + 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+            + 504f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(4) * phi_2
+            + 15750f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+            + 2100f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+            - 7560f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            - 972f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3) * phi_2
+            + 84f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(3)
+            - 15225f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+            + 8400f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            + 1050f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+            + 600f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2) * phi_2
+            - 120f64 * phi_0.powi(2) * phi_1n * phi_1p.powi(2)
+            + 840f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(4)
+            + 40f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(3)
+            - 360f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2.powi(2)
+            - 120f64 * phi_0.powi(2) * phi_1n * phi_1p * phi_2
+            + 40f64 * phi_0.powi(2) * phi_1n * phi_1p
+            + 630f64 * phi_0.powi(2) * phi_1n * phi_2.powi(4)
+            - 720f64 * phi_0.powi(2) * phi_1n * phi_2.powi(3)
+            + 180f64 * phi_0.powi(2) * phi_1n * phi_2.powi(2)
+            + 2100f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+            - 1260f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0.powi(2) * phi_1p.powi(4) * phi_2
+            + 84f64 * phi_0.powi(2) * phi_1p.powi(4)
+            - 2625f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+            + 1050f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            + 360f64 * phi_0.powi(2) * phi_1p.powi(3) * phi_2
+            - 120f64 * phi_0.powi(2) * phi_1p.powi(3)
+            + 2100f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            - 1400f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(2) * phi_1p.powi(2) * phi_2
+            + 40f64 * phi_0.powi(2) * phi_1p.powi(2)
+            + 630f64 * phi_0.powi(2) * phi_1p * phi_2.powi(4)
+            - 720f64 * phi_0.powi(2) * phi_1p * phi_2.powi(3)
+            + 180f64 * phi_0.powi(2) * phi_1p * phi_2.powi(2)
+            - 420f64 * phi_0.powi(2) * phi_2.powi(4)
+            + 480f64 * phi_0.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0.powi(2) * phi_2.powi(2)
+            + 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
+            - 6300f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+            - 630f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(4)
+            - 16800f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
+            + 5040f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+            + 840f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(3)
+            + 12600f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+            - 7560f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+            - 252f64 * phi_0 * phi_1n.powi(4) * phi_1p.powi(2)
+            + 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+            - 2520f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+            + 504f64 * phi_0 * phi_1n.powi(4) * phi_1p * phi_2
+            - 1260f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(3)
+            + 1260f64 * phi_0 * phi_1n.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0 * phi_1n.powi(4) * phi_2
+            - 16800f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
+            + 5040f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+            + 840f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(4)
+            + 21000f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
+            + 12600f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+            - 7560f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            - 4200f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            - 1152f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(3)
+            - 15750f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+            + 2520f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+            + 3780f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            + 504f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+            + 360f64 * phi_0 * phi_1n.powi(3) * phi_1p.powi(2)
+            - 3150f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+            - 1260f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(3)
+            + 3960f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            - 972f64 * phi_0 * phi_1n.powi(3) * phi_1p * phi_2
+            + 1575f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(4)
+            - 1350f64 * phi_0 * phi_1n.powi(3) * phi_2.powi(2)
+            + 360f64 * phi_0 * phi_1n.powi(3) * phi_2
+            + 12600f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+            - 7560f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(4)
+            - 15750f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+            + 2520f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+            + 3780f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            + 504f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+            + 360f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(3)
+            + 9450f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            - 9660f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            + 3960f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            - 972f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            - 120f64 * phi_0 * phi_1n.powi(2) * phi_1p.powi(2)
+            + 5775f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+            - 3600f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            - 1350f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2.powi(2)
+            + 600f64 * phi_0 * phi_1n.powi(2) * phi_1p * phi_2
+            - 2100f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(4)
+            + 1800f64 * phi_0 * phi_1n.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0 * phi_1n.powi(2) * phi_2
+            + 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+            - 2520f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+            + 504f64 * phi_0 * phi_1n * phi_1p.powi(4) * phi_2
+            - 3150f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+            - 1260f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(3)
+            + 3960f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            - 972f64 * phi_0 * phi_1n * phi_1p.powi(3) * phi_2
+            + 5775f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+            - 3600f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            - 1350f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2.powi(2)
+            + 600f64 * phi_0 * phi_1n * phi_1p.powi(2) * phi_2
+            - 3360f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(4)
+            + 3240f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(3)
+            - 360f64 * phi_0 * phi_1n * phi_1p * phi_2.powi(2)
+            - 120f64 * phi_0 * phi_1n * phi_1p * phi_2
+            + 630f64 * phi_0 * phi_1n * phi_2.powi(4)
+            - 720f64 * phi_0 * phi_1n * phi_2.powi(3)
+            + 180f64 * phi_0 * phi_1n * phi_2.powi(2)
+            - 1260f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(3)
+            + 1260f64 * phi_0 * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_0 * phi_1p.powi(4) * phi_2
+            + 1575f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(4)
+            - 1350f64 * phi_0 * phi_1p.powi(3) * phi_2.powi(2)
+            + 360f64 * phi_0 * phi_1p.powi(3) * phi_2
+            - 2100f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(4)
+            + 1800f64 * phi_0 * phi_1p.powi(2) * phi_2.powi(3)
+            - 120f64 * phi_0 * phi_1p.powi(2) * phi_2
+            + 630f64 * phi_0 * phi_1p * phi_2.powi(4)
+            - 720f64 * phi_0 * phi_1p * phi_2.powi(3)
+            + 180f64 * phi_0 * phi_1p * phi_2.powi(2)
+            - 6300f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2.powi(2)
+            + 5040f64 * phi_1n.powi(4) * phi_1p.powi(4) * phi_2
+            - 630f64 * phi_1n.powi(4) * phi_1p.powi(4)
+            + 8400f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2.powi(3)
+            - 5040f64 * phi_1n.powi(4) * phi_1p.powi(3) * phi_2
+            + 840f64 * phi_1n.powi(4) * phi_1p.powi(3)
+            - 10080f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(3)
+            + 7560f64 * phi_1n.powi(4) * phi_1p.powi(2) * phi_2.powi(2)
+            - 252f64 * phi_1n.powi(4) * phi_1p.powi(2)
+            + 2520f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(3)
+            - 2520f64 * phi_1n.powi(4) * phi_1p * phi_2.powi(2)
+            + 504f64 * phi_1n.powi(4) * phi_1p * phi_2
+            + 8400f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2.powi(3)
+            - 5040f64 * phi_1n.powi(3) * phi_1p.powi(4) * phi_2
+            + 840f64 * phi_1n.powi(3) * phi_1p.powi(4)
+            - 10500f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(4)
+            - 10080f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(3)
+            + 7560f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2.powi(2)
+            + 4800f64 * phi_1n.powi(3) * phi_1p.powi(3) * phi_2
+            - 1152f64 * phi_1n.powi(3) * phi_1p.powi(3)
+            + 12600f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(4)
+            + 2520f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(3)
+            - 9720f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2.powi(2)
+            + 504f64 * phi_1n.powi(3) * phi_1p.powi(2) * phi_2
+            + 360f64 * phi_1n.powi(3) * phi_1p.powi(2)
+            - 3150f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(4)
+            + 2700f64 * phi_1n.powi(3) * phi_1p * phi_2.powi(2)
+            - 720f64 * phi_1n.powi(3) * phi_1p * phi_2
+            - 10080f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(3)
+            + 7560f64 * phi_1n.powi(2) * phi_1p.powi(4) * phi_2.powi(2)
+            - 252f64 * phi_1n.powi(2) * phi_1p.powi(4)
+            + 12600f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(4)
+            + 2520f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(3)
+            - 9720f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2.powi(2)
+            + 504f64 * phi_1n.powi(2) * phi_1p.powi(3) * phi_2
+            + 360f64 * phi_1n.powi(2) * phi_1p.powi(3)
+            - 15750f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(4)
+            + 9600f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(3)
+            + 2700f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2.powi(2)
+            - 720f64 * phi_1n.powi(2) * phi_1p.powi(2) * phi_2
+            - 120f64 * phi_1n.powi(2) * phi_1p.powi(2)
+            + 4200f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(4)
+            - 3600f64 * phi_1n.powi(2) * phi_1p * phi_2.powi(3)
+            + 240f64 * phi_1n.powi(2) * phi_1p * phi_2
+            + 2520f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(3)
+            - 2520f64 * phi_1n * phi_1p.powi(4) * phi_2.powi(2)
+            + 504f64 * phi_1n * phi_1p.powi(4) * phi_2
+            - 3150f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(4)
+            + 2700f64 * phi_1n * phi_1p.powi(3) * phi_2.powi(2)
+            - 720f64 * phi_1n * phi_1p.powi(3) * phi_2
+            + 4200f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(4)
+            - 3600f64 * phi_1n * phi_1p.powi(2) * phi_2.powi(3)
+            + 240f64 * phi_1n * phi_1p.powi(2) * phi_2
+            - 1260f64 * phi_1n * phi_1p * phi_2.powi(4)
+            + 1440f64 * phi_1n * phi_1p * phi_2.powi(3)
+            - 360f64 * phi_1n * phi_1p * phi_2.powi(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fourth-order central differences of `f` w.r.t. each of the parameters (φ₀, φ₁₋, φ₁₊, φ₂) at φ
+    fn finite_diff(f: fn(f64, f64, f64, f64, f64) -> f64, p: [f64; 4], phi: f64) -> [f64; 4] {
+        // The expanded closed form loses ~1e-10 to cancellation, so a smaller step is dominated by round-off
+        let h = 1e-4;
+        let at = |i: usize, k: f64| {
+            let mut ps = p;
+            ps[i] += k * h;
+            f(phi, ps[0], ps[1], ps[2], ps[3])
+        };
+        std::array::from_fn(|i| {
+            (8f64 * (at(i, 1f64) - at(i, -1f64)) - (at(i, 2f64) - at(i, -2f64))) / (12f64 * h)
+        })
+    }
+
+    #[test]
+    fn gradients_match_finite_differences() {
+        for p in [[0.4, 0.9, 0.1, 0.6], [0.7, 0.85, 0.2, 0.35]] {
+            let grad_v = c1_potential_grad(p[0], p[1], p[2], p[3]);
+            let grad_dv = c1_deriv_grad(p[0], p[1], p[2], p[3]);
+            for phi in [0.05, 0.3, 0.5, 0.8, 1.0] {
+                let pairs = [
+                    (grad_v(phi), finite_diff(c1_potential_expr::<f64>, p, phi)),
+                    (grad_dv(phi), finite_diff(c1_deriv_expr::<f64>, p, phi)),
+                ];
+                for (exact, approx) in pairs {
+                    for (a, b) in exact.iter().zip(approx) {
+                        assert!(
+                            (a - b).abs() <= 1e-5 * (1f64 + a.abs()),
+                            "{:?} at phi = {}: AD {} vs FD {}",
+                            p,
+                            phi,
+                            a,
+                            b
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
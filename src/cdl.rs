@@ -0,0 +1,485 @@
+use crate::batch::c1_coefficients;
+use crate::bounce::{c1_false_vacuum, dormand_prince, solve_bounce, BounceOptions, Bracket};
+use crate::c1::C1Params;
+use crate::output::{write_parquet, Codec};
+use rayon::prelude::*;
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fmt;
+
+/// Coleman–De Luccia bounce: the field and the scale factor ρ(ξ) of the O(4)-symmetric
+/// Euclidean metric `dξ² + ρ(ξ)² dΩ_3²`
+#[derive(Debug, Clone)]
+pub struct CdlBounce {
+    pub xi: Vec<f64>,
+    pub phi: Vec<f64>,
+    pub dphi: Vec<f64>,
+    pub rho: Vec<f64>,
+    pub phi_release: f64,
+    /// Tunnelling exponent `B = S[bounce] - S[false vacuum]`
+    pub action: f64,
+}
+
+/// Hawking–Moss instanton: the whole Euclidean de Sitter sphere at the top of the barrier
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HawkingMoss {
+    pub phi_top: f64,
+    /// `B = 24 π² M_P⁴ (1 / V(phi_false) - 1 / V(phi_top))`
+    pub action: f64,
+}
+
+/// Hawking–Moss instanton between `phi_false` and `phi_true`, which needs V(phi_false) > 0
+pub fn hawking_moss(
+    v: &dyn Fn(f64) -> f64,
+    dv: &dyn Fn(f64) -> f64,
+    phi_false: f64,
+    phi_true: f64,
+    planck_mass: f64,
+) -> Result<HawkingMoss, String> {
+    let v_false = v(phi_false);
+    if v_false <= 0f64 {
+        return Err(
+            "there is no Hawking–Moss instanton without a de Sitter false vacuum".to_string(),
+        );
+    }
+    if planck_mass.is_infinite() {
+        return Err("there is no Hawking–Moss instanton in flat space".to_string());
+    }
+    let bracket = Bracket::new(v, dv, phi_false, phi_true)?;
+    let phi_top = phi_false + bracket.sign * bracket.x_top;
+    let v_top = v(phi_top);
+    Ok(HawkingMoss {
+        phi_top,
+        // Written as a difference over a product, without the cancellation at large M_P
+        action: 24f64 * PI * PI * planck_mass.powi(4) * (v_top - v_false) / (v_false * v_top),
+    })
+}
+
+enum Shot {
+    Undershoot,
+    Overshoot,
+}
+
+/// State `[x, x', ρ, ρ', B_flat, S]` with `x = |φ - phi_false|`; `B_flat` and `S` accumulate the
+/// two forms of the action described in `solve_cdl`
+type State = [f64; 6];
+
+struct Shooter<'a> {
+    v: &'a dyn Fn(f64) -> f64,
+    dv: &'a dyn Fn(f64) -> f64,
+    phi_false: f64,
+    v_false: f64,
+    sign: f64,
+    /// 1 / M_P²
+    kappa: f64,
+    length: f64,
+}
+
+impl Shooter<'_> {
+    fn rhs(&self, _xi: f64, y: &State) -> State {
+        let phi = self.phi_false + self.sign * y[0];
+        let (v, force) = ((self.v)(phi), self.sign * (self.dv)(phi));
+        let (x1, rho, rho1) = (y[1], y[2], y[3]);
+        let kinetic = 0.5 * x1 * x1;
+        // ρ' of the false-vacuum geometry at the same ρ
+        let rho1_false = (1f64 - self.kappa * self.v_false * rho * rho / 3f64)
+            .max(0f64)
+            .sqrt();
+        let matched = if rho1 > 0f64 && rho1_false > 0f64 {
+            rho.powi(3) * (kinetic - rho1 * (kinetic - (v - self.v_false)) / (rho1 + rho1_false))
+        } else {
+            0f64
+        };
+        [
+            x1,
+            force - 3f64 * rho1 / rho * x1,
+            rho1,
+            -self.kappa / 3f64 * rho * (x1 * x1 + v),
+            matched,
+            rho.powi(3) * v - 3f64 * rho / self.kappa,
+        ]
+    }
+
+    /// Integrate from rest at `x0` until the field over- or undershoots or ρ returns to 0
+    fn shoot(&self, x0: f64, record: bool) -> (Shot, Vec<(f64, State)>) {
+        let phi0 = self.phi_false + self.sign * x0;
+        let (v0, a) = ((self.v)(phi0), self.sign * (self.dv)(phi0));
+        let xi0 = 1e-6 * self.length;
+        let mut y = [
+            x0 + a * xi0 * xi0 / 8f64,
+            a * xi0 / 4f64,
+            xi0 - self.kappa * v0 * xi0.powi(3) / 18f64,
+            1f64 - self.kappa * v0 * xi0 * xi0 / 6f64,
+            0f64,
+            v0 * xi0.powi(4) / 4f64 - 1.5 * xi0 * xi0 / self.kappa,
+        ];
+        let mut xi = xi0;
+        let mut h = 1e-4 * self.length;
+        let xi_max = 1e4 * self.length;
+        let mut path = vec![(0f64, [x0, 0f64, 0f64, 1f64, 0f64, 0f64])];
+        if record {
+            path.push((xi, y));
+        }
+
+        let shot = loop {
+            let (y_new, err) = dormand_prince(|xi, y| self.rhs(xi, y), xi, &y, h);
+            if err > 1f64 {
+                h *= (0.9 * err.powf(-0.2)).max(0.2);
+                // The anti-friction past the equator of a closed geometry drives x away
+                if h < 1e-12 * self.length {
+                    break if y[1] > 0f64 {
+                        Shot::Undershoot
+                    } else {
+                        Shot::Overshoot
+                    };
+                }
+                continue;
+            }
+            xi += h;
+            y = y_new;
+            h *= (0.9 * err.powf(-0.2)).min(5f64);
+            if y[0] < 0f64 || y[2] <= 0f64 {
+                break Shot::Overshoot;
+            }
+            if record {
+                path.push((xi, y));
+            }
+            if y[1] > 0f64 || xi > xi_max {
+                break Shot::Undershoot;
+            }
+        };
+        if !record || path.len() == 1 {
+            path.push((xi, y));
+        }
+        (shot, path)
+    }
+}
+
+/// Solve the O(4) field and scale-factor equations with gravity,
+/// `φ'' + 3 ρ'/ρ φ' = V'(φ)` and `ρ'' = -ρ (φ'² + V) / (3 M_P²)`, by shooting from
+/// `φ'(0) = 0`, `ρ(0) = 0`, `ρ'(0) = 1`
+///
+/// `planck_mass` is the reduced Planck mass in the units of φ; with `f64::INFINITY` this is
+/// the flat-space bounce of `solve_bounce`. The release point is bisected between the top of
+/// the barrier and the first minimum past it, an overshoot being a field that passes the false
+/// vacuum or a closed geometry (ρ back to 0) before it turns back.
+///
+/// The action is accumulated as
+/// `B = 2π² ∫ ρ³ [φ'² - 2 ρ' (φ'²/2 - ΔV) / (ρ' + ρ_f')] dξ`, which compares the bounce with the
+/// false-vacuum geometry at equal ρ (ρ_f' = sqrt(1 - V_f ρ² / (3 M_P²))) and tends to the
+/// flat-space action as M_P → ∞. It holds while ρ grows; for a de Sitter false vacuum whose
+/// field has not settled before the equator of the bounce the action is instead
+/// `4π² ∫ (ρ³ V - 3 M_P² ρ) dξ + 24 π² M_P⁴ / V_f` over the closed geometry.
+pub fn solve_cdl(
+    v: &dyn Fn(f64) -> f64,
+    dv: &dyn Fn(f64) -> f64,
+    phi_false: f64,
+    phi_true: f64,
+    planck_mass: f64,
+    opts: BounceOptions,
+) -> Result<CdlBounce, String> {
+    if planck_mass.is_infinite() {
+        let b = solve_bounce(v, dv, phi_false, phi_true, BounceOptions { dim: 4, ..opts })?;
+        return Ok(CdlBounce {
+            rho: b.r.clone(),
+            xi: b.r,
+            phi: b.phi,
+            dphi: b.dphi,
+            phi_release: b.phi_release,
+            action: b.action,
+        });
+    }
+    if planck_mass.is_nan() || planck_mass <= 0f64 {
+        return Err(format!(
+            "the Planck mass must be positive, got {}",
+            planck_mass
+        ));
+    }
+    let bracket = Bracket::new(v, dv, phi_false, phi_true)?;
+    let v_false = v(phi_false);
+    let shooter = Shooter {
+        v,
+        dv,
+        phi_false,
+        v_false,
+        sign: bracket.sign,
+        kappa: planck_mass.powi(-2),
+        length: bracket.length(),
+    };
+
+    let (mut lo, mut hi) = (bracket.x_top, bracket.x_target);
+    let mut n_shots = 0;
+    while hi - lo > opts.tol * bracket.x_true {
+        if n_shots == opts.max_shots {
+            return Err(format!("no bounce found after {} shots", n_shots));
+        }
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        match shooter.shoot(mid, false).0 {
+            Shot::Undershoot => lo = mid,
+            Shot::Overshoot => hi = mid,
+        }
+        n_shots += 1;
+    }
+    if lo == bracket.x_top {
+        return Err(
+            "every release point overshoots (thin-wall limit beyond f64 precision)".to_string(),
+        );
+    }
+    if (lo - bracket.x_top) < 1e-6 * bracket.x_true {
+        return Err(
+            "the bounce collapses onto the top of the barrier (Hawking–Moss regime)".to_string(),
+        );
+    }
+
+    let (_, path) = shooter.shoot(lo, true);
+    let last = path.len() - 1;
+    // Last point before the geometry contracts; the matched action is complete there if the
+    // field has settled in the false vacuum
+    let i_end = path
+        .iter()
+        .position(|(_, y)| y[3] <= 0f64)
+        .map_or(last, |i| i - 1);
+    let x_end = path[i_end].1[0];
+    let closed = v_false > 0f64 && i_end < last;
+    let action = if x_end <= 1e-3 * bracket.x_true && (!closed || x_end <= 1e-6 * bracket.x_true) {
+        4f64 * PI * PI * path[i_end].1[4]
+    } else if closed {
+        // The last undershoot turns back near the far pole; the cap beyond it is de Sitter
+        // space of the static field there, `∫ (ρ³ V - 3 M_P² ρ) dξ = -M_P² R² (1 - cos³θ)`
+        let (rho, phi) = (path[last].1[2], phi_false + bracket.sign * path[last].1[0]);
+        let r2 = 3f64 * planck_mass.powi(2) / v(phi);
+        let cap =
+            -planck_mass.powi(2) * r2 * (1f64 - (1f64 - (rho * rho / r2).min(1f64)).powf(1.5));
+        4f64 * PI * PI * (path[last].1[5] + cap + 6f64 * planck_mass.powi(4) / v_false)
+    } else {
+        return Err(format!(
+            "the bounce ends at {:.3e} from the false vacuum (thin-wall limit beyond f64 precision or decay quenched by gravity)",
+            x_end
+        ));
+    };
+    Ok(CdlBounce {
+        xi: path.iter().map(|(xi, _)| *xi).collect(),
+        phi: path
+            .iter()
+            .map(|(_, y)| phi_false + bracket.sign * y[0])
+            .collect(),
+        dphi: path.iter().map(|(_, y)| bracket.sign * y[1]).collect(),
+        rho: path.iter().map(|(_, y)| y[2]).collect(),
+        phi_release: phi_false + bracket.sign * lo,
+        action,
+    })
+}
+
+/// Instanton mediating the decay
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Instanton {
+    ColemanDeLuccia,
+    HawkingMoss,
+}
+
+impl fmt::Display for Instanton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instanton::ColemanDeLuccia => write!(f, "cdl"),
+            Instanton::HawkingMoss => write!(f, "hawking_moss"),
+        }
+    }
+}
+
+/// Decay of a C1 potential shifted by a vacuum energy, `V = V_C1 + vacuum_energy`
+#[derive(Debug, Clone)]
+pub struct Decay {
+    pub planck_mass: f64,
+    pub cdl: Result<CdlBounce, String>,
+    /// Only with a de Sitter false vacuum and finite M_P
+    pub hawking_moss: Option<HawkingMoss>,
+    /// `|V''(φ_top)| < 4 H² = 4 V(φ_top) / (3 M_P²)`: the barrier is too flat for a CDL
+    /// bounce to fit in the de Sitter horizon and Hawking–Moss takes over
+    pub flat_barrier: bool,
+}
+
+impl Decay {
+    /// The instanton of smallest action, Hawking–Moss on a flat barrier
+    pub fn dominant(&self) -> Option<Instanton> {
+        let hm = self.hawking_moss.map(|hm| hm.action);
+        match (&self.cdl, hm) {
+            (_, Some(_)) if self.flat_barrier => Some(Instanton::HawkingMoss),
+            (Ok(cdl), Some(hm)) if hm < cdl.action => Some(Instanton::HawkingMoss),
+            (Ok(_), _) => Some(Instanton::ColemanDeLuccia),
+            (Err(_), Some(_)) => Some(Instanton::HawkingMoss),
+            (Err(_), None) => None,
+        }
+    }
+
+    /// Tunnelling exponent of the dominant instanton
+    pub fn action(&self) -> f64 {
+        match self.dominant() {
+            Some(Instanton::ColemanDeLuccia) => self.cdl.as_ref().map_or(f64::NAN, |b| b.action),
+            Some(Instanton::HawkingMoss) => self.hawking_moss.map_or(f64::NAN, |hm| hm.action),
+            None => f64::NAN,
+        }
+    }
+}
+
+impl fmt::Display for Decay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (release, cdl) = match &self.cdl {
+            Ok(b) => (format!("{:.6}", b.phi_release), format!("{:.6e}", b.action)),
+            Err(_) => ("-".to_string(), "-".to_string()),
+        };
+        let hm = self
+            .hawking_moss
+            .map_or("-".to_string(), |hm| format!("{:.6e}", hm.action));
+        let dominant = self
+            .dominant()
+            .map_or("none".to_string(), |i| i.to_string());
+        write!(
+            f,
+            "{:>12.4e} {:>12} {:>14} {:>14} {:>14}",
+            self.planck_mass, release, cdl, hm, dominant
+        )
+    }
+}
+
+/// CDL bounce and Hawking–Moss instanton of a C1 potential shifted by `vacuum_energy`, from the
+/// false vacuum of `c1_false_vacuum` to φ = 1
+pub fn c1_decay(
+    params: &C1Params,
+    vacuum_energy: f64,
+    planck_mass: f64,
+    opts: BounceOptions,
+) -> Result<Decay, String> {
+    let a = c1_coefficients(params);
+    let v =
+        |phi: f64| phi * phi * a.iter().rev().fold(0f64, |acc, &c| acc * phi + c) + vacuum_energy;
+    let dv = |phi: f64| {
+        phi * a
+            .iter()
+            .enumerate()
+            .rev()
+            .fold(0f64, |acc, (k, &c)| acc * phi + (k + 2) as f64 * c)
+    };
+    let phi_false = c1_false_vacuum(params)?;
+    let cdl = solve_cdl(&v, &dv, phi_false, 1f64, planck_mass, opts);
+    let hawking_moss = hawking_moss(&v, &dv, phi_false, 1f64, planck_mass).ok();
+    let flat_barrier = hawking_moss.is_some_and(|hm| {
+        let h = 1e-6;
+        let curvature = (dv(hm.phi_top + h) - dv(hm.phi_top - h)) / (2f64 * h);
+        curvature.abs() < 4f64 * v(hm.phi_top) / (3f64 * planck_mass * planck_mass)
+    });
+    Ok(Decay {
+        planck_mass,
+        cdl,
+        hawking_moss,
+        flat_barrier,
+    })
+}
+
+/// Decays at each of `planck_masses`, solved in parallel
+pub fn scan_planck_mass(
+    params: &C1Params,
+    vacuum_energy: f64,
+    planck_masses: &[f64],
+    opts: BounceOptions,
+) -> Result<Vec<Decay>, String> {
+    planck_masses
+        .par_iter()
+        .map(|&m| c1_decay(params, vacuum_energy, m, opts))
+        .collect()
+}
+
+/// Write one row per Planck mass: release point and action of the CDL bounce, Hawking–Moss
+/// action and whether Hawking–Moss dominates (NaN where an instanton does not exist)
+pub fn write_decays(
+    path: &str,
+    decays: &[Decay],
+    metadata: Vec<(String, String)>,
+) -> Result<(), Box<dyn Error>> {
+    let col = |f: &dyn Fn(&Decay) -> f64| -> Vec<f64> { decays.iter().map(f).collect() };
+    let columns = vec![
+        ("planck_mass".to_string(), col(&|d| d.planck_mass)),
+        (
+            "phi_release".to_string(),
+            col(&|d| d.cdl.as_ref().map_or(f64::NAN, |b| b.phi_release)),
+        ),
+        (
+            "action_cdl".to_string(),
+            col(&|d| d.cdl.as_ref().map_or(f64::NAN, |b| b.action)),
+        ),
+        (
+            "action_hm".to_string(),
+            col(&|d| d.hawking_moss.map_or(f64::NAN, |hm| hm.action)),
+        ),
+        (
+            "hawking_moss".to_string(),
+            col(&|d| match d.dominant() {
+                Some(i) => (i == Instanton::HawkingMoss) as u8 as f64,
+                None => f64::NAN,
+            }),
+        ),
+    ];
+    write_parquet(path, columns, metadata, Codec::Uncompressed.options())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bounce::c1_bounce;
+
+    #[test]
+    fn tends_to_flat_bounce_as_planck_mass_grows() {
+        let p = C1Params::new(0.4, 0.9, 0.1, 0.6);
+        let opts = BounceOptions::default();
+        let flat = c1_bounce(&p, opts).unwrap().action;
+        let action = |m: f64| c1_decay(&p, 0f64, m, opts).unwrap().cdl.unwrap().action;
+
+        assert_eq!(action(f64::INFINITY), flat);
+        assert!((action(1e3) - flat).abs() < 1e-5 * flat);
+        // Without a vacuum energy the false vacuum has V < 0 (anti-de Sitter), and gravity
+        // suppresses its decay at order 1/M_P²
+        let (d_30, d_100) = (action(30f64) - flat, action(100f64) - flat);
+        assert!(d_100 > 0f64 && d_30 > d_100);
+        let ratio = d_30 / d_100;
+        assert!(
+            (ratio / (100f64 / 30f64).powi(2) - 1f64).abs() < 0.05,
+            "ratio {}",
+            ratio
+        );
+    }
+
+    #[test]
+    fn hawking_moss_takes_over_in_de_sitter() {
+        let p = C1Params::new(0.4, 0.9, 0.1, 0.6);
+        let opts = BounceOptions::default();
+        let v = |phi: f64| p.potential()(phi) + 3f64;
+
+        // A barrier too flat for the horizon at M_P = 1: no CDL bounce, Hawking–Moss dominates
+        let decay = c1_decay(&p, 3f64, 1f64, opts).unwrap();
+        assert!(decay.flat_barrier && decay.cdl.is_err());
+        assert_eq!(decay.dominant(), Some(Instanton::HawkingMoss));
+        let hm = decay.hawking_moss.unwrap();
+        let v_false = v(c1_false_vacuum(&p).unwrap());
+        let exact = 24f64 * PI * PI * (1f64 / v_false - 1f64 / v(hm.phi_top));
+        // The closed form of V loses ~1e-10 to cancellation against the polynomial
+        assert!(
+            (hm.action - exact).abs() < 1e-8 * exact,
+            "{} vs {}",
+            hm.action,
+            exact
+        );
+        assert_eq!(decay.action(), hm.action);
+
+        // Just past the threshold the CDL bounce fills a closed geometry and its action
+        // merges with the Hawking–Moss one from below
+        let decay = c1_decay(&p, 3f64, 1.5, opts).unwrap();
+        let cdl = decay.cdl.as_ref().unwrap();
+        let rho_max = cdl.rho.iter().copied().fold(0f64, f64::max);
+        assert!(*cdl.rho.last().unwrap() < 0.99 * rho_max);
+        let hm = decay.hawking_moss.unwrap().action;
+        assert!(!decay.flat_barrier && cdl.action < hm && cdl.action > 0.97 * hm);
+        assert_eq!(decay.dominant(), Some(Instanton::ColemanDeLuccia));
+    }
+}
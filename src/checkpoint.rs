@@ -0,0 +1,160 @@
+use std::error::Error;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// Progress of an interrupted generation run
+///
+/// Candidates are addressed by index, so the next candidate index is all the sampler state
+/// there is. Completed samples live in numbered part files next to the checkpoint; a targeted
+/// run additionally keeps the (index, bin) list of its selected candidates in `selected`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checkpoint {
+    /// Metadata of the run, compared on resume to make sure the options did not change
+    pub config: Vec<(String, String)>,
+    pub next_index: u64,
+    pub n_accepted: u64,
+    /// Samples written to the first `n_parts` part files
+    pub n_rows: usize,
+    pub n_parts: usize,
+    /// Entries of the `selected` file that belong to this checkpoint
+    pub n_selected: usize,
+    /// Per-bin counters of the target filter
+    pub seen: Vec<usize>,
+    pub kept: Vec<usize>,
+}
+
+/// Directory holding the checkpoint and part files of a run writing to `output`
+pub fn partial_dir(output: &str) -> PathBuf {
+    PathBuf::from(format!("{}.partial", output))
+}
+
+pub fn part_path(dir: &Path, part: usize) -> PathBuf {
+    dir.join(format!("part-{:05}.parquet", part))
+}
+
+fn join(xs: &[usize]) -> String {
+    xs.iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn split(s: &str) -> Result<Vec<usize>, String> {
+    if s.is_empty() {
+        return Ok(vec![]);
+    }
+    s.split(',')
+        .map(|x| {
+            x.parse()
+                .map_err(|e| format!("invalid counter '{}': {}", x, e))
+        })
+        .collect()
+}
+
+impl Checkpoint {
+    /// Write atomically (to a temporary file, then rename) into `dir/checkpoint`
+    pub fn save(&self, dir: &Path) -> Result<(), Box<dyn Error>> {
+        let mut lines = vec![
+            format!("next_index={}", self.next_index),
+            format!("n_accepted={}", self.n_accepted),
+            format!("n_rows={}", self.n_rows),
+            format!("n_parts={}", self.n_parts),
+            format!("n_selected={}", self.n_selected),
+            format!("seen={}", join(&self.seen)),
+            format!("kept={}", join(&self.kept)),
+        ];
+        lines.extend(
+            self.config
+                .iter()
+                .map(|(k, v)| format!("config.{}={}", k, v)),
+        );
+        let tmp = dir.join("checkpoint.tmp");
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all((lines.join("\n") + "\n").as_bytes())?;
+        file.sync_all()?;
+        fs::rename(tmp, dir.join("checkpoint"))?;
+        Ok(())
+    }
+
+    pub fn load(dir: &Path) -> Result<Self, Box<dyn Error>> {
+        let path = dir.join("checkpoint");
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read checkpoint {}: {}", path.display(), e))?;
+        let mut checkpoint = Checkpoint {
+            config: vec![],
+            next_index: 0,
+            n_accepted: 0,
+            n_rows: 0,
+            n_parts: 0,
+            n_selected: 0,
+            seen: vec![],
+            kept: vec![],
+        };
+        for line in text.lines() {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("invalid checkpoint line '{}'", line))?;
+            let invalid =
+                |e: &dyn std::fmt::Display| format!("invalid {} in checkpoint: {}", key, e);
+            match key {
+                "next_index" => checkpoint.next_index = value.parse().map_err(|e| invalid(&e))?,
+                "n_accepted" => checkpoint.n_accepted = value.parse().map_err(|e| invalid(&e))?,
+                "n_rows" => checkpoint.n_rows = value.parse().map_err(|e| invalid(&e))?,
+                "n_parts" => checkpoint.n_parts = value.parse().map_err(|e| invalid(&e))?,
+                "n_selected" => checkpoint.n_selected = value.parse().map_err(|e| invalid(&e))?,
+                "seen" => checkpoint.seen = split(value)?,
+                "kept" => checkpoint.kept = split(value)?,
+                _ => match key.strip_prefix("config.") {
+                    Some(k) => checkpoint.config.push((k.to_string(), value.to_string())),
+                    None => return Err(format!("unknown checkpoint key '{}'", key).into()),
+                },
+            }
+        }
+        Ok(checkpoint)
+    }
+}
+
+/// Append (index, bin) pairs of selected candidates to `dir/selected`
+pub fn append_selected(dir: &Path, entries: &[(u64, usize)]) -> Result<(), Box<dyn Error>> {
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join("selected"))?;
+    let text: String = entries
+        .iter()
+        .map(|(index, bin)| format!("{} {}\n", index, bin))
+        .collect();
+    file.write_all(text.as_bytes())?;
+    file.sync_all()?;
+    Ok(())
+}
+
+/// Read the first `n` selected candidates, dropping entries written after the checkpoint
+pub fn load_selected(dir: &Path, n: usize) -> Result<Vec<(u64, usize)>, Box<dyn Error>> {
+    if n == 0 {
+        fs::File::create(dir.join("selected"))?;
+        return Ok(vec![]);
+    }
+    let file = fs::File::open(dir.join("selected"))?;
+    let mut entries = Vec::with_capacity(n);
+    for line in BufReader::new(file).lines().take(n) {
+        let line = line?;
+        let (index, bin) = line
+            .split_once(' ')
+            .ok_or_else(|| format!("invalid selected entry '{}'", line))?;
+        entries.push((index.parse()?, bin.parse()?));
+    }
+    if entries.len() < n {
+        return Err(format!(
+            "checkpoint lists {} selected candidates, found {}",
+            n,
+            entries.len()
+        )
+        .into());
+    }
+    // Rewrite so that later appends follow the checkpointed entries
+    fs::remove_file(dir.join("selected"))?;
+    append_selected(dir, &entries)?;
+    Ok(entries)
+}
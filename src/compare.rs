@@ -0,0 +1,262 @@
+use crate::c1::C1Param;
+use crate::dataset::Dataset;
+use crate::features::{is_finite_curve, Features};
+use crate::stats::{feature_values, FEATURES};
+use std::error::Error;
+use std::fmt;
+
+/// Two-sample statistics of one quantity
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drift {
+    pub name: String,
+    /// Kolmogorov–Smirnov statistic, the largest distance between the empirical CDFs
+    pub ks: f64,
+    /// Asymptotic p-value of `ks` under the hypothesis of equal distributions
+    pub p_value: f64,
+    /// Energy distance `2 E|X - Y| - E|X - X'| - E|Y - Y'|`, in the units of the quantity
+    pub energy: f64,
+}
+
+/// Comparison of the distributions of two datasets, quantity by quantity
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub n_a: usize,
+    pub n_b: usize,
+    /// Significance level below which a quantity counts as drifted
+    pub alpha: f64,
+    pub drifts: Vec<Drift>,
+}
+
+impl Comparison {
+    pub fn drifted(&self) -> impl Iterator<Item = &Drift> {
+        self.drifts.iter().filter(move |d| d.p_value < self.alpha)
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "samples: {} vs {}", self.n_a, self.n_b)?;
+        writeln!(
+            f,
+            "{:<12} {:>8} {:>10} {:>10}",
+            "", "KS", "p-value", "energy"
+        )?;
+        for d in &self.drifts {
+            writeln!(
+                f,
+                "{:<12} {:>8.4} {:>10.3e} {:>10.3e}{}",
+                d.name,
+                d.ks,
+                d.p_value,
+                d.energy,
+                if d.p_value < self.alpha {
+                    "  drifted"
+                } else {
+                    ""
+                }
+            )?;
+        }
+        write!(
+            f,
+            "{} of {} quantities drifted (p < {})",
+            self.drifted().count(),
+            self.drifts.len(),
+            self.alpha
+        )
+    }
+}
+
+/// Sorted finite values of the features, the parameters and the `labels` columns
+fn quantities(dataset: &Dataset, labels: &[String]) -> Result<Vec<Vec<f64>>, Box<dyn Error>> {
+    let label_index: Vec<usize> = labels
+        .iter()
+        .map(|l| dataset.labels().iter().position(|m| m == l).unwrap())
+        .collect();
+    let mut columns = vec![Vec::with_capacity(dataset.len()); FEATURES.len() + 4 + labels.len()];
+    for record in dataset.records() {
+        let record = record?;
+        let s = &record.sample;
+        let phi = s.grid.as_deref().or(dataset.grid()).unwrap_or(&[]);
+        let features = if is_finite_curve(&s.v) {
+            feature_values(&Features::new(phi, &s.v))
+        } else {
+            [f64::NAN; 5]
+        };
+        let values = features
+            .into_iter()
+            .chain(s.params.to_array())
+            .chain(label_index.iter().map(|&i| record.labels[i]));
+        for (column, x) in columns.iter_mut().zip(values) {
+            column.push(x);
+        }
+    }
+    for column in columns.iter_mut() {
+        column.retain(|x| x.is_finite());
+        column.sort_by(|a, b| a.total_cmp(b));
+    }
+    Ok(columns)
+}
+
+/// Compare the per-sample features, parameter marginals and shared label columns of two datasets
+pub fn compare(a: &Dataset, b: &Dataset, alpha: f64) -> Result<Comparison, Box<dyn Error>> {
+    let labels: Vec<String> = a
+        .labels()
+        .iter()
+        .filter(|l| *l != "split" && b.labels().contains(l))
+        .cloned()
+        .collect();
+    let names = FEATURES
+        .iter()
+        .map(|s| s.to_string())
+        .chain(C1Param::ALL.iter().map(|p| p.name().to_string()))
+        .chain(labels.iter().cloned());
+    let (xs, ys) = (quantities(a, &labels)?, quantities(b, &labels)?);
+    let drifts = names
+        .zip(xs.iter().zip(&ys))
+        .map(|(name, (x, y))| {
+            if is_constant(x, y) {
+                return Drift {
+                    name,
+                    ks: 0f64,
+                    p_value: 1f64,
+                    energy: 0f64,
+                };
+            }
+            let (ks, p_value) = ks_test(x, y);
+            Drift {
+                name,
+                ks,
+                p_value,
+                energy: energy_distance(x, y),
+            }
+        })
+        .collect();
+    Ok(Comparison {
+        n_a: a.len(),
+        n_b: b.len(),
+        alpha,
+        drifts,
+    })
+}
+
+/// Whether two sorted samples hold the same value up to rounding (e.g. ΔV, fixed by the C1 normalization)
+fn is_constant(x: &[f64], y: &[f64]) -> bool {
+    let (lo, hi) = match (x.first(), x.last(), y.first(), y.last()) {
+        (Some(a), Some(b), Some(c), Some(d)) => (a.min(*c), b.max(*d)),
+        _ => return false,
+    };
+    hi - lo <= 1e-9 * (1f64 + lo.abs())
+}
+
+/// Kolmogorov–Smirnov statistic and asymptotic p-value of two sorted samples
+pub fn ks_test(x: &[f64], y: &[f64]) -> (f64, f64) {
+    if x.is_empty() || y.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+    let (n, m) = (x.len() as f64, y.len() as f64);
+    let (mut i, mut j, mut d) = (0, 0, 0f64);
+    while i < x.len() && j < y.len() {
+        let t = x[i].min(y[j]);
+        while i < x.len() && x[i] == t {
+            i += 1;
+        }
+        while j < y.len() && y[j] == t {
+            j += 1;
+        }
+        d = d.max((i as f64 / n - j as f64 / m).abs());
+    }
+    let ne = (n * m / (n + m)).sqrt();
+    (d, kolmogorov_q((ne + 0.12 + 0.11 / ne) * d))
+}
+
+/// Complementary CDF of the Kolmogorov distribution
+fn kolmogorov_q(lambda: f64) -> f64 {
+    if lambda < 1e-3 {
+        return 1f64;
+    }
+    let mut sum = 0f64;
+    for k in 1..=100 {
+        let term = 2f64 * (-2f64 * (k * k) as f64 * lambda * lambda).exp();
+        sum += if k % 2 == 1 { term } else { -term };
+        if term < 1e-16 {
+            break;
+        }
+    }
+    sum.clamp(0f64, 1f64)
+}
+
+/// Energy distance of two sorted samples, in O(n log n)
+pub fn energy_distance(x: &[f64], y: &[f64]) -> f64 {
+    if x.is_empty() || y.is_empty() {
+        return f64::NAN;
+    }
+    let mut all: Vec<f64> = x.iter().chain(y).copied().collect();
+    all.sort_by(|a, b| a.total_cmp(b));
+    let (n, m) = (x.len() as f64, y.len() as f64);
+    let (sxx, syy) = (pair_sum(x), pair_sum(y));
+    let sxy = pair_sum(&all) - sxx - syy;
+    // Non-negative in exact arithmetic
+    (2f64 * sxy / (n * m) - 2f64 * sxx / (n * n) - 2f64 * syy / (m * m)).max(0f64)
+}
+
+/// `Σ_{i<j} |x_i - x_j|` of sorted values
+fn pair_sum(sorted: &[f64]) -> f64 {
+    let n = sorted.len() as f64;
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(i, x)| x * (2f64 * i as f64 - n + 1f64))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sampler::SplitMix64;
+
+    fn sorted_normals(seed: u64, n: usize, shift: f64) -> Vec<f64> {
+        let mut rng = SplitMix64::new(seed);
+        let mut x: Vec<f64> = (0..n).map(|_| rng.next_normal() + shift).collect();
+        x.sort_by(|a, b| a.total_cmp(b));
+        x
+    }
+
+    #[test]
+    fn identical_samples_do_not_drift() {
+        let x = sorted_normals(1, 200, 0f64);
+        assert_eq!(ks_test(&x, &x), (0f64, 1f64));
+        assert!(energy_distance(&x, &x) < 1e-12);
+    }
+
+    #[test]
+    fn disjoint_samples_drift_completely() {
+        let x = sorted_normals(1, 200, 0f64);
+        let y: Vec<f64> = x.iter().map(|v| v + 100f64).collect();
+        let (d, p) = ks_test(&x, &y);
+        assert_eq!(d, 1f64);
+        assert!(p < 1e-30, "p = {:e}", p);
+        // Tied values move both CDFs before they are compared
+        assert_eq!(
+            ks_test(&[1f64, 3f64, 3f64, 5f64], &[2f64, 3f64, 4f64, 6f64]).0,
+            0.25
+        );
+        assert_eq!(ks_test(&[1f64, 2f64, 3f64, 4f64], &[3.5, 5f64]).0, 0.75);
+    }
+
+    #[test]
+    fn energy_distance_matches_pairwise_definition() {
+        let mean_abs = |a: &[f64], b: &[f64]| {
+            let sum: f64 = a
+                .iter()
+                .flat_map(|u| b.iter().map(move |v| (u - v).abs()))
+                .sum();
+            sum / (a.len() * b.len()) as f64
+        };
+        let x = sorted_normals(2, 150, 0f64);
+        let y = sorted_normals(3, 90, 0.4);
+        let direct = 2f64 * mean_abs(&x, &y) - mean_abs(&x, &x) - mean_abs(&y, &y);
+        let fast = energy_distance(&x, &y);
+        assert!((fast - direct).abs() < 1e-12, "{} vs {}", fast, direct);
+        assert!(direct > 0.01);
+    }
+}
@@ -0,0 +1,1090 @@
+use crate::bounce::BounceOptions;
+use crate::c1::C1Params;
+use crate::formats::OutputFormat;
+use crate::grid::GridSpec;
+use crate::gw::{FrequencyRange, GwOptions};
+use crate::mcmc::McmcOptions;
+use crate::output::Codec;
+use crate::plot::PlotSpec;
+use crate::precision::RELIABLE_TOL;
+use crate::sampler::SamplerKind;
+use crate::scan::{parse_fixed, Axis, ScanSpec};
+use crate::sensitivity::GradientSpec;
+use crate::split::{SplitSpec, Stratify, SPLIT_NAMES};
+use crate::surrogate::SurrogateSpec;
+use crate::target::TargetSpec;
+use crate::thermal::{Species, TemperatureRange, ThermalApprox, ThermalPotential};
+use crate::transition::TransitionOptions;
+
+/// Settings of a generation run, parsed from `--key value` command line options
+#[derive(Debug, Clone)]
+pub struct RunConfig {
+    pub n_samples: usize,
+    pub grid: GridSpec,
+    pub output: String,
+    /// Format of the output; inferred from its extension unless given
+    pub format: OutputFormat,
+    /// Compression of parquet output
+    pub compression: Codec,
+    pub sampler: SamplerKind,
+    pub seed: u64,
+    /// Split candidates evenly between the plain and the swapped branch
+    pub stratify_branch: bool,
+    /// Steer the kept samples towards a histogram over a feature
+    pub target: Option<TargetSpec>,
+    /// Give up after this many candidates (e.g. when a target bin cannot be filled)
+    pub max_candidates: Option<u64>,
+    /// Sample the accepted region with Markov chains instead of rejection sampling
+    pub mcmc: Option<McmcOptions>,
+    /// Rows per parquet row group, i.e. how many samples are buffered before being written
+    pub row_group_size: usize,
+    /// Seconds between checkpoints; `None` writes the output directly without checkpoints
+    pub checkpoint_interval: Option<u64>,
+    /// Continue from the checkpoint of an interrupted run with the same options
+    pub resume: bool,
+    /// Assign samples to train/val/test splits
+    pub split: Option<SplitSpec>,
+    /// Parameter gradients stored as label columns
+    pub gradients: Option<GradientSpec>,
+    /// Store a `reliable` label column, 0 where the round-off of the curve exceeds this
+    pub precision_tol: Option<f64>,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            n_samples: 10000,
+            grid: GridSpec::Uniform { n: 100 },
+            output: "c1.parquet".to_string(),
+            format: OutputFormat::Parquet,
+            compression: Codec::Uncompressed,
+            sampler: SamplerKind::Random,
+            seed: 0,
+            stratify_branch: false,
+            target: None,
+            max_candidates: None,
+            mcmc: None,
+            row_group_size: 8192,
+            checkpoint_interval: None,
+            resume: false,
+            split: None,
+            gradients: None,
+            precision_tol: None,
+        }
+    }
+}
+
+impl RunConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut format = None;
+        let mut compression = None;
+        let mut split: Option<SplitSpec> = None;
+        let mut split_by = None;
+        let mut split_files = false;
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for '{}'", key))
+            };
+            match key.as_str() {
+                "--n-samples" => {
+                    config.n_samples = value()?
+                        .parse()
+                        .map_err(|e| format!("invalid --n-samples: {}", e))?
+                }
+                "--grid" => config.grid = value()?.parse()?,
+                "--output" => config.output = value()?,
+                "--format" => format = Some(value()?.parse()?),
+                "--compression" => compression = Some(value()?.parse()?),
+                "--sampler" => config.sampler = value()?.parse()?,
+                "--seed" => {
+                    config.seed = value()?
+                        .parse()
+                        .map_err(|e| format!("invalid --seed: {}", e))?
+                }
+                "--stratify-branch" => config.stratify_branch = true,
+                "--target" => config.target = Some(value()?.parse()?),
+                "--max-candidates" => {
+                    config.max_candidates = Some(
+                        value()?
+                            .parse()
+                            .map_err(|e| format!("invalid --max-candidates: {}", e))?,
+                    )
+                }
+                "--row-group-size" => {
+                    config.row_group_size = value()?
+                        .parse()
+                        .map_err(|e| format!("invalid --row-group-size: {}", e))?
+                }
+                "--checkpoint" => {
+                    config.checkpoint_interval = Some(
+                        value()?
+                            .parse()
+                            .map_err(|e| format!("invalid --checkpoint: {}", e))?,
+                    )
+                }
+                "--resume" => config.resume = true,
+                "--split" => split = Some(value()?.parse()?),
+                "--split-by" => split_by = Some(value()?.parse()?),
+                "--split-files" => split_files = true,
+                "--gradients" => config.gradients = Some(value()?.parse()?),
+                "--precision-tol" => {
+                    config.precision_tol = Some(
+                        value()?
+                            .parse()
+                            .map_err(|e| format!("invalid --precision-tol: {}", e))?,
+                    )
+                }
+                "--mcmc" => {
+                    config.mcmc.get_or_insert_with(McmcOptions::default);
+                }
+                "--chains" | "--burn-in" | "--thin" | "--step" => {
+                    let value = value()?;
+                    let invalid = |e: &dyn std::fmt::Display| format!("invalid {}: {}", key, e);
+                    let opts = config.mcmc.get_or_insert_with(McmcOptions::default);
+                    match key.as_str() {
+                        "--chains" => opts.n_chains = value.parse().map_err(|e| invalid(&e))?,
+                        "--burn-in" => opts.burn_in = value.parse().map_err(|e| invalid(&e))?,
+                        "--thin" => opts.thin = value.parse().map_err(|e| invalid(&e))?,
+                        _ => opts.step = value.parse().map_err(|e| invalid(&e))?,
+                    }
+                }
+                _ => return Err(format!("unknown option '{}'", key)),
+            }
+        }
+        if config.mcmc.is_some() && config.target.is_some() {
+            return Err("--target cannot be combined with MCMC sampling".to_string());
+        }
+        config.format = format
+            .or_else(|| OutputFormat::from_path(&config.output))
+            .unwrap_or(OutputFormat::Parquet);
+        if let Some(codec) = compression {
+            if config.format != OutputFormat::Parquet {
+                return Err("--compression only applies to parquet output".to_string());
+            }
+            config.compression = codec;
+        }
+        if split.is_some() || split_by.is_some() || split_files {
+            let mut spec = split.unwrap_or_default();
+            if let Some(stratify) = split_by {
+                spec.stratify = stratify;
+            }
+            spec.files = split_files;
+            config.split = Some(spec);
+        }
+        if config.resume {
+            config.checkpoint_interval.get_or_insert(300);
+        }
+        if config.mcmc.is_some() && config.checkpoint_interval.is_some() {
+            return Err("checkpoints are not supported with MCMC sampling".to_string());
+        }
+        if config.row_group_size == 0 {
+            return Err("--row-group-size must be positive".to_string());
+        }
+        if config.mcmc.is_some_and(|opts| opts.n_chains == 0) {
+            return Err("--chains must be positive".to_string());
+        }
+        Ok(config)
+    }
+
+    /// Key-value pairs recorded in the output file
+    pub fn to_metadata(&self) -> Vec<(String, String)> {
+        let mut meta = vec![
+            ("bounce.n_samples".to_string(), self.n_samples.to_string()),
+            ("bounce.grid".to_string(), self.grid.to_string()),
+            ("bounce.sampler".to_string(), self.sampler.to_string()),
+            ("bounce.seed".to_string(), self.seed.to_string()),
+            (
+                "bounce.stratify_branch".to_string(),
+                self.stratify_branch.to_string(),
+            ),
+        ];
+        if let Some(target) = &self.target {
+            meta.push(("bounce.target".to_string(), target.to_string()));
+        }
+        if let Some(opts) = &self.mcmc {
+            meta.push((
+                "bounce.mcmc".to_string(),
+                format!(
+                    "chains={},burn_in={},thin={},step={},flip_prob={}",
+                    opts.n_chains, opts.burn_in, opts.thin, opts.step, opts.flip_prob
+                ),
+            ));
+        }
+        if let Some(split) = &self.split {
+            meta.push(("bounce.split".to_string(), split.to_string()));
+            if split.stratify != Stratify::None {
+                meta.push(("bounce.split.by".to_string(), split.stratify.to_string()));
+            }
+        }
+        if let Some(gradients) = &self.gradients {
+            meta.push(("bounce.gradients".to_string(), gradients.to_string()));
+        }
+        if let Some(tol) = self.precision_tol {
+            meta.push(("bounce.precision_tol".to_string(), tol.to_string()));
+        }
+        if self.grid.is_shared() {
+            let nodes: Vec<String> = self.grid.nodes().iter().map(|x| x.to_string()).collect();
+            meta.push(("bounce.grid.nodes".to_string(), nodes.join(",")));
+        }
+        meta
+    }
+}
+
+/// Settings of the `scan` subcommand
+#[derive(Debug, Clone)]
+pub struct ScanConfig {
+    pub spec: ScanSpec,
+    pub grid: GridSpec,
+    pub output: String,
+    /// Solve for the bounce action at every ordered point
+    pub action: Option<BounceOptions>,
+}
+
+impl ScanConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut fixed = vec![];
+        let mut axes = vec![];
+        let mut grid = GridSpec::Uniform { n: 100 };
+        let mut output = "scan.parquet".to_string();
+        let mut action = None;
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for '{}'", key))
+            };
+            match key.as_str() {
+                "--fix" => fixed.push(parse_fixed(&value()?)?),
+                "--sweep" => axes.push(value()?.parse::<Axis>()?),
+                "--grid" => grid = value()?.parse()?,
+                "--output" => output = value()?,
+                "--action" => action = Some(BounceOptions::default()),
+                _ => return Err(format!("unknown option '{}'", key)),
+            }
+        }
+        if fixed.len() != 2 || axes.len() != 2 {
+            return Err("scan needs two --fix name=value and two --sweep name=lo:hi:n".to_string());
+        }
+        if !grid.is_shared() {
+            return Err("scan needs a shared grid".to_string());
+        }
+        let spec = ScanSpec::new([fixed[0], fixed[1]], [axes[0], axes[1]])?;
+        Ok(Self {
+            spec,
+            grid,
+            output,
+            action,
+        })
+    }
+
+    pub fn to_metadata(&self) -> Vec<(String, String)> {
+        let fixed: Vec<String> = self
+            .spec
+            .fixed
+            .iter()
+            .map(|(p, x)| format!("{}={}", p, x))
+            .collect();
+        let mut metadata = vec![
+            ("bounce.scan.fixed".to_string(), fixed.join(",")),
+            (
+                "bounce.scan.axes".to_string(),
+                format!("{},{}", self.spec.axes[0], self.spec.axes[1]),
+            ),
+            ("bounce.grid".to_string(), self.grid.to_string()),
+        ];
+        if let Some(opts) = self.action {
+            metadata.push(("bounce.scan.action_dim".to_string(), opts.dim.to_string()));
+        }
+        metadata
+    }
+}
+
+/// Settings of the `validate` subcommand
+#[derive(Debug, Clone)]
+pub struct ValidateConfig {
+    pub path: String,
+    /// Largest allowed difference between stored and recomputed curves, relative to 1 + |V|
+    pub tol: f64,
+    /// Relative round-off above which a stored curve is flagged as unreliable
+    pub precision_tol: f64,
+}
+
+impl ValidateConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut path = None;
+        let mut tol = 1e-4;
+        let mut precision_tol = RELIABLE_TOL;
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            match key.as_str() {
+                "--tol" => {
+                    tol = args
+                        .next()
+                        .ok_or("missing value for '--tol'")?
+                        .parse()
+                        .map_err(|e| format!("invalid --tol: {}", e))?
+                }
+                "--precision-tol" => {
+                    precision_tol = args
+                        .next()
+                        .ok_or("missing value for '--precision-tol'")?
+                        .parse()
+                        .map_err(|e| format!("invalid --precision-tol: {}", e))?
+                }
+                _ if key.starts_with("--") => return Err(format!("unknown option '{}'", key)),
+                _ if path.is_none() => path = Some(key),
+                _ => return Err(format!("unexpected argument '{}'", key)),
+            }
+        }
+        Ok(Self {
+            path: path.ok_or("validate needs the path of a dataset")?,
+            tol,
+            precision_tol,
+        })
+    }
+}
+
+/// Settings of the `plot` subcommand
+#[derive(Debug, Clone)]
+pub struct PlotConfig {
+    pub path: String,
+    pub spec: PlotSpec,
+    /// Image file; SVG or PNG by extension
+    pub output: String,
+}
+
+impl PlotConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut path = None;
+        let mut spec = PlotSpec::default();
+        let mut output = "plot.png".to_string();
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            if !key.starts_with("--") {
+                if path.is_some() {
+                    return Err(format!("unexpected argument '{}'", key));
+                }
+                path = Some(key);
+                continue;
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for '{}'", key))?;
+            let invalid = |e: &dyn std::fmt::Display| format!("invalid {}: {}", key, e);
+            match key.as_str() {
+                "--kind" => spec.kind = value.parse()?,
+                "--n" => spec.n_curves = value.parse().map_err(|e| invalid(&e))?,
+                "--feature" => spec.feature = value.parse()?,
+                "--bins" => spec.bins = value.parse().map_err(|e| invalid(&e))?,
+                "--width" => spec.width = value.parse().map_err(|e| invalid(&e))?,
+                "--height" => spec.height = value.parse().map_err(|e| invalid(&e))?,
+                "--output" => output = value,
+                _ => return Err(format!("unknown option '{}'", key)),
+            }
+        }
+        if spec.bins == 0 {
+            return Err("--bins must be positive".to_string());
+        }
+        if spec.width == 0 || spec.height == 0 {
+            return Err("--width and --height must be positive".to_string());
+        }
+        Ok(Self {
+            path: path.ok_or("plot needs the path of a dataset")?,
+            spec,
+            output,
+        })
+    }
+}
+
+/// Settings of the `stats` subcommand
+#[derive(Debug, Clone)]
+pub struct StatsConfig {
+    pub path: String,
+    /// Machine-readable copy of the summary; `<path>.stats.json` by default
+    pub json: String,
+}
+
+impl StatsConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut path: Option<String> = None;
+        let mut json = None;
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            match key.as_str() {
+                "--json" => json = Some(args.next().ok_or("missing value for '--json'")?),
+                _ if key.starts_with("--") => return Err(format!("unknown option '{}'", key)),
+                _ if path.is_none() => path = Some(key),
+                _ => return Err(format!("unexpected argument '{}'", key)),
+            }
+        }
+        let path = path.ok_or("stats needs the path of a dataset")?;
+        Ok(Self {
+            json: json.unwrap_or_else(|| format!("{}.stats.json", path)),
+            path,
+        })
+    }
+}
+
+/// Settings of the `compare` subcommand
+#[derive(Debug, Clone)]
+pub struct CompareConfig {
+    pub paths: [String; 2],
+    /// Significance level below which a quantity counts as drifted
+    pub alpha: f64,
+}
+
+impl CompareConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut paths = vec![];
+        let mut alpha = 0.01;
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            match key.as_str() {
+                "--alpha" => {
+                    alpha = args
+                        .next()
+                        .ok_or("missing value for '--alpha'")?
+                        .parse()
+                        .map_err(|e| format!("invalid --alpha: {}", e))?
+                }
+                _ if key.starts_with("--") => return Err(format!("unknown option '{}'", key)),
+                _ => paths.push(key),
+            }
+        }
+        if paths.len() != 2 {
+            return Err("compare needs the paths of two datasets".to_string());
+        }
+        if !(0f64..=1f64).contains(&alpha) {
+            return Err("--alpha must be in [0, 1]".to_string());
+        }
+        Ok(Self {
+            paths: [paths[0].clone(), paths[1].clone()],
+            alpha,
+        })
+    }
+}
+
+/// Settings of the `pca` subcommand
+#[derive(Debug, Clone)]
+pub struct PcaConfig {
+    pub path: String,
+    /// Analyse V followed by V' instead of V alone
+    pub derivatives: bool,
+    /// Components written out and used for reconstruction
+    pub n_components: usize,
+    /// Mean and components; `<stem>.pca.parquet` by default
+    pub components: String,
+    /// Per-sample scores; `<stem>.scores.parquet` by default
+    pub scores: String,
+    /// Copy of the dataset with curves rebuilt from `n_components` components
+    pub reconstruct: Option<String>,
+}
+
+impl PcaConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut path: Option<String> = None;
+        let mut derivatives = false;
+        let mut n_components = 10;
+        let mut components = None;
+        let mut scores = None;
+        let mut reconstruct = None;
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for '{}'", key))
+            };
+            match key.as_str() {
+                "--derivatives" => derivatives = true,
+                "--components" => {
+                    n_components = value()?
+                        .parse()
+                        .map_err(|e| format!("invalid --components: {}", e))?
+                }
+                "--components-output" => components = Some(value()?),
+                "--scores-output" => scores = Some(value()?),
+                "--reconstruct" => reconstruct = Some(value()?),
+                _ if key.starts_with("--") => return Err(format!("unknown option '{}'", key)),
+                _ if path.is_none() => path = Some(key),
+                _ => return Err(format!("unexpected argument '{}'", key)),
+            }
+        }
+        if n_components == 0 {
+            return Err("--components must be positive".to_string());
+        }
+        let path = path.ok_or("pca needs the path of a dataset")?;
+        let stem = path.strip_suffix(".parquet").unwrap_or(&path).to_string();
+        Ok(Self {
+            derivatives,
+            n_components,
+            components: components.unwrap_or_else(|| format!("{}.pca.parquet", stem)),
+            scores: scores.unwrap_or_else(|| format!("{}.scores.parquet", stem)),
+            reconstruct,
+            path,
+        })
+    }
+}
+
+/// Settings of the `surrogate` subcommand, one of
+/// `train <dataset>`, `evaluate <model> <dataset>` and `predict <model> <dataset>`
+#[derive(Debug, Clone)]
+pub enum SurrogateConfig {
+    Train {
+        path: String,
+        spec: SurrogateSpec,
+        /// Model file; `<stem>.surrogate` by default
+        output: String,
+    },
+    Evaluate {
+        model: String,
+        path: String,
+        /// Only the samples of one split (index into `SPLIT_NAMES`)
+        split: Option<usize>,
+    },
+    Predict {
+        model: String,
+        path: String,
+        /// Predictions; `<stem>.pred.parquet` by default
+        output: String,
+    },
+}
+
+impl SurrogateConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        let action = args
+            .next()
+            .ok_or("surrogate needs an action: train, evaluate or predict")?;
+        let mut paths = vec![];
+        let mut spec = SurrogateSpec::default();
+        let mut split = None;
+        let mut output = None;
+        while let Some(key) = args.next() {
+            if !key.starts_with("--") {
+                paths.push(key);
+                continue;
+            }
+            if action == "train" && key == "--linear" {
+                spec.log = false;
+                continue;
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for '{}'", key))?;
+            let invalid = |e: &dyn std::fmt::Display| format!("invalid {}: {}", key, e);
+            match (action.as_str(), key.as_str()) {
+                ("train", "--model") => spec.model = value.parse()?,
+                ("train", "--inputs") => spec.inputs = value.parse()?,
+                ("train", "--target") => spec.target = value,
+                ("train", "--lambda") => spec.lambda = value.parse().map_err(|e| invalid(&e))?,
+                ("train", "--max-train") => {
+                    spec.max_train = value.parse().map_err(|e| invalid(&e))?
+                }
+                ("evaluate", "--split") => {
+                    split = Some(
+                        SPLIT_NAMES
+                            .iter()
+                            .position(|&s| s == value)
+                            .ok_or_else(|| {
+                                format!("unknown split '{}' (expected train, val or test)", value)
+                            })?,
+                    )
+                }
+                ("train" | "predict", "--output") => output = Some(value),
+                _ => return Err(format!("unknown option '{}' of surrogate {}", key, action)),
+            }
+        }
+        let stem = |path: &str| path.strip_suffix(".parquet").unwrap_or(path).to_string();
+        match (action.as_str(), &paths[..]) {
+            ("train", [path]) => {
+                if spec.lambda < 0f64 {
+                    return Err("--lambda must not be negative".to_string());
+                }
+                Ok(SurrogateConfig::Train {
+                    output: output.unwrap_or_else(|| format!("{}.surrogate", stem(path))),
+                    path: path.clone(),
+                    spec,
+                })
+            }
+            ("evaluate", [model, path]) => Ok(SurrogateConfig::Evaluate {
+                model: model.clone(),
+                path: path.clone(),
+                split,
+            }),
+            ("predict", [model, path]) => Ok(SurrogateConfig::Predict {
+                output: output.unwrap_or_else(|| format!("{}.pred.parquet", stem(path))),
+                model: model.clone(),
+                path: path.clone(),
+            }),
+            ("train", _) => Err("surrogate train needs the path of a dataset".to_string()),
+            ("evaluate" | "predict", _) => {
+                Err(format!("surrogate {} needs a model and a dataset", action))
+            }
+            _ => Err(format!(
+                "unknown surrogate action '{}' (expected train, evaluate or predict)",
+                action
+            )),
+        }
+    }
+}
+
+/// Options defining a `ThermalPotential`, shared by the finite-temperature subcommands
+#[derive(Debug, Clone)]
+struct ThermalArgs {
+    params: Option<C1Params>,
+    species: Vec<Species>,
+    approx: ThermalApprox,
+    scale: f64,
+    range: Option<(f64, f64)>,
+}
+
+/// C1 parameters written `phi_0,phi_1n,phi_1p,phi_2`
+fn parse_params(value: &str) -> Result<C1Params, String> {
+    let ps = value
+        .split(',')
+        .map(|x| x.parse::<f64>())
+        .collect::<Result<Vec<f64>, _>>()
+        .map_err(|e| format!("invalid --params: {}", e))?;
+    let ps: [f64; 4] = ps
+        .try_into()
+        .map_err(|_| "--params needs phi_0,phi_1n,phi_1p,phi_2".to_string())?;
+    Ok(C1Params::from_array(ps))
+}
+
+impl ThermalArgs {
+    fn new() -> Self {
+        Self {
+            params: None,
+            species: vec![],
+            approx: ThermalApprox::Exact,
+            scale: 1f64,
+            range: None,
+        }
+    }
+
+    /// Take `key value` if it is a potential option; returns whether it was one
+    fn parse(&mut self, key: &str, value: &str) -> Result<bool, String> {
+        match key {
+            "--params" => self.params = Some(parse_params(value)?),
+            "--species" => self.species.push(value.parse::<Species>()?),
+            "--approx" => self.approx = value.parse()?,
+            "--scale" => {
+                self.scale = value
+                    .parse()
+                    .ok()
+                    .filter(|&v: &f64| v > 0f64)
+                    .ok_or_else(|| {
+                        format!("invalid --scale '{}' (expected a positive number)", value)
+                    })?
+            }
+            "--range" => {
+                self.range = Some(
+                    value
+                        .split_once(':')
+                        .and_then(|(lo, hi)| Some((lo.parse().ok()?, hi.parse().ok()?)))
+                        .filter(|(lo, hi): &(f64, f64)| lo < hi)
+                        .ok_or_else(|| format!("invalid --range '{}' (expected lo:hi)", value))?,
+                )
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn build(self) -> Result<ThermalPotential, String> {
+        let params = self
+            .params
+            .ok_or("missing --params phi_0,phi_1n,phi_1p,phi_2")?;
+        if !params.is_ordered() {
+            return Err("--params do not satisfy the ordering of the C1 family".to_string());
+        }
+        if self.species.is_empty() {
+            return Err("the thermal potential needs at least one --species".to_string());
+        }
+        let mut potential =
+            ThermalPotential::new(params, self.species, self.approx).with_scale(self.scale);
+        if let Some(range) = self.range {
+            potential.range = range;
+        }
+        Ok(potential)
+    }
+}
+
+fn thermal_metadata(p: &ThermalPotential) -> Vec<(String, String)> {
+    let species: Vec<String> = p.species.iter().map(|s| s.to_string()).collect();
+    let params: Vec<String> = p.tree.to_array().iter().map(|x| x.to_string()).collect();
+    vec![
+        ("bounce.thermal.params".to_string(), params.join(",")),
+        ("bounce.thermal.species".to_string(), species.join(";")),
+        ("bounce.thermal.approx".to_string(), p.approx.to_string()),
+        ("bounce.thermal.scale".to_string(), p.scale.to_string()),
+        (
+            "bounce.thermal.range".to_string(),
+            format!("{}:{}", p.range.0, p.range.1),
+        ),
+    ]
+}
+
+/// Settings of the `thermal` subcommand
+#[derive(Debug, Clone)]
+pub struct ThermalConfig {
+    pub potential: ThermalPotential,
+    pub temps: TemperatureRange,
+    pub output: String,
+}
+
+impl ThermalConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut potential = ThermalArgs::new();
+        let mut temps = None;
+        let mut output = "thermal.parquet".to_string();
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for '{}'", key))?;
+            if potential.parse(&key, &value)? {
+                continue;
+            }
+            match key.as_str() {
+                "--temps" => temps = Some(value.parse()?),
+                "--output" => output = value,
+                _ => return Err(format!("unknown option '{}'", key)),
+            }
+        }
+        Ok(Self {
+            potential: potential.build()?,
+            temps: temps.ok_or("thermal needs --temps lo:hi:n")?,
+            output,
+        })
+    }
+
+    pub fn to_metadata(&self) -> Vec<(String, String)> {
+        let mut metadata = thermal_metadata(&self.potential);
+        metadata.push(("bounce.thermal.temps".to_string(), self.temps.to_string()));
+        metadata
+    }
+}
+
+/// Settings of the `transition` subcommand
+#[derive(Debug, Clone)]
+pub struct TransitionConfig {
+    pub potential: ThermalPotential,
+    /// Grid searched for the critical temperature and scanned down from it for nucleation
+    pub temps: TemperatureRange,
+    pub options: TransitionOptions,
+    /// Machine-readable copy of the parameters
+    pub json: String,
+}
+
+impl TransitionConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut potential = ThermalArgs::new();
+        let mut temps = None;
+        let mut options = TransitionOptions::default();
+        let mut json = "transition.json".to_string();
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for '{}'", key))?;
+            if potential.parse(&key, &value)? {
+                continue;
+            }
+            let positive = |value: &str| {
+                value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|&x| x > 0f64)
+                    .ok_or_else(|| {
+                        format!("invalid {} '{}' (expected a positive number)", key, value)
+                    })
+            };
+            match key.as_str() {
+                "--temps" => temps = Some(value.parse()?),
+                "--target" => options.target = positive(&value)?,
+                "--g-star" => options.g_star = positive(&value)?,
+                "--step" => options.step = positive(&value)?,
+                "--tol" => options.tol = positive(&value)?,
+                "--json" => json = value,
+                _ => return Err(format!("unknown option '{}'", key)),
+            }
+        }
+        Ok(Self {
+            potential: potential.build()?,
+            temps: temps.ok_or("transition needs --temps lo:hi:n")?,
+            options,
+            json,
+        })
+    }
+}
+
+/// Where the `gw` subcommand takes the transition parameters from
+#[derive(Debug, Clone)]
+pub enum TransitionSource {
+    /// Found from a thermal potential as by the `transition` subcommand
+    Potential {
+        potential: ThermalPotential,
+        temps: TemperatureRange,
+        options: TransitionOptions,
+    },
+    Given {
+        alpha: f64,
+        beta_over_h: f64,
+        t_n: f64,
+    },
+}
+
+/// Settings of the `gw` subcommand
+#[derive(Debug, Clone)]
+pub struct GwConfig {
+    pub source: TransitionSource,
+    pub options: GwOptions,
+    pub freqs: FrequencyRange,
+    pub output: String,
+}
+
+impl GwConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut potential = ThermalArgs::new();
+        let mut has_potential = false;
+        let mut temps = None;
+        let mut transition = TransitionOptions::default();
+        let (mut alpha, mut beta_over_h, mut t_n) = (None, None, None);
+        let mut options = GwOptions::default();
+        let mut freqs = FrequencyRange::default();
+        let mut output = "gw.parquet".to_string();
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for '{}'", key))?;
+            if potential.parse(&key, &value)? {
+                has_potential = true;
+                continue;
+            }
+            let number = |value: &str, lo: f64, hi: f64| {
+                value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|&x| x > lo && x <= hi)
+                    .ok_or_else(|| {
+                        format!(
+                            "invalid {} '{}' (expected a number in ({}, {}])",
+                            key, value, lo, hi
+                        )
+                    })
+            };
+            let positive = |value: &str| number(value, 0f64, f64::INFINITY);
+            let fraction = |value: &str| {
+                value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|x| (0f64..=1f64).contains(x))
+                    .ok_or_else(|| {
+                        format!("invalid {} '{}' (expected a number in [0, 1])", key, value)
+                    })
+            };
+            match key.as_str() {
+                "--temps" => temps = Some(value.parse()?),
+                "--target" => transition.target = positive(&value)?,
+                "--step" => transition.step = positive(&value)?,
+                "--tol" => transition.tol = positive(&value)?,
+                "--alpha" => alpha = Some(positive(&value)?),
+                "--beta-over-h" => beta_over_h = Some(positive(&value)?),
+                "--t-n" => t_n = Some(positive(&value)?),
+                "--v-w" => options.v_w = number(&value, 0f64, 1f64)?,
+                "--kappa-sw" => options.kappa_sw = value.parse()?,
+                "--kappa-col" => options.kappa_col = fraction(&value)?,
+                "--epsilon-turb" => options.epsilon_turb = fraction(&value)?,
+                "--g-star" => {
+                    options.g_star = positive(&value)?;
+                    transition.g_star = options.g_star;
+                }
+                "--unit-gev" => options.unit_gev = positive(&value)?,
+                "--freqs" => freqs = value.parse()?,
+                "--output" => output = value,
+                _ => return Err(format!("unknown option '{}'", key)),
+            }
+        }
+        let source = match (alpha, beta_over_h, t_n) {
+            (Some(alpha), Some(beta_over_h), Some(t_n)) if !has_potential => TransitionSource::Given {
+                alpha,
+                beta_over_h,
+                t_n,
+            },
+            (None, None, None) if has_potential => TransitionSource::Potential {
+                potential: potential.build()?,
+                temps: temps.ok_or("gw needs --temps lo:hi:n to find the transition")?,
+                options: transition,
+            },
+            _ => {
+                return Err(
+                    "gw needs either a thermal potential (--params, --species, ...) or all of --alpha, --beta-over-h and --t-n"
+                        .to_string(),
+                )
+            }
+        };
+        Ok(Self {
+            source,
+            options,
+            freqs,
+            output,
+        })
+    }
+
+    pub fn to_metadata(&self) -> Vec<(String, String)> {
+        let mut metadata = match &self.source {
+            TransitionSource::Potential {
+                potential,
+                temps,
+                options,
+            } => {
+                let mut metadata = thermal_metadata(potential);
+                metadata.push(("bounce.thermal.temps".to_string(), temps.to_string()));
+                metadata.push((
+                    "bounce.transition.target".to_string(),
+                    options.target.to_string(),
+                ));
+                metadata
+            }
+            TransitionSource::Given { .. } => vec![],
+        };
+        let o = &self.options;
+        metadata.extend([
+            ("bounce.gw.v_w".to_string(), o.v_w.to_string()),
+            ("bounce.gw.kappa_sw".to_string(), o.kappa_sw.to_string()),
+            ("bounce.gw.kappa_col".to_string(), o.kappa_col.to_string()),
+            (
+                "bounce.gw.epsilon_turb".to_string(),
+                o.epsilon_turb.to_string(),
+            ),
+            ("bounce.gw.g_star".to_string(), o.g_star.to_string()),
+            ("bounce.gw.unit_gev".to_string(), o.unit_gev.to_string()),
+        ]);
+        metadata
+    }
+}
+
+/// Settings of the `cdl` subcommand
+#[derive(Debug, Clone)]
+pub struct CdlConfig {
+    pub params: C1Params,
+    /// Constant added to the C1 potential, setting the false-vacuum energy
+    pub vacuum_energy: f64,
+    /// Reduced Planck masses; `inf` is the flat-space limit
+    pub planck_masses: Vec<f64>,
+    pub output: String,
+}
+
+impl CdlConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut params = None;
+        let mut vacuum_energy = 0f64;
+        let mut planck_masses = vec![f64::INFINITY];
+        let mut output = "cdl.parquet".to_string();
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for '{}'", key))?;
+            match key.as_str() {
+                "--params" => params = Some(parse_params(&value)?),
+                "--vacuum-energy" => {
+                    vacuum_energy = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|x| x.is_finite())
+                        .ok_or_else(|| {
+                            format!("invalid --vacuum-energy '{}' (expected a number)", value)
+                        })?
+                }
+                "--planck-mass" => {
+                    planck_masses = value
+                        .split(',')
+                        .map(|x| {
+                            x.parse::<f64>().ok().filter(|&m| m > 0f64).ok_or_else(|| {
+                                format!(
+                                    "invalid Planck mass '{}' (expected a positive number or inf)",
+                                    x
+                                )
+                            })
+                        })
+                        .collect::<Result<Vec<f64>, String>>()?
+                }
+                "--output" => output = value,
+                _ => return Err(format!("unknown option '{}'", key)),
+            }
+        }
+        let params = params.ok_or("missing --params phi_0,phi_1n,phi_1p,phi_2")?;
+        if !params.is_ordered() {
+            return Err("--params do not satisfy the ordering of the C1 family".to_string());
+        }
+        Ok(Self {
+            params,
+            vacuum_energy,
+            planck_masses,
+            output,
+        })
+    }
+
+    pub fn to_metadata(&self) -> Vec<(String, String)> {
+        let params: Vec<String> = self
+            .params
+            .to_array()
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        let masses: Vec<String> = self.planck_masses.iter().map(|m| m.to_string()).collect();
+        vec![
+            ("bounce.cdl.params".to_string(), params.join(",")),
+            (
+                "bounce.cdl.vacuum_energy".to_string(),
+                self.vacuum_energy.to_string(),
+            ),
+            ("bounce.cdl.planck_mass".to_string(), masses.join(",")),
+        ]
+    }
+}
+
+/// Settings of the `migrate` subcommand
+#[derive(Debug, Clone)]
+pub struct MigrateConfig {
+    /// v1 dataset to convert
+    pub path: String,
+    /// Converted dataset; `<stem>.v2.parquet` by default
+    pub output: String,
+    pub compression: Codec,
+}
+
+impl MigrateConfig {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut path: Option<String> = None;
+        let mut output = None;
+        let mut compression = Codec::Uncompressed;
+        let mut args = args.into_iter();
+        while let Some(key) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for '{}'", key))
+            };
+            match key.as_str() {
+                "--output" => output = Some(value()?),
+                "--compression" => compression = value()?.parse()?,
+                _ if key.starts_with("--") => return Err(format!("unknown option '{}'", key)),
+                _ if path.is_none() => path = Some(key),
+                _ => return Err(format!("unexpected argument '{}'", key)),
+            }
+        }
+        let path = path.ok_or("migrate needs the path of a v1 dataset")?;
+        Ok(Self {
+            output: output
+                .unwrap_or_else(|| format!("{}.v2.parquet", path.trim_end_matches(".parquet"))),
+            path,
+            compression,
+        })
+    }
+}
@@ -0,0 +1,293 @@
+use crate::c1::C1Params;
+use crate::output::{sample_columns, Sample, SCHEMA_VERSION};
+use arrow2::array::Float64Array;
+use arrow2::datatypes::Schema;
+use arrow2::io::parquet::read::{self, RowGroupMetaData};
+use std::error::Error;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// A generated parquet dataset, opened for reading
+///
+/// Only the footer is read on opening; samples are read one row group at a time. Columns
+/// after `dv_{j}` are labels attached to the samples (e.g. bounce actions).
+#[derive(Debug, Clone)]
+pub struct Dataset {
+    path: PathBuf,
+    schema: Schema,
+    row_groups: Vec<RowGroupMetaData>,
+    metadata: Vec<(String, String)>,
+    grid_len: usize,
+    per_sample_grid: bool,
+    grid: Option<Vec<f64>>,
+    labels: Vec<String>,
+}
+
+/// A sample together with its label columns
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub sample: Sample,
+    /// Values of the label columns, in the order of `Dataset::labels`
+    pub labels: Vec<f64>,
+}
+
+impl Dataset {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = File::open(&path)?;
+        let file_metadata = read::read_metadata(&mut file)?;
+        let schema = read::infer_schema(&file_metadata)?;
+        let metadata: Vec<(String, String)> = file_metadata
+            .key_value_metadata()
+            .iter()
+            .flatten()
+            .map(|kv| (kv.key.clone(), kv.value.clone().unwrap_or_default()))
+            .collect();
+
+        let names: Vec<&str> = schema.fields.iter().map(|f| f.name.as_str()).collect();
+        let version = metadata
+            .iter()
+            .find(|(k, _)| k == "bounce.schema_version")
+            .map(|(_, v)| v.as_str());
+        match version {
+            Some(v) if v == SCHEMA_VERSION.to_string() => {}
+            Some(v) => {
+                return Err(format!(
+                    "{} has schema version {}, expected {}",
+                    path.display(),
+                    v,
+                    SCHEMA_VERSION
+                )
+                .into())
+            }
+            None if is_v1_layout(&names) => {
+                return Err(format!(
+                    "{} is a v1 dataset (one v<i> column per sample, without parameters); convert it with `bounce migrate {} --output <file>`",
+                    path.display(),
+                    path.display()
+                )
+                .into())
+            }
+            None => {
+                return Err(format!(
+                    "{} has no schema version (written before version {}?)",
+                    path.display(),
+                    SCHEMA_VERSION
+                )
+                .into())
+            }
+        }
+
+        let grid_len = names.iter().filter(|n| n.starts_with("v_")).count();
+        let per_sample_grid = names.iter().any(|n| n.starts_with("grid_"));
+        let expected = sample_columns(grid_len, per_sample_grid);
+        if names.len() < expected.len() || names[..expected.len()] != expected[..] {
+            return Err(format!(
+                "{} does not have the columns of a sample table",
+                path.display()
+            )
+            .into());
+        }
+        let labels = names[expected.len()..]
+            .iter()
+            .map(|n| n.to_string())
+            .collect();
+
+        let grid = metadata
+            .iter()
+            .find(|(k, _)| k == "bounce.grid.nodes")
+            .map(|(_, v)| {
+                v.split(',')
+                    .map(|x| x.parse::<f64>())
+                    .collect::<Result<Vec<f64>, _>>()
+            })
+            .transpose()
+            .map_err(|e| format!("invalid bounce.grid.nodes in {}: {}", path.display(), e))?;
+        if grid.as_ref().is_some_and(|g| g.len() != grid_len) {
+            return Err(
+                format!("grid nodes in {} do not match its columns", path.display()).into(),
+            );
+        }
+
+        Ok(Self {
+            path,
+            schema,
+            row_groups: file_metadata.row_groups,
+            metadata,
+            grid_len,
+            per_sample_grid,
+            grid,
+            labels,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.row_groups.iter().map(|rg| rg.num_rows()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Value of a key of the file metadata
+    pub fn metadata(&self, key: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn metadata_entries(&self) -> &[(String, String)] {
+        &self.metadata
+    }
+
+    pub fn grid_len(&self) -> usize {
+        self.grid_len
+    }
+
+    /// Shared grid of all samples; `None` for per-sample (adaptive) grids
+    pub fn grid(&self) -> Option<&[f64]> {
+        self.grid.as_deref()
+    }
+
+    pub fn has_per_sample_grid(&self) -> bool {
+        self.per_sample_grid
+    }
+
+    /// Names of the label columns
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    pub fn n_row_groups(&self) -> usize {
+        self.row_groups.len()
+    }
+
+    /// Read the records of one row group
+    pub fn read_row_group(&self, index: usize) -> Result<Vec<Record>, Box<dyn Error>> {
+        let file = File::open(&self.path)?;
+        let row_group = self
+            .row_groups
+            .get(index)
+            .ok_or_else(|| format!("row group {} out of range", index))?;
+        let reader = read::FileReader::new(
+            file,
+            vec![row_group.clone()],
+            self.schema.clone(),
+            None,
+            None,
+            None,
+        );
+        let g = self.grid_len;
+        let offset = if self.per_sample_grid { 5 + g } else { 5 };
+        let n_labels = self.labels.len();
+        let mut records = Vec::with_capacity(row_group.num_rows());
+        for chunk in reader {
+            let chunk = chunk?;
+            let columns: Vec<&Float64Array> = chunk
+                .arrays()
+                .iter()
+                .map(|a| {
+                    a.as_any()
+                        .downcast_ref::<Float64Array>()
+                        .ok_or("expected f64 columns")
+                })
+                .collect::<Result<_, _>>()?;
+            for r in 0..chunk.len() {
+                let at = |j: usize| columns[j].value(r);
+                records.push(Record {
+                    sample: Sample {
+                        params: C1Params::from_array([at(0), at(1), at(2), at(3)]),
+                        grid: self
+                            .per_sample_grid
+                            .then(|| (0..g).map(|j| at(5 + j)).collect()),
+                        v: (0..g).map(|j| at(offset + j)).collect(),
+                        dv: (0..g).map(|j| at(offset + g + j)).collect(),
+                        weight: at(4),
+                    },
+                    labels: (0..n_labels).map(|j| at(offset + 2 * g + j)).collect(),
+                });
+            }
+        }
+        Ok(records)
+    }
+
+    /// Row groups in file order, each read when the iterator reaches it
+    pub fn row_groups(&self) -> impl Iterator<Item = Result<Vec<Record>, Box<dyn Error>>> + '_ {
+        (0..self.n_row_groups()).map(|i| self.read_row_group(i))
+    }
+
+    /// All records, read lazily one row group at a time
+    pub fn records(&self) -> impl Iterator<Item = Result<Record, Box<dyn Error>>> + '_ {
+        self.row_groups().flat_map(|rg| match rg {
+            Ok(records) => records.into_iter().map(Ok).collect::<Vec<_>>(),
+            Err(e) => vec![Err(e)],
+        })
+    }
+
+    /// Read every record into memory
+    pub fn load(&self) -> Result<Vec<Record>, Box<dyn Error>> {
+        self.records().collect()
+    }
+}
+
+/// Whether the columns are those of a v1 file: `v0`, `v1`, ..., one per sample
+fn is_v1_layout(names: &[&str]) -> bool {
+    !names.is_empty()
+        && names.iter().all(|n| {
+            n.strip_prefix('v')
+                .is_some_and(|i| !i.is_empty() && i.bytes().all(|b| b.is_ascii_digit()))
+        })
+}
+
+/// `(V, V')` of a sample on its grid
+pub type Curve = (Vec<f64>, Vec<f64>);
+
+/// Curves of a v1 dataset, the layout written before schema versions
+///
+/// Each sample is a column `v{i}` holding V and then V' on `linspace(0, 1, n)`; the
+/// parameters were not stored. Returns the curves in column order.
+pub fn read_v1<P: AsRef<Path>>(path: P) -> Result<Vec<Curve>, Box<dyn Error>> {
+    let path = path.as_ref();
+    let mut file = File::open(path)?;
+    let file_metadata = read::read_metadata(&mut file)?;
+    let schema = read::infer_schema(&file_metadata)?;
+    let names: Vec<&str> = schema.fields.iter().map(|f| f.name.as_str()).collect();
+    if !is_v1_layout(&names) {
+        return Err(format!(
+            "{} does not have the columns of a v1 dataset",
+            path.display()
+        )
+        .into());
+    }
+    let mut columns: Vec<Vec<f64>> = vec![vec![]; names.len()];
+    let reader = read::FileReader::new(
+        file,
+        file_metadata.row_groups,
+        schema.clone(),
+        None,
+        None,
+        None,
+    );
+    for chunk in reader {
+        let chunk = chunk?;
+        for (column, array) in columns.iter_mut().zip(chunk.arrays()) {
+            let array = array
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .ok_or("expected f64 columns")?;
+            column.extend(array.values_iter());
+        }
+    }
+    let n = columns[0].len() / 2;
+    if columns.iter().any(|c| c.len() != 2 * n) || n < 2 {
+        return Err(format!("{} has columns of unequal or odd length", path.display()).into());
+    }
+    Ok(columns
+        .into_iter()
+        .map(|mut v| {
+            let dv = v.split_off(n);
+            (v, dv)
+        })
+        .collect())
+}
@@ -0,0 +1,131 @@
+use peroxide::fuga::PowOps;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Forward-mode dual number carrying `N` tangent directions at once
+///
+/// Peroxide's `AD` propagates a single direction, so a full gradient of the C1 expressions
+/// would need one pass per parameter; `Dual<4>` gets all four in one pass.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Dual<const N: usize> {
+    pub x: f64,
+    pub dx: [f64; N],
+}
+
+impl<const N: usize> Dual<N> {
+    pub fn constant(x: f64) -> Self {
+        Self { x, dx: [0f64; N] }
+    }
+
+    /// Independent variable seeded along the `i`-th direction
+    pub fn var(x: f64, i: usize) -> Self {
+        let mut dx = [0f64; N];
+        dx[i] = 1f64;
+        Self { x, dx }
+    }
+
+    fn chain(&self, f: f64, df: f64) -> Self {
+        Self {
+            x: f,
+            dx: self.dx.map(|d| df * d),
+        }
+    }
+}
+
+impl<const N: usize> Neg for Dual<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.chain(-self.x, -1f64)
+    }
+}
+
+impl<const N: usize> Add for Dual<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut dx = self.dx;
+        dx.iter_mut().zip(rhs.dx).for_each(|(a, b)| *a += b);
+        Self {
+            x: self.x + rhs.x,
+            dx,
+        }
+    }
+}
+
+impl<const N: usize> Sub for Dual<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let mut dx = self.dx;
+        dx.iter_mut().zip(rhs.dx).for_each(|(a, b)| *a -= b);
+        Self {
+            x: self.x - rhs.x,
+            dx,
+        }
+    }
+}
+
+impl<const N: usize> Mul for Dual<N> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        let mut dx = self.dx;
+        dx.iter_mut()
+            .zip(rhs.dx)
+            .for_each(|(a, b)| *a = *a * rhs.x + self.x * b);
+        Self {
+            x: self.x * rhs.x,
+            dx,
+        }
+    }
+}
+
+impl<const N: usize> Div for Dual<N> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        let inv = 1f64 / rhs.x;
+        let x = self.x * inv;
+        let mut dx = self.dx;
+        dx.iter_mut()
+            .zip(rhs.dx)
+            .for_each(|(a, b)| *a = (*a - x * b) * inv);
+        Self { x, dx }
+    }
+}
+
+impl<const N: usize> Mul<Dual<N>> for f64 {
+    type Output = Dual<N>;
+
+    fn mul(self, rhs: Dual<N>) -> Dual<N> {
+        rhs.chain(self * rhs.x, self)
+    }
+}
+
+impl<const N: usize> PowOps for Dual<N> {
+    type Float = f64;
+
+    fn powi(&self, n: i32) -> Self {
+        self.chain(self.x.powi(n), n as f64 * self.x.powi(n - 1))
+    }
+
+    fn powf(&self, f: f64) -> Self {
+        self.chain(self.x.powf(f), f * self.x.powf(f - 1f64))
+    }
+
+    fn pow(&self, y: Self) -> Self {
+        let z = self.x.powf(y.x);
+        let mut dx = [0f64; N];
+        for (d, (a, b)) in dx.iter_mut().zip(self.dx.iter().zip(y.dx)) {
+            *d = z * (y.x * a / self.x + b * self.x.ln());
+        }
+        Self { x: z, dx }
+    }
+
+    fn sqrt(&self) -> Self {
+        let s = self.x.sqrt();
+        self.chain(s, 0.5 / s)
+    }
+}
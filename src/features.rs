@@ -0,0 +1,200 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Acceptance criteria for a sampled potential on its grid
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Acceptance {
+    pub v_max_min: f64,
+    pub v_max_max: f64,
+    pub max_maxima: usize,
+    pub max_minima: usize,
+}
+
+impl Default for Acceptance {
+    fn default() -> Self {
+        Self {
+            v_max_min: 0.01,
+            v_max_max: 10f64.powf(-0.5),
+            max_maxima: 1,
+            max_minima: 2,
+        }
+    }
+}
+
+impl Acceptance {
+    pub fn accepts(&self, v: &[f64]) -> bool {
+        let v_max = v.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+        if v_max < self.v_max_min || v_max > self.v_max_max {
+            return false;
+        }
+        let (count_max, count_min) = count_local_extrema(v);
+        count_max <= self.max_maxima && count_min <= self.max_minima
+    }
+}
+
+/// Scalar summaries of a potential sampled on a grid (false vacuum at φ = 0 with V = 0)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Features {
+    /// Barrier height V_max
+    pub v_max: f64,
+    /// φ at the barrier top
+    pub phi_top: f64,
+    /// ΔV = V(0) - min V
+    pub delta_v: f64,
+    /// ΔV / V_max
+    pub delta_ratio: f64,
+    /// Thin-wall parameter, wall thickness over the thin-wall bubble radius
+    ///
+    /// `φ_* ΔV / (3 σ sqrt(2 V_max))` with the tension `σ = ∫ sqrt(2V) dφ` over the barrier
+    /// and φ_* where V returns to zero; small values mean the thin-wall limit applies.
+    pub thin_wall: f64,
+    pub n_maxima: usize,
+    pub n_minima: usize,
+}
+
+impl Features {
+    pub fn new(phi: &[f64], v: &[f64]) -> Self {
+        let (i_top, v_max) = v.iter().enumerate().fold(
+            (0, f64::NEG_INFINITY),
+            |(i, m), (j, &x)| if x > m { (j, x) } else { (i, m) },
+        );
+        let v_min = v.iter().fold(f64::INFINITY, |a, &b| a.min(b));
+        let delta_v = v[0] - v_min;
+
+        // Barrier region: from the false vacuum until V drops below zero again past the top
+        let mut sigma = 0f64;
+        let mut phi_star = phi[phi.len() - 1];
+        for i in 1..phi.len() {
+            if i > i_top && v[i] < 0f64 {
+                // Linear interpolation of the zero crossing
+                let t = v[i - 1] / (v[i - 1] - v[i]);
+                phi_star = phi[i - 1] + t * (phi[i] - phi[i - 1]);
+                sigma += 0.5 * (2f64 * v[i - 1].max(0f64)).sqrt() * (phi_star - phi[i - 1]);
+                break;
+            }
+            let (a, b) = (v[i - 1].max(0f64), v[i].max(0f64));
+            sigma += 0.5 * ((2f64 * a).sqrt() + (2f64 * b).sqrt()) * (phi[i] - phi[i - 1]);
+        }
+
+        let (n_maxima, n_minima) = count_local_extrema(v);
+        Self {
+            v_max,
+            phi_top: phi[i_top],
+            delta_v,
+            delta_ratio: delta_v / v_max,
+            thin_wall: phi_star * delta_v / (3f64 * sigma * (2f64 * v_max).sqrt()),
+            n_maxima,
+            n_minima,
+        }
+    }
+}
+
+/// A scalar feature used to steer or summarize datasets
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FeatureKind {
+    /// log10 of the barrier height
+    LogBarrier,
+    /// ΔV / V_max
+    DeltaRatio,
+    ThinWall,
+}
+
+impl FeatureKind {
+    pub fn value(&self, f: &Features) -> f64 {
+        match self {
+            FeatureKind::LogBarrier => f.v_max.log10(),
+            FeatureKind::DeltaRatio => f.delta_ratio,
+            FeatureKind::ThinWall => f.thin_wall,
+        }
+    }
+}
+
+impl fmt::Display for FeatureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeatureKind::LogBarrier => write!(f, "log_barrier"),
+            FeatureKind::DeltaRatio => write!(f, "delta_ratio"),
+            FeatureKind::ThinWall => write!(f, "thin_wall"),
+        }
+    }
+}
+
+impl FromStr for FeatureKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "log_barrier" => Ok(FeatureKind::LogBarrier),
+            "delta_ratio" => Ok(FeatureKind::DeltaRatio),
+            "thin_wall" => Ok(FeatureKind::ThinWall),
+            _ => Err(format!(
+                "unknown feature '{}' (expected log_barrier, delta_ratio or thin_wall)",
+                s
+            )),
+        }
+    }
+}
+
+/// Whether every value of a stored curve is finite; features of other curves are meaningless
+pub fn is_finite_curve(v: &[f64]) -> bool {
+    v.iter().all(|x| x.is_finite())
+}
+
+pub fn count_local_extrema(ys: &[f64]) -> (usize, usize) {
+    let mut count_maxima = 0;
+    let mut count_minima = 0;
+
+    ys.iter().enumerate().for_each(|(i, y)| {
+        if i == 0 {
+            if y > &ys[1] {
+                count_maxima += 1;
+            } else if y < &ys[1] {
+                count_minima += 1;
+            }
+        } else if i == ys.len() - 1 {
+            if y > &ys[i - 1] {
+                count_maxima += 1;
+            } else if y < &ys[i - 1] {
+                count_minima += 1;
+            }
+        } else {
+            match y.partial_cmp(&ys[i - 1]) {
+                Some(Ordering::Greater) if y > &ys[i + 1] => {
+                    count_maxima += 1;
+                }
+                Some(Ordering::Less) if y < &ys[i + 1] => {
+                    count_minima += 1;
+                }
+                // Equal elements, and NaN, which is neither a maximum nor a minimum
+                _ => {}
+            }
+        }
+    });
+
+    (count_maxima, count_minima)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acceptance_screens_height_and_extrema() {
+        let acceptance = Acceptance::default();
+        // One barrier of height 0.1 between the false vacuum and a lower true vacuum
+        assert!(acceptance.accepts(&[0f64, 0.05, 0.1, 0.05, -0.1, -0.2]));
+        // Barrier too low or too high
+        assert!(!acceptance.accepts(&[0f64, 0.005, -0.1]));
+        assert!(!acceptance.accepts(&[0f64, 0.5, -0.1]));
+        // Two barriers
+        assert!(!acceptance.accepts(&[0f64, 0.1, 0.05, 0.1, -0.1]));
+    }
+
+    #[test]
+    fn local_extrema_count_endpoints_and_skip_nan() {
+        assert_eq!(count_local_extrema(&[0f64, 0.1, 0.05, 0.1, -0.1]), (2, 3));
+        assert_eq!(count_local_extrema(&[0f64, 1f64, 1f64, 0f64]), (0, 2));
+        assert_eq!(count_local_extrema(&[0f64, f64::NAN, 1f64, 0f64]), (0, 1));
+    }
+}
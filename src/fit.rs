@@ -0,0 +1,279 @@
+use crate::c1::{c1_potential_grad, C1Params};
+use peroxide::fuga::*;
+
+/// Least-squares fit of a C1 member to tabulated (φ_i, V_i) samples
+#[derive(Debug, Clone)]
+pub struct C1Fit {
+    pub params: C1Params,
+    /// `V_fit(φ_i) - V_i`
+    pub residuals: Vec<f64>,
+    /// Sum of squared residuals
+    pub rss: f64,
+    /// Covariance of (phi_0, phi_1n, phi_1p, phi_2) from the linearized model
+    pub covariance: Matrix,
+    /// One-sigma uncertainties, `sqrt(diag(covariance))`
+    pub std_err: [f64; 4],
+    pub iterations: usize,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FitOptions {
+    pub max_iter: usize,
+    /// Stop once the relative decrease of the RSS falls below this
+    pub tol: f64,
+    pub lambda_init: f64,
+    /// Restrict to one branch (`Some(true)` = swapped, `phi_0 > phi_2`); `None` tries both
+    pub swap: Option<bool>,
+    /// Start only from these parameters (and on their branch) instead of the fixed starting points
+    pub init: Option<C1Params>,
+}
+
+impl Default for FitOptions {
+    fn default() -> Self {
+        Self {
+            max_iter: 200,
+            tol: 1e-12,
+            lambda_init: 1e-3,
+            swap: None,
+            init: None,
+        }
+    }
+}
+
+/// Find the C1 parameters closest to the samples `(phi, v)` in the least-squares sense
+///
+/// The ordering constraints are enforced by fitting in unconstrained coordinates that map
+/// onto ascending values in (0, 1), once per branch (`phi_0 < phi_2` and the swapped one)
+/// and from several starting points; the best local minimum wins.
+///
+/// Different parameter sets can produce the same curve (V' may vanish at points other than
+/// phi_0), so the result is one member of possibly several equivalent ones and `std_err`
+/// only describes the local curvature around it.
+pub fn fit_c1(phi: &[f64], v: &[f64], opts: FitOptions) -> C1Fit {
+    assert_eq!(phi.len(), v.len(), "phi and v must have the same length");
+    assert!(
+        phi.len() > 4,
+        "need more than four samples to fit four parameters"
+    );
+
+    let knots = [0.1, 0.3, 0.5, 0.7, 0.9];
+    let branches = match opts.swap {
+        Some(swap) => vec![swap],
+        None => vec![false, true],
+    };
+    let starts: Vec<([f64; 4], bool)> = match opts.init {
+        Some(p) => vec![p.to_sorted()],
+        None => branches
+            .into_iter()
+            .flat_map(|swap| {
+                (0..knots.len()).map(move |skip| {
+                    let us: Vec<f64> = (0..knots.len())
+                        .filter(|&k| k != skip)
+                        .map(|k| knots[k])
+                        .collect();
+                    ([us[0], us[1], us[2], us[3]], swap)
+                })
+            })
+            .collect(),
+    };
+    let mut best: Option<(Vec<f64>, bool, f64, usize)> = None;
+    for (us, swap) in starts {
+        let (z, rss, iter) = levenberg_marquardt(phi, v, sorted_to_free(&us), swap, opts);
+        if best.as_ref().is_none_or(|b| rss < b.2) {
+            best = Some((z, swap, rss, iter));
+        }
+    }
+    let (z, swap, rss, iterations) = best.unwrap();
+    let params = C1Params::from_sorted(free_to_sorted(&z), swap);
+
+    let f = params.potential();
+    let residuals: Vec<f64> = phi.iter().zip(v).map(|(&x, &y)| f(x) - y).collect();
+
+    // Covariance in the physical parameters: s^2 (J^T J)^-1
+    let g = c1_potential_grad(params.phi_0, params.phi_1n, params.phi_1p, params.phi_2);
+    let jac: Vec<f64> = phi.iter().flat_map(|&x| g(x)).collect();
+    let jac = matrix(jac, phi.len(), 4, Row);
+    let dof = (phi.len() - 4) as f64;
+    let covariance = (&jac.t() * &jac).pseudo_inv() * (rss / dof);
+    let mut std_err = [0f64; 4];
+    for (i, s) in std_err.iter_mut().enumerate() {
+        *s = covariance[(i, i)].max(0f64).sqrt();
+    }
+
+    C1Fit {
+        params,
+        residuals,
+        rss,
+        covariance,
+        std_err,
+        iterations,
+    }
+}
+
+fn levenberg_marquardt(
+    phi: &[f64],
+    v: &[f64],
+    mut z: Vec<f64>,
+    swap: bool,
+    opts: FitOptions,
+) -> (Vec<f64>, f64, usize) {
+    let mut lambda = opts.lambda_init;
+    let mut rss = rss_at(phi, v, &z, swap);
+    let mut iter = 0;
+    while iter < opts.max_iter {
+        iter += 1;
+        let (jtj, jtr) = normal_equations(phi, v, &z, swap);
+        if !jtj.data.iter().chain(&jtr).all(|x| x.is_finite()) {
+            break;
+        }
+        let mut improved = false;
+        while lambda < 1e16 {
+            let mut a = jtj.clone();
+            for i in 0..4 {
+                a[(i, i)] += lambda * jtj[(i, i)].max(1e-12);
+            }
+            let step = a.solve(&jtr.fmap(|x| -x), LU);
+            let z_new = z.add_v(&step);
+            let rss_new = rss_at(phi, v, &z_new, swap);
+            if rss_new.is_finite() && rss_new < rss {
+                let rel = (rss - rss_new) / rss.max(f64::MIN_POSITIVE);
+                z = z_new;
+                rss = rss_new;
+                lambda = (lambda / 10f64).max(1e-12);
+                improved = rel > opts.tol;
+                break;
+            }
+            lambda *= 10f64;
+        }
+        if !improved {
+            break;
+        }
+    }
+    (z, rss, iter)
+}
+
+fn rss_at(phi: &[f64], v: &[f64], z: &[f64], swap: bool) -> f64 {
+    let f = C1Params::from_sorted(free_to_sorted(z), swap).potential();
+    phi.iter().zip(v).map(|(&x, &y)| (f(x) - y).powi(2)).sum()
+}
+
+/// `J^T J` and `J^T r` in the free coordinates
+fn normal_equations(phi: &[f64], v: &[f64], z: &[f64], swap: bool) -> (Matrix, Vec<f64>) {
+    let us = free_to_sorted(z);
+    let p = C1Params::from_sorted(us, swap);
+    let f = p.potential();
+    let g = c1_potential_grad(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+    let du_dz = sorted_jacobian(z, &us);
+
+    // Position of each sorted value within (phi_0, phi_1n, phi_1p, phi_2)
+    let slot = if swap { [2, 3, 0, 1] } else { [2, 0, 3, 1] };
+
+    let mut jtj = zeros(4, 4);
+    let mut jtr = vec![0f64; 4];
+    for (&x, &y) in phi.iter().zip(v) {
+        let r = f(x) - y;
+        let dv_dp = g(x);
+        let mut row = [0f64; 4];
+        for (m, jm) in row.iter_mut().enumerate() {
+            *jm = (0..4).map(|j| dv_dp[slot[j]] * du_dz[j][m]).sum();
+        }
+        for a in 0..4 {
+            jtr[a] += row[a] * r;
+            for b in 0..4 {
+                jtj[(a, b)] += row[a] * row[b];
+            }
+        }
+    }
+    (jtj, jtr)
+}
+
+/// Stick-breaking map R^4 -> {0 < u_1 < u_2 < u_3 < u_4 < 1}
+fn free_to_sorted(z: &[f64]) -> [f64; 4] {
+    let w: Vec<f64> = z.iter().map(|x| x.exp()).collect();
+    let s = 1f64 + w.iter().sum::<f64>();
+    let mut us = [0f64; 4];
+    let mut acc = 0f64;
+    for (u, wk) in us.iter_mut().zip(&w) {
+        acc += wk;
+        *u = acc / s;
+    }
+    us
+}
+
+fn sorted_to_free(us: &[f64; 4]) -> Vec<f64> {
+    let last = 1f64 - us[3];
+    let mut prev = 0f64;
+    us.iter()
+        .map(|&u| {
+            let z = ((u - prev) / last).ln();
+            prev = u;
+            z
+        })
+        .collect()
+}
+
+/// `du_j / dz_m = (w_m / s) (1[m <= j] - u_j)`
+fn sorted_jacobian(z: &[f64], us: &[f64; 4]) -> [[f64; 4]; 4] {
+    let w: Vec<f64> = z.iter().map(|x| x.exp()).collect();
+    let s = 1f64 + w.iter().sum::<f64>();
+    let mut jac = [[0f64; 4]; 4];
+    for (j, row) in jac.iter_mut().enumerate() {
+        for (m, d) in row.iter_mut().enumerate() {
+            let ind = if m <= j { 1f64 } else { 0f64 };
+            *d = w[m] / s * (ind - us[j]);
+        }
+    }
+    jac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tabulate(p: &C1Params, n: usize) -> (Vec<f64>, Vec<f64>) {
+        let f = p.potential();
+        let phi: Vec<f64> = (0..n).map(|i| i as f64 / (n - 1) as f64).collect();
+        let v = phi.iter().map(|&x| f(x)).collect();
+        (phi, v)
+    }
+
+    fn assert_close(fit: &C1Params, truth: &C1Params) {
+        for (a, b) in fit.to_array().iter().zip(truth.to_array()) {
+            assert!(
+                (a - b).abs() < 1e-6,
+                "fitted {:?}, expected {:?}",
+                fit,
+                truth
+            );
+        }
+    }
+
+    #[test]
+    fn recovers_parameters_from_fixed_starts() {
+        let truth = C1Params::new(0.3, 0.8, 0.15, 0.5);
+        let (phi, v) = tabulate(&truth, 21);
+        let opts = FitOptions {
+            swap: Some(false),
+            ..FitOptions::default()
+        };
+        let fit = fit_c1(&phi, &v, opts);
+        assert!(fit.rss < 1e-18, "rss = {:e}", fit.rss);
+        assert_close(&fit.params, &truth);
+    }
+
+    #[test]
+    fn reproduces_swapped_curve() {
+        // On the swapped branch several parameter sets share this curve, so only the curve
+        // itself is pinned down
+        let truth = C1Params::new(0.6, 0.85, 0.2, 0.35);
+        let (phi, v) = tabulate(&truth, 21);
+        let opts = FitOptions {
+            swap: Some(true),
+            ..FitOptions::default()
+        };
+        let fit = fit_c1(&phi, &v, opts);
+        assert!(fit.params.is_swapped());
+        assert!(fit.rss < 1e-18, "rss = {:e}", fit.rss);
+        assert!(fit.residuals.iter().all(|r| r.abs() < 1e-9));
+    }
+}
@@ -0,0 +1,740 @@
+use crate::output::{sample_columns, Codec, Sample, SampleWriter};
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Destination of generated samples
+pub trait SampleSink {
+    /// Append a sample with the values of its label columns
+    fn push(&mut self, sample: &Sample, labels: &[f64]) -> Result<(), Box<dyn Error>>;
+
+    /// Number of samples pushed so far
+    fn n_rows(&self) -> usize;
+
+    /// Complete the output; returns the number of samples written
+    fn finish(self: Box<Self>, metadata: Vec<(String, String)>) -> Result<usize, Box<dyn Error>>;
+}
+
+/// File format of a generated dataset
+///
+/// The array formats store the curves as an (N, channels, grid) tensor with channels V, V'
+/// and, for adaptive grids, φ. Formats without a place for metadata get a `<output>.meta.json`
+/// next to the output. Label columns follow the other columns of the tabular formats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// One row per sample, metadata in the footer
+    Parquet,
+    /// Curves tensor, plus `<stem>_params.npy` with columns phi_0, phi_1n, phi_1p, phi_2, weight
+    /// and the labels
+    Npy,
+    /// `curves.npy`, `params.npy`, `weight.npy`, `labels.npy` (if any) and `metadata.json` in
+    /// one (stored) archive
+    Npz,
+    /// Same columns as the parquet table
+    Csv,
+    /// One JSON object per sample with the parameters, weight and curves as arrays
+    Jsonl,
+}
+
+impl OutputFormat {
+    /// Format implied by the extension of `path`, if any
+    pub fn from_path(path: &str) -> Option<Self> {
+        match Path::new(path).extension()?.to_str()? {
+            "parquet" | "pq" => Some(OutputFormat::Parquet),
+            "npy" => Some(OutputFormat::Npy),
+            "npz" => Some(OutputFormat::Npz),
+            "csv" => Some(OutputFormat::Csv),
+            "jsonl" | "ndjson" => Some(OutputFormat::Jsonl),
+            _ => None,
+        }
+    }
+
+    pub fn create(
+        &self,
+        path: &str,
+        grid_len: usize,
+        per_sample_grid: bool,
+        labels: &[String],
+        codec: Codec,
+        row_group_size: usize,
+    ) -> Result<Box<dyn SampleSink>, Box<dyn Error>> {
+        let channels = if per_sample_grid { 3 } else { 2 };
+        Ok(match self {
+            OutputFormat::Parquet => Box::new(SampleWriter::create(
+                path,
+                grid_len,
+                per_sample_grid,
+                labels,
+                codec.options(),
+                row_group_size,
+            )?),
+            OutputFormat::Npy => Box::new(NpyWriter {
+                curves: NpyArray::create(path, vec![channels, grid_len])?,
+                params: NpyArray::create(&npy_params_path(path), vec![5 + labels.len()])?,
+                path: path.to_string(),
+            }),
+            OutputFormat::Npz => {
+                Box::new(NpzWriter::create(path, channels, grid_len, labels.len())?)
+            }
+            OutputFormat::Csv => {
+                Box::new(CsvWriter::create(path, grid_len, per_sample_grid, labels)?)
+            }
+            OutputFormat::Jsonl => Box::new(JsonlWriter {
+                out: BufWriter::new(File::create(path)?),
+                path: path.to_string(),
+                labels: labels.to_vec(),
+                n_rows: 0,
+            }),
+        })
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OutputFormat::Parquet => "parquet",
+            OutputFormat::Npy => "npy",
+            OutputFormat::Npz => "npz",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Jsonl => "jsonl",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parquet" => Ok(OutputFormat::Parquet),
+            "npy" => Ok(OutputFormat::Npy),
+            "npz" => Ok(OutputFormat::Npz),
+            "csv" => Ok(OutputFormat::Csv),
+            "jsonl" => Ok(OutputFormat::Jsonl),
+            _ => Err(format!(
+                "unknown format '{}' (expected parquet, npy, npz, csv or jsonl)",
+                s
+            )),
+        }
+    }
+}
+
+impl SampleSink for SampleWriter {
+    fn push(&mut self, sample: &Sample, labels: &[f64]) -> Result<(), Box<dyn Error>> {
+        SampleWriter::push(self, sample, labels)
+    }
+
+    fn n_rows(&self) -> usize {
+        SampleWriter::n_rows(self)
+    }
+
+    fn finish(self: Box<Self>, metadata: Vec<(String, String)>) -> Result<usize, Box<dyn Error>> {
+        SampleWriter::finish(*self, metadata)
+    }
+}
+
+/// Curves of a sample in channel order V, V'[, φ]
+fn channels(sample: &Sample) -> impl Iterator<Item = &f64> {
+    sample
+        .v
+        .iter()
+        .chain(&sample.dv)
+        .chain(sample.grid.iter().flatten())
+}
+
+fn sample_params(sample: &Sample) -> [f64; 5] {
+    let p = sample.params.to_array();
+    [p[0], p[1], p[2], p[3], sample.weight]
+}
+
+// =============================================================================
+// NumPy
+// =============================================================================
+
+/// Header length of the npy files, large enough for any shape we write
+const NPY_HEADER_LEN: usize = 128;
+
+/// Little-endian f64 array of shape (N, tail...) written row by row; N goes into the header
+/// once the array is complete
+struct NpyArray {
+    out: BufWriter<File>,
+    tail: Vec<usize>,
+    n_rows: usize,
+}
+
+impl NpyArray {
+    fn create(path: &str, tail: Vec<usize>) -> Result<Self, Box<dyn Error>> {
+        let mut out = BufWriter::new(File::create(path)?);
+        out.write_all(&[b' '; NPY_HEADER_LEN])?;
+        Ok(Self {
+            out,
+            tail,
+            n_rows: 0,
+        })
+    }
+
+    fn push<'a, I: IntoIterator<Item = &'a f64>>(&mut self, row: I) -> Result<(), Box<dyn Error>> {
+        for x in row {
+            self.out.write_all(&x.to_le_bytes())?;
+        }
+        self.n_rows += 1;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<usize, Box<dyn Error>> {
+        let mut file = self.out.into_inner().map_err(|e| e.into_error())?;
+        let mut shape: Vec<String> = vec![self.n_rows.to_string()];
+        shape.extend(self.tail.iter().map(|n| n.to_string()));
+        let shape = if shape.len() == 1 {
+            format!("{},", shape[0])
+        } else {
+            shape.join(", ")
+        };
+        let dict = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': ({}), }}",
+            shape
+        );
+        // magic, version 1.0, header length, then the dict padded with spaces and ended by '\n'
+        let mut header = b"\x93NUMPY\x01\x00".to_vec();
+        header.extend(((NPY_HEADER_LEN - 10) as u16).to_le_bytes());
+        header.extend(dict.as_bytes());
+        if header.len() >= NPY_HEADER_LEN {
+            return Err("npy header too long".into());
+        }
+        header.resize(NPY_HEADER_LEN - 1, b' ');
+        header.push(b'\n');
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(&header)?;
+        Ok(self.n_rows)
+    }
+}
+
+fn npy_params_path(path: &str) -> String {
+    format!("{}_params.npy", path.strip_suffix(".npy").unwrap_or(path))
+}
+
+struct NpyWriter {
+    curves: NpyArray,
+    params: NpyArray,
+    path: String,
+}
+
+impl SampleSink for NpyWriter {
+    fn push(&mut self, sample: &Sample, labels: &[f64]) -> Result<(), Box<dyn Error>> {
+        self.curves.push(channels(sample))?;
+        self.params.push(sample_params(sample).iter().chain(labels))
+    }
+
+    fn n_rows(&self) -> usize {
+        self.curves.n_rows
+    }
+
+    fn finish(self: Box<Self>, metadata: Vec<(String, String)>) -> Result<usize, Box<dyn Error>> {
+        self.params.finish()?;
+        let n = self.curves.finish()?;
+        write_metadata_json(&format!("{}.meta.json", self.path), &metadata)?;
+        Ok(n)
+    }
+}
+
+/// Arrays are streamed into a scratch directory and packed into the archive at the end
+struct NpzWriter {
+    path: String,
+    dir: PathBuf,
+    curves: NpyArray,
+    params: NpyArray,
+    weight: NpyArray,
+    labels: Option<NpyArray>,
+}
+
+impl NpzWriter {
+    fn create(
+        path: &str,
+        channels: usize,
+        grid_len: usize,
+        n_labels: usize,
+    ) -> Result<Self, Box<dyn Error>> {
+        let dir = PathBuf::from(format!("{}.tmp", path));
+        fs::create_dir_all(&dir)?;
+        let array = |name: &str, tail: Vec<usize>| {
+            NpyArray::create(dir.join(name).to_str().ok_or("invalid path")?, tail)
+        };
+        Ok(Self {
+            path: path.to_string(),
+            curves: array("curves.npy", vec![channels, grid_len])?,
+            params: array("params.npy", vec![4])?,
+            weight: array("weight.npy", vec![])?,
+            labels: if n_labels > 0 {
+                Some(array("labels.npy", vec![n_labels])?)
+            } else {
+                None
+            },
+            dir,
+        })
+    }
+}
+
+impl SampleSink for NpzWriter {
+    fn push(&mut self, sample: &Sample, labels: &[f64]) -> Result<(), Box<dyn Error>> {
+        self.curves.push(channels(sample))?;
+        self.params.push(&sample.params.to_array())?;
+        if let Some(array) = self.labels.as_mut() {
+            array.push(labels)?;
+        }
+        self.weight.push([&sample.weight])
+    }
+
+    fn n_rows(&self) -> usize {
+        self.curves.n_rows
+    }
+
+    fn finish(self: Box<Self>, metadata: Vec<(String, String)>) -> Result<usize, Box<dyn Error>> {
+        let n = self.curves.finish()?;
+        self.params.finish()?;
+        self.weight.finish()?;
+        let mut names = vec!["curves.npy", "params.npy", "weight.npy"];
+        if let Some(array) = self.labels {
+            array.finish()?;
+            names.push("labels.npy");
+        }
+        names.push("metadata.json");
+        write_metadata_json(
+            self.dir
+                .join("metadata.json")
+                .to_str()
+                .ok_or("invalid path")?,
+            &metadata,
+        )?;
+        let entries: Vec<(&str, PathBuf)> = names
+            .iter()
+            .map(|&name| (name, self.dir.join(name)))
+            .collect();
+        write_stored_zip(&self.path, &entries)?;
+        fs::remove_dir_all(&self.dir)?;
+        Ok(n)
+    }
+}
+
+/// Zip archive with stored (uncompressed) members, always using zip64 records so that
+/// members may exceed 4 GiB
+fn write_stored_zip(path: &str, entries: &[(&str, PathBuf)]) -> Result<(), Box<dyn Error>> {
+    const DOS_DATE: u16 = (1 << 5) | 1; // 1980-01-01
+    let mut out = BufWriter::new(File::create(path)?);
+    let mut offset = 0u64;
+    let mut central = vec![];
+
+    for (name, source) in entries {
+        let (crc, size) = crc32_file(source)?;
+        let mut local = vec![];
+        local.extend(0x0403_4b50u32.to_le_bytes());
+        local.extend(45u16.to_le_bytes()); // version needed: zip64
+        local.extend(0u16.to_le_bytes()); // flags
+        local.extend(0u16.to_le_bytes()); // stored
+        local.extend(0u16.to_le_bytes()); // time
+        local.extend(DOS_DATE.to_le_bytes());
+        local.extend(crc.to_le_bytes());
+        local.extend(u32::MAX.to_le_bytes());
+        local.extend(u32::MAX.to_le_bytes());
+        local.extend((name.len() as u16).to_le_bytes());
+        local.extend(20u16.to_le_bytes());
+        local.extend(name.as_bytes());
+        local.extend(1u16.to_le_bytes()); // zip64 extra field
+        local.extend(16u16.to_le_bytes());
+        local.extend(size.to_le_bytes());
+        local.extend(size.to_le_bytes());
+        out.write_all(&local)?;
+        std::io::copy(&mut File::open(source)?, &mut out)?;
+
+        central.extend(0x0201_4b50u32.to_le_bytes());
+        central.extend(45u16.to_le_bytes()); // version made by
+        central.extend(45u16.to_le_bytes());
+        central.extend(0u16.to_le_bytes());
+        central.extend(0u16.to_le_bytes());
+        central.extend(0u16.to_le_bytes());
+        central.extend(DOS_DATE.to_le_bytes());
+        central.extend(crc.to_le_bytes());
+        central.extend(u32::MAX.to_le_bytes());
+        central.extend(u32::MAX.to_le_bytes());
+        central.extend((name.len() as u16).to_le_bytes());
+        central.extend(28u16.to_le_bytes());
+        central.extend(0u16.to_le_bytes()); // comment
+        central.extend(0u16.to_le_bytes()); // disk
+        central.extend(0u16.to_le_bytes()); // internal attributes
+        central.extend(0u32.to_le_bytes()); // external attributes
+        central.extend(u32::MAX.to_le_bytes());
+        central.extend(name.as_bytes());
+        central.extend(1u16.to_le_bytes());
+        central.extend(24u16.to_le_bytes());
+        central.extend(size.to_le_bytes());
+        central.extend(size.to_le_bytes());
+        central.extend(offset.to_le_bytes());
+
+        offset += local.len() as u64 + size;
+    }
+
+    let n = entries.len() as u64;
+    let central_offset = offset;
+    out.write_all(&central)?;
+    let zip64_end_offset = central_offset + central.len() as u64;
+
+    let mut end = vec![];
+    end.extend(0x0606_4b50u32.to_le_bytes()); // zip64 end of central directory
+    end.extend(44u64.to_le_bytes());
+    end.extend(45u16.to_le_bytes());
+    end.extend(45u16.to_le_bytes());
+    end.extend(0u32.to_le_bytes());
+    end.extend(0u32.to_le_bytes());
+    end.extend(n.to_le_bytes());
+    end.extend(n.to_le_bytes());
+    end.extend((central.len() as u64).to_le_bytes());
+    end.extend(central_offset.to_le_bytes());
+    end.extend(0x0706_4b50u32.to_le_bytes()); // zip64 locator
+    end.extend(0u32.to_le_bytes());
+    end.extend(zip64_end_offset.to_le_bytes());
+    end.extend(1u32.to_le_bytes());
+    end.extend(0x0605_4b50u32.to_le_bytes()); // end of central directory
+    end.extend([0u8; 4]);
+    end.extend(u16::MAX.to_le_bytes());
+    end.extend(u16::MAX.to_le_bytes());
+    end.extend(u32::MAX.to_le_bytes());
+    end.extend(u32::MAX.to_le_bytes());
+    end.extend(0u16.to_le_bytes());
+    out.write_all(&end)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// CRC-32 (IEEE) and length of a file
+fn crc32_file(path: &Path) -> Result<(u32, u64), Box<dyn Error>> {
+    let table: Vec<u32> = (0..256u32)
+        .map(|i| {
+            (0..8).fold(i, |c, _| {
+                if c & 1 == 1 {
+                    0xedb8_8320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                }
+            })
+        })
+        .collect();
+    let mut file = File::open(path)?;
+    let mut buf = vec![0u8; 1 << 20];
+    let mut crc = u32::MAX;
+    let mut len = 0u64;
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        for &b in &buf[..n] {
+            crc = table[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8);
+        }
+        len += n as u64;
+    }
+    Ok((!crc, len))
+}
+
+// =============================================================================
+// Text formats
+// =============================================================================
+
+struct CsvWriter {
+    out: BufWriter<File>,
+    path: String,
+    n_rows: usize,
+}
+
+impl CsvWriter {
+    fn create(
+        path: &str,
+        grid_len: usize,
+        per_sample_grid: bool,
+        labels: &[String],
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut out = BufWriter::new(File::create(path)?);
+        let mut names = sample_columns(grid_len, per_sample_grid);
+        names.extend(labels.iter().cloned());
+        writeln!(out, "{}", names.join(","))?;
+        Ok(Self {
+            out,
+            path: path.to_string(),
+            n_rows: 0,
+        })
+    }
+}
+
+impl SampleSink for CsvWriter {
+    fn push(&mut self, sample: &Sample, labels: &[f64]) -> Result<(), Box<dyn Error>> {
+        // Same column order as the parquet table
+        let row: Vec<String> = sample_params(sample)
+            .iter()
+            .chain(sample.grid.iter().flatten())
+            .chain(&sample.v)
+            .chain(&sample.dv)
+            .chain(labels)
+            .map(|x| x.to_string())
+            .collect();
+        writeln!(self.out, "{}", row.join(","))?;
+        self.n_rows += 1;
+        Ok(())
+    }
+
+    fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    fn finish(
+        mut self: Box<Self>,
+        metadata: Vec<(String, String)>,
+    ) -> Result<usize, Box<dyn Error>> {
+        self.out.flush()?;
+        write_metadata_json(&format!("{}.meta.json", self.path), &metadata)?;
+        Ok(self.n_rows)
+    }
+}
+
+struct JsonlWriter {
+    out: BufWriter<File>,
+    path: String,
+    labels: Vec<String>,
+    n_rows: usize,
+}
+
+/// JSON number, with null for NaN and infinities
+pub fn json_number(x: f64) -> String {
+    if x.is_finite() {
+        format!("{:?}", x)
+    } else {
+        "null".to_string()
+    }
+}
+
+fn json_array(xs: &[f64]) -> String {
+    let xs: Vec<String> = xs.iter().map(|&x| json_number(x)).collect();
+    format!("[{}]", xs.join(","))
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl SampleSink for JsonlWriter {
+    fn push(&mut self, sample: &Sample, labels: &[f64]) -> Result<(), Box<dyn Error>> {
+        let p = sample.params;
+        let mut fields = vec![
+            format!("\"phi_0\":{}", json_number(p.phi_0)),
+            format!("\"phi_1n\":{}", json_number(p.phi_1n)),
+            format!("\"phi_1p\":{}", json_number(p.phi_1p)),
+            format!("\"phi_2\":{}", json_number(p.phi_2)),
+            format!("\"weight\":{}", json_number(sample.weight)),
+        ];
+        if let Some(grid) = &sample.grid {
+            fields.push(format!("\"grid\":{}", json_array(grid)));
+        }
+        fields.push(format!("\"v\":{}", json_array(&sample.v)));
+        fields.push(format!("\"dv\":{}", json_array(&sample.dv)));
+        for (name, &x) in self.labels.iter().zip(labels) {
+            fields.push(format!("{}:{}", json_string(name), json_number(x)));
+        }
+        writeln!(self.out, "{{{}}}", fields.join(","))?;
+        self.n_rows += 1;
+        Ok(())
+    }
+
+    fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    fn finish(
+        mut self: Box<Self>,
+        metadata: Vec<(String, String)>,
+    ) -> Result<usize, Box<dyn Error>> {
+        self.out.flush()?;
+        write_metadata_json(&format!("{}.meta.json", self.path), &metadata)?;
+        Ok(self.n_rows)
+    }
+}
+
+/// Key-value metadata as a flat JSON object of strings
+fn write_metadata_json(path: &str, metadata: &[(String, String)]) -> Result<(), Box<dyn Error>> {
+    let fields: Vec<String> = metadata
+        .iter()
+        .map(|(k, v)| format!("  {}: {}", json_string(k), json_string(v)))
+        .collect();
+    fs::write(path, format!("{{\n{}\n}}\n", fields.join(",\n")))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c1::C1Params;
+    use crate::testutil::TempDir;
+
+    fn samples() -> Vec<(Sample, Vec<f64>)> {
+        (0..3)
+            .map(|i| {
+                let x = i as f64;
+                let sample = Sample {
+                    params: C1Params::new(0.4 + 0.01 * x, 0.9, 0.1, 0.6),
+                    grid: None,
+                    v: vec![-x, 0.5 * x, 1f64 / 3f64],
+                    dv: vec![x, -1.25, f64::MIN_POSITIVE],
+                    weight: 1f64 + x,
+                };
+                (sample, vec![10f64 * x, -x])
+            })
+            .collect()
+    }
+
+    fn write(format: OutputFormat, path: &Path) {
+        let labels = ["a".to_string(), "b".to_string()];
+        let path = path.to_str().unwrap();
+        let mut sink = format
+            .create(path, 3, false, &labels, Codec::Uncompressed, 100)
+            .unwrap();
+        for (sample, labels) in samples() {
+            sink.push(&sample, &labels).unwrap();
+        }
+        let metadata = vec![("bounce.n_samples".to_string(), "3".to_string())];
+        assert_eq!(sink.finish(metadata).unwrap(), 3);
+    }
+
+    /// Shape and values of an npy file as written by `NpyArray`
+    fn parse_npy(bytes: &[u8]) -> (Vec<usize>, Vec<f64>) {
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        let dict = std::str::from_utf8(&bytes[10..10 + header_len]).unwrap();
+        assert!(dict.starts_with("{'descr': '<f8', 'fortran_order': False, 'shape': ("));
+        assert!(dict.ends_with('\n'));
+        let shape = dict
+            .split_once("'shape': (")
+            .unwrap()
+            .1
+            .split_once(')')
+            .unwrap()
+            .0;
+        let shape = shape
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let data = bytes[10 + header_len..]
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+            .collect();
+        (shape, data)
+    }
+
+    /// Members of a stored zip archive as written by `write_stored_zip`
+    fn parse_zip(bytes: &[u8]) -> Vec<(String, u32, Vec<u8>)> {
+        let u16_at = |i: usize| u16::from_le_bytes([bytes[i], bytes[i + 1]]) as usize;
+        let mut members = vec![];
+        let mut pos = 0;
+        while bytes[pos..pos + 4] == 0x0403_4b50u32.to_le_bytes() {
+            let crc = u32::from_le_bytes(bytes[pos + 14..pos + 18].try_into().unwrap());
+            let (name_len, extra_len) = (u16_at(pos + 26), u16_at(pos + 28));
+            let name = std::str::from_utf8(&bytes[pos + 30..pos + 30 + name_len]).unwrap();
+            let extra = pos + 30 + name_len;
+            let size =
+                u64::from_le_bytes(bytes[extra + 4..extra + 12].try_into().unwrap()) as usize;
+            let start = extra + extra_len;
+            members.push((name.to_string(), crc, bytes[start..start + size].to_vec()));
+            pos = start + size;
+        }
+        members
+    }
+
+    #[test]
+    fn npy_round_trip() {
+        let dir = TempDir::new("formats-npy");
+        let path = dir.path().join("out.npy");
+        write(OutputFormat::Npy, &path);
+
+        let (shape, curves) = parse_npy(&fs::read(&path).unwrap());
+        assert_eq!(shape, vec![3, 2, 3]);
+        let (shape, params) =
+            parse_npy(&fs::read(npy_params_path(path.to_str().unwrap())).unwrap());
+        assert_eq!(shape, vec![3, 7]);
+        for (i, (sample, labels)) in samples().iter().enumerate() {
+            assert_eq!(
+                curves[i * 6..(i + 1) * 6],
+                [sample.v.clone(), sample.dv.clone()].concat()
+            );
+            let row: Vec<f64> = sample_params(sample)
+                .iter()
+                .chain(labels)
+                .copied()
+                .collect();
+            assert_eq!(params[i * 7..(i + 1) * 7], row);
+        }
+        let meta = fs::read_to_string(format!("{}.meta.json", path.display())).unwrap();
+        assert!(meta.contains("\"bounce.n_samples\": \"3\""));
+    }
+
+    #[test]
+    fn npz_round_trip() {
+        let dir = TempDir::new("formats-npz");
+        let path = dir.path().join("out.npz");
+        write(OutputFormat::Npz, &path);
+        assert!(!dir.path().join("out.npz.tmp").exists());
+
+        let members = parse_zip(&fs::read(&path).unwrap());
+        let names: Vec<&str> = members.iter().map(|m| m.0.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "curves.npy",
+                "params.npy",
+                "weight.npy",
+                "labels.npy",
+                "metadata.json"
+            ]
+        );
+        let scratch = dir.path().join("member");
+        for (name, crc, data) in &members {
+            fs::write(&scratch, data).unwrap();
+            assert_eq!(
+                crc32_file(&scratch).unwrap(),
+                (*crc, data.len() as u64),
+                "{}",
+                name
+            );
+        }
+
+        let array = |i: usize| parse_npy(&members[i].2);
+        let samples = samples();
+        assert_eq!(array(0).0, vec![3, 2, 3]);
+        assert_eq!(array(1).0, vec![3, 4]);
+        assert_eq!(array(2).0, vec![3]);
+        assert_eq!(array(3).0, vec![3, 2]);
+        for (i, (sample, labels)) in samples.iter().enumerate() {
+            assert_eq!(
+                array(0).1[i * 6..(i + 1) * 6],
+                [sample.v.clone(), sample.dv.clone()].concat()
+            );
+            assert_eq!(array(1).1[i * 4..(i + 1) * 4], sample.params.to_array());
+            assert_eq!(array(2).1[i], sample.weight);
+            assert_eq!(&array(3).1[i * 2..(i + 1) * 2], labels.as_slice());
+        }
+        assert!(std::str::from_utf8(&members[4].2)
+            .unwrap()
+            .contains("\"bounce.n_samples\": \"3\""));
+    }
+}
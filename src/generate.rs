@@ -0,0 +1,694 @@
+use crate::batch::BatchEvaluator;
+use crate::c1::C1Params;
+use crate::checkpoint::{append_selected, load_selected, part_path, partial_dir, Checkpoint};
+use crate::config::RunConfig;
+use crate::dataset::Dataset;
+use crate::features::{Acceptance, Features};
+use crate::formats::{OutputFormat, SampleSink};
+use crate::grid::GridSpec;
+use crate::mcmc::{run_mcmc, McmcDiagnostics};
+use crate::output::{Codec, Sample};
+use crate::precision::curve_precision;
+use crate::sampler::params_from_unit;
+use crate::sensitivity::GradientSpec;
+use crate::split::{split_path, write_manifest, SplitSpec, Splitter, SPLIT_NAMES};
+use crate::target::QuotaFilter;
+use rayon::prelude::*;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+const CHUNK_SIZE: usize = 1024;
+
+/// Chunks drawn per round of the sampling loop
+///
+/// Fixed rather than tied to the number of threads: a targeted run stops offering candidates
+/// at a round boundary, so the round size decides which candidates its weights are based on.
+const CHUNKS_PER_ROUND: u64 = 16;
+
+/// Rounds without a new kept sample after which a run without a candidate budget gives up
+///
+/// Bins that the accepted region barely reaches would otherwise keep an unbounded run busy forever.
+const MAX_STALLED_ROUNDS: u64 = 64;
+
+/// Counters of a finished run
+#[derive(Debug, Clone)]
+pub struct RunSummary {
+    pub n_samples: usize,
+    pub n_candidates: u64,
+    pub n_accepted: u64,
+    /// Kept samples per target bin against their quotas, when a target was set
+    pub target_fill: Option<(Vec<usize>, Vec<usize>)>,
+    pub mcmc: Option<McmcDiagnostics>,
+    /// Samples per split (train, val, test), when the output was split
+    pub split_counts: Option<[usize; 3]>,
+}
+
+/// Draw, screen and write a dataset as described by `config`
+///
+/// Without a target, accepted samples are written as they are found. Target weights are only
+/// known once every quota is filled, so a targeted run first records the indices of its kept
+/// candidates and then regenerates and writes them; MCMC runs likewise write their chains
+/// once they are complete. Either way curves are evaluated and written one block at a time.
+///
+/// With checkpoints enabled, samples go to part files that are merged into the output at the
+/// end, so a resumed run produces the same file as an uninterrupted one.
+///
+/// Samples are assigned to splits as they reach the output, and a split run records its
+/// provenance in `<output>.manifest.json`.
+pub fn generate(config: &RunConfig) -> Result<RunSummary, Box<dyn Error>> {
+    let n_samples = config.n_samples;
+    let sampler = config.sampler.build(config.seed, config.stratify_branch);
+    let acceptance = Acceptance::default();
+    let max_candidates = config.max_candidates.unwrap_or(u64::MAX);
+    let mut n_stalled = 0u64;
+    let mut filter = config
+        .target
+        .as_ref()
+        .map(|spec| QuotaFilter::new(spec.clone(), n_samples));
+
+    // Adaptive grids are screened on the uniform grid of the same size
+    let screen_grid = config.grid.nodes();
+    let evaluator = BatchEvaluator::new(&screen_grid);
+    let labels = Labels {
+        gradients: config.gradients.as_ref(),
+        precision_tol: config.precision_tol,
+    };
+    let layout = Layout {
+        labels: labels.columns(config.grid.len()),
+        grid_len: config.grid.len(),
+        per_sample_grid: !config.grid.is_shared(),
+        row_group_size: config.row_group_size,
+        format: config.format,
+        codec: config.compression,
+    };
+    let streaming = filter.is_none() && config.mcmc.is_none();
+    // Candidate index and target bin of the samples kept for later
+    let mut selected: Vec<(u64, usize)> = vec![];
+    let mut n_accepted = 0u64;
+    let mut next_index = 0u64;
+
+    let mut checkpointer = None;
+    let mut sink = match config.checkpoint_interval {
+        None => Sink::Direct(Output::create(
+            &layout,
+            &config.output,
+            config.split.as_ref(),
+        )?),
+        Some(interval) => {
+            let dir = partial_dir(&config.output);
+            let mut parts = Parts {
+                dir: dir.clone(),
+                layout,
+                split: config.split.clone(),
+                current: None,
+                n_parts: 0,
+                n_rows: 0,
+            };
+            let mut n_saved = 0;
+            if config.resume {
+                let checkpoint = Checkpoint::load(&dir)?;
+                if checkpoint.config != config.to_metadata() {
+                    return Err("the checkpoint was written by a run with different options".into());
+                }
+                next_index = checkpoint.next_index;
+                n_accepted = checkpoint.n_accepted;
+                if let Some(filter) = filter.as_mut() {
+                    filter.restore(checkpoint.seen, checkpoint.kept)?;
+                }
+                selected = load_selected(&dir, checkpoint.n_selected)?;
+                n_saved = selected.len();
+                parts.n_parts = checkpoint.n_parts;
+                parts.n_rows = checkpoint.n_rows;
+            } else {
+                if dir.exists() {
+                    fs::remove_dir_all(&dir)?;
+                }
+                fs::create_dir_all(&dir)?;
+            }
+            checkpointer = Some(Checkpointer {
+                dir,
+                interval: Duration::from_secs(interval),
+                last: Instant::now(),
+                config: config.to_metadata(),
+                n_saved,
+            });
+            Sink::Parts(parts)
+        }
+    };
+    let mut checkpoint =
+        |sink: &mut Sink,
+         next_index: u64,
+         n_accepted: u64,
+         selected: &[(u64, usize)],
+         filter: Option<&QuotaFilter>| match checkpointer.as_mut() {
+            Some(c) => c.maybe_save(sink, next_index, n_accepted, selected, filter),
+            None => Ok(()),
+        };
+
+    // MCMC only needs one accepted starting point per chain
+    let n_wanted = config.mcmc.map_or(n_samples, |opts| opts.n_chains);
+    let n_kept = |sink: &Sink, selected: &[(u64, usize)]| {
+        if streaming {
+            sink.n_rows()
+        } else {
+            selected.len()
+        }
+    };
+
+    // Each rayon task draws a chunk of candidates, evaluates it in one batch and keeps the accepted ones;
+    // chunks are collected in index order and rounds have a fixed size, so the result does not
+    // depend on the number of threads
+    while n_kept(&sink, &selected) < n_wanted && next_index < max_candidates {
+        let accepted: Vec<(u64, C1Params, Vec<f64>, Vec<f64>)> = (0..CHUNKS_PER_ROUND)
+            .into_par_iter()
+            .flat_map_iter(|c| {
+                let start = next_index + c * CHUNK_SIZE as u64;
+                let params: Vec<C1Params> = sampler
+                    .draw(start, CHUNK_SIZE)
+                    .into_iter()
+                    .map(params_from_unit)
+                    .collect();
+                let out = evaluator.eval(&params);
+                (0..CHUNK_SIZE).filter_map(move |i| {
+                    let v = out.v.row(i);
+                    acceptance
+                        .accepts(&v)
+                        .then(|| (start + i as u64, params[i], v, out.dv.row(i)))
+                })
+            })
+            .collect();
+        next_index += CHUNKS_PER_ROUND * CHUNK_SIZE as u64;
+        n_accepted += accepted.len() as u64;
+        let n_before = n_kept(&sink, &selected);
+
+        match filter.as_mut() {
+            // Keep offering after the quotas are full so the natural bin frequencies stay unbiased
+            Some(filter) => {
+                for (index, _, v, _) in accepted {
+                    let x = filter.feature().value(&Features::new(&screen_grid, &v));
+                    if let Some(b) = filter.offer(x) {
+                        selected.push((index, b));
+                    }
+                }
+            }
+            None if streaming => {
+                let block: Vec<(C1Params, Vec<f64>, Vec<f64>, f64)> = accepted
+                    .into_iter()
+                    .take(n_samples - sink.n_rows())
+                    .map(|(_, p, v, dv)| (p, v, dv, 1f64))
+                    .collect();
+                write_block(&mut sink, &config.grid, &labels, block)?;
+            }
+            None => selected.extend(
+                accepted
+                    .iter()
+                    .take(n_wanted - selected.len())
+                    .map(|a| (a.0, 0)),
+            ),
+        }
+        checkpoint(
+            &mut sink,
+            next_index,
+            n_accepted,
+            &selected,
+            filter.as_ref(),
+        )?;
+
+        n_stalled = if n_kept(&sink, &selected) > n_before {
+            0
+        } else {
+            n_stalled + 1
+        };
+        if config.max_candidates.is_none() && n_stalled == MAX_STALLED_ROUNDS {
+            return Err(stalled_message(filter.as_ref(), next_index).into());
+        }
+    }
+
+    let mut mcmc = None;
+    if let Some(opts) = config.mcmc {
+        if selected.len() < opts.n_chains {
+            return Err(format!(
+                "found only {} accepted starting points for {} chains",
+                selected.len(),
+                opts.n_chains
+            )
+            .into());
+        }
+        let indices: Vec<u64> = selected.iter().map(|s| s.0).collect();
+        let init: Vec<C1Params> = sampler
+            .draw_at(&indices)
+            .into_iter()
+            .map(params_from_unit)
+            .collect();
+        let accept = |p: &C1Params| acceptance.accepts(&evaluator.eval(&[*p]).v.row(0));
+        let (params, diagnostics) = run_mcmc(accept, &init, n_samples, opts, config.seed);
+        write_params(
+            &mut sink,
+            &config.grid,
+            &labels,
+            &evaluator,
+            &params,
+            |_| 1f64,
+            |_| Ok(()),
+        )?;
+        mcmc = Some(diagnostics);
+    } else if let Some(filter) = filter.as_ref() {
+        let bin_weights = filter.weights();
+        // After a resume the first rows are already in the part files
+        let offset = sink.n_rows();
+        let indices: Vec<u64> = selected[offset..].iter().map(|s| s.0).collect();
+        let params: Vec<C1Params> = sampler
+            .draw_at(&indices)
+            .into_iter()
+            .map(params_from_unit)
+            .collect();
+        write_params(
+            &mut sink,
+            &config.grid,
+            &labels,
+            &evaluator,
+            &params,
+            |i| bin_weights[selected[offset + i].1],
+            |sink| checkpoint(sink, next_index, n_accepted, &selected, Some(filter)),
+        )?;
+    }
+
+    let (n_written, split_counts) = sink.finish(&config.output, config.to_metadata())?;
+    if let (Some(spec), Some(counts)) = (&config.split, split_counts) {
+        let path = format!("{}.manifest.json", config.output);
+        write_manifest(&path, &config.output, spec, counts, &config.to_metadata())?;
+    }
+
+    Ok(RunSummary {
+        n_samples: n_written,
+        n_candidates: next_index,
+        n_accepted,
+        target_fill: filter.map(|f| (f.kept().to_vec(), f.quotas().to_vec())),
+        mcmc,
+        split_counts,
+    })
+}
+
+/// Why an unbounded run stopped without progress, naming the target bins it could not fill
+fn stalled_message(filter: Option<&QuotaFilter>, n_candidates: u64) -> String {
+    let unfilled = filter.map(|f| {
+        f.kept()
+            .iter()
+            .zip(f.quotas())
+            .enumerate()
+            .filter(|(_, (k, q))| k < q)
+            .map(|(b, (k, q))| format!("bin {} ({}/{})", b, k, q))
+            .collect::<Vec<String>>()
+            .join(", ")
+    });
+    let what = match unfilled {
+        Some(bins) => format!("target quotas still open: {}", bins),
+        None => "no more accepted candidates".to_string(),
+    };
+    format!(
+        "no new samples in the last {} candidates ({} drawn; {}); pass --max-candidates to stop early with a partial dataset",
+        MAX_STALLED_ROUNDS * CHUNKS_PER_ROUND * CHUNK_SIZE as u64,
+        n_candidates,
+        what
+    )
+}
+
+/// Label columns computed for every written sample
+struct Labels<'a> {
+    gradients: Option<&'a GradientSpec>,
+    /// Relative round-off above which the `reliable` column is 0
+    precision_tol: Option<f64>,
+}
+
+impl Labels<'_> {
+    fn columns(&self, grid_len: usize) -> Vec<String> {
+        let mut names = self.gradients.map_or(vec![], |g| g.columns(grid_len));
+        if self.precision_tol.is_some() {
+            names.push("reliable".to_string());
+        }
+        names
+    }
+
+    fn values(&self, sample: &Sample, phi: &[f64]) -> Vec<f64> {
+        let mut values = self.gradients.map_or(vec![], |g| g.values(sample, phi));
+        if let Some(tol) = self.precision_tol {
+            let report = curve_precision(&sample.params, phi, &sample.v, &sample.dv);
+            values.push(if report.is_reliable(tol) { 1f64 } else { 0f64 });
+        }
+        values
+    }
+}
+
+/// Shape and format of the sample files of a run
+#[derive(Debug, Clone)]
+struct Layout {
+    /// Label columns computed for every sample (gradients, reliability), before any `split` column
+    labels: Vec<String>,
+    grid_len: usize,
+    per_sample_grid: bool,
+    row_group_size: usize,
+    format: OutputFormat,
+    codec: Codec,
+}
+
+impl Layout {
+    fn create(&self, path: &str, labels: &[String]) -> Result<Box<dyn SampleSink>, Box<dyn Error>> {
+        self.format.create(
+            path,
+            self.grid_len,
+            self.per_sample_grid,
+            labels,
+            self.codec,
+            self.row_group_size,
+        )
+    }
+
+    /// Part files are always uncompressed parquet
+    fn create_part(&self, path: &str) -> Result<Box<dyn SampleSink>, Box<dyn Error>> {
+        OutputFormat::Parquet.create(
+            path,
+            self.grid_len,
+            self.per_sample_grid,
+            &self.labels,
+            Codec::Uncompressed,
+            self.row_group_size,
+        )
+    }
+}
+
+/// Number of samples written and their counts per split
+type Written = (usize, Option<[usize; 3]>);
+
+/// Output file of a run, or one file per split
+struct Output {
+    sinks: Vec<Box<dyn SampleSink>>,
+    splitter: Option<Splitter>,
+}
+
+impl Output {
+    fn create(
+        layout: &Layout,
+        path: &str,
+        split: Option<&SplitSpec>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let sinks = match split {
+            Some(spec) if spec.files => (0..SPLIT_NAMES.len())
+                .map(|i| layout.create(&split_path(path, i), &layout.labels))
+                .collect::<Result<_, _>>()?,
+            Some(_) => {
+                let mut labels = layout.labels.clone();
+                labels.push("split".to_string());
+                vec![layout.create(path, &labels)?]
+            }
+            None => vec![layout.create(path, &layout.labels)?],
+        };
+        Ok(Self {
+            sinks,
+            splitter: split.map(|spec| Splitter::new(spec.clone())),
+        })
+    }
+
+    fn n_rows(&self) -> usize {
+        self.sinks.iter().map(|s| s.n_rows()).sum()
+    }
+
+    fn push(&mut self, sample: &Sample, labels: &[f64]) -> Result<(), Box<dyn Error>> {
+        match self.splitter.as_mut() {
+            None => self.sinks[0].push(sample, labels),
+            Some(splitter) => {
+                let split = splitter.assign(sample);
+                if self.sinks.len() == 1 {
+                    let mut labels = labels.to_vec();
+                    labels.push(split as f64);
+                    self.sinks[0].push(sample, &labels)
+                } else {
+                    self.sinks[split].push(sample, labels)
+                }
+            }
+        }
+    }
+
+    fn finish(self, metadata: Vec<(String, String)>) -> Result<Written, Box<dyn Error>> {
+        let split_files = self.sinks.len() > 1;
+        let mut n = 0;
+        for (i, sink) in self.sinks.into_iter().enumerate() {
+            let mut metadata = metadata.clone();
+            if split_files {
+                metadata.push(("bounce.split.part".to_string(), SPLIT_NAMES[i].to_string()));
+            }
+            n += sink.finish(metadata)?;
+        }
+        Ok((n, self.splitter.map(|s| s.counts())))
+    }
+}
+
+/// Where samples are written: the output itself, or part files when checkpointing
+enum Sink {
+    Direct(Output),
+    Parts(Parts),
+}
+
+struct Parts {
+    dir: PathBuf,
+    layout: Layout,
+    /// Split of the merged output
+    split: Option<SplitSpec>,
+    current: Option<Box<dyn SampleSink>>,
+    /// Completed part files
+    n_parts: usize,
+    /// Samples in the completed parts and the current one
+    n_rows: usize,
+}
+
+impl Sink {
+    fn n_rows(&self) -> usize {
+        match self {
+            Sink::Direct(out) => out.n_rows(),
+            Sink::Parts(parts) => parts.n_rows,
+        }
+    }
+
+    fn push(&mut self, sample: &Sample, labels: &[f64]) -> Result<(), Box<dyn Error>> {
+        match self {
+            Sink::Direct(out) => out.push(sample, labels),
+            Sink::Parts(parts) => {
+                if parts.current.is_none() {
+                    let path = part_path(&parts.dir, parts.n_parts);
+                    parts.current = Some(
+                        parts
+                            .layout
+                            .create_part(path.to_str().ok_or("invalid path")?)?,
+                    );
+                }
+                parts.current.as_mut().unwrap().push(sample, labels)?;
+                parts.n_rows += 1;
+                Ok(())
+            }
+        }
+    }
+
+    /// Complete the current part file, if any
+    fn close_part(&mut self) -> Result<(), Box<dyn Error>> {
+        if let Sink::Parts(parts) = self {
+            if let Some(writer) = parts.current.take() {
+                writer.finish(vec![])?;
+                parts.n_parts += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(
+        mut self,
+        output: &str,
+        metadata: Vec<(String, String)>,
+    ) -> Result<Written, Box<dyn Error>> {
+        self.close_part()?;
+        match self {
+            Sink::Direct(out) => out.finish(metadata),
+            Sink::Parts(parts) => {
+                let mut out = Output::create(&parts.layout, output, parts.split.as_ref())?;
+                for i in 0..parts.n_parts {
+                    for record in Dataset::open(part_path(&parts.dir, i))?.records() {
+                        let record = record?;
+                        out.push(&record.sample, &record.labels)?;
+                    }
+                }
+                let finished = out.finish(metadata)?;
+                fs::remove_dir_all(&parts.dir)?;
+                Ok(finished)
+            }
+        }
+    }
+}
+
+/// Saves a checkpoint whenever `interval` has passed since the last one
+struct Checkpointer {
+    dir: PathBuf,
+    interval: Duration,
+    last: Instant,
+    config: Vec<(String, String)>,
+    /// Selected candidates already appended to the `selected` file
+    n_saved: usize,
+}
+
+impl Checkpointer {
+    fn maybe_save(
+        &mut self,
+        sink: &mut Sink,
+        next_index: u64,
+        n_accepted: u64,
+        selected: &[(u64, usize)],
+        filter: Option<&QuotaFilter>,
+    ) -> Result<(), Box<dyn Error>> {
+        if self.last.elapsed() < self.interval {
+            return Ok(());
+        }
+        sink.close_part()?;
+        append_selected(&self.dir, &selected[self.n_saved..])?;
+        self.n_saved = selected.len();
+        let n_parts = match sink {
+            Sink::Parts(parts) => parts.n_parts,
+            Sink::Direct(_) => 0,
+        };
+        Checkpoint {
+            config: self.config.clone(),
+            next_index,
+            n_accepted,
+            n_rows: sink.n_rows(),
+            n_parts,
+            n_selected: selected.len(),
+            seen: filter.map_or(vec![], |f| f.seen().to_vec()),
+            kept: filter.map_or(vec![], |f| f.kept().to_vec()),
+        }
+        .save(&self.dir)?;
+        self.last = Instant::now();
+        Ok(())
+    }
+}
+
+/// Evaluate `params` block by block on the shared grid and write them with weights `weight(i)`,
+/// calling `after_block` after each block
+fn write_params<W, C>(
+    sink: &mut Sink,
+    grid: &GridSpec,
+    labels: &Labels,
+    evaluator: &BatchEvaluator,
+    params: &[C1Params],
+    weight: W,
+    mut after_block: C,
+) -> Result<(), Box<dyn Error>>
+where
+    W: Fn(usize) -> f64 + Sync,
+    C: FnMut(&mut Sink) -> Result<(), Box<dyn Error>>,
+{
+    let block_size = rayon::current_num_threads() * CHUNK_SIZE;
+    for (b, block) in params.chunks(block_size).enumerate() {
+        let offset = b * block_size;
+        let block: Vec<(C1Params, Vec<f64>, Vec<f64>, f64)> = block
+            .par_chunks(CHUNK_SIZE)
+            .enumerate()
+            .flat_map_iter(|(c, chunk)| {
+                let out = evaluator.eval(chunk);
+                let weight = &weight;
+                chunk.iter().enumerate().map(move |(i, &p)| {
+                    let w = weight(offset + c * CHUNK_SIZE + i);
+                    (p, out.v.row(i), out.dv.row(i), w)
+                })
+            })
+            .collect();
+        write_block(sink, grid, labels, block)?;
+        after_block(sink)?;
+    }
+    Ok(())
+}
+
+/// Write evaluated samples in order, re-evaluating them on their own nodes for adaptive grids
+/// and computing their labels
+fn write_block(
+    sink: &mut Sink,
+    grid: &GridSpec,
+    labels: &Labels,
+    block: Vec<(C1Params, Vec<f64>, Vec<f64>, f64)>,
+) -> Result<(), Box<dyn Error>> {
+    let shared = grid.is_shared().then(|| grid.nodes());
+    let samples: Vec<(Sample, Vec<f64>)> = block
+        .into_par_iter()
+        .map(|(p, v, dv, weight)| {
+            let sample = if grid.is_shared() {
+                Sample {
+                    params: p,
+                    grid: None,
+                    v,
+                    dv,
+                    weight,
+                }
+            } else {
+                let phi = grid.nodes_for(&p);
+                let out = BatchEvaluator::new(&phi).eval(&[p]);
+                Sample {
+                    params: p,
+                    grid: Some(phi),
+                    v: out.v.row(0),
+                    dv: out.dv.row(0),
+                    weight,
+                }
+            };
+            let phi = sample.grid.as_deref().or(shared.as_deref()).unwrap_or(&[]);
+            let labels = labels.values(&sample, phi);
+            (sample, labels)
+        })
+        .collect();
+    for (sample, labels) in &samples {
+        sink.push(sample, labels)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+    use arrow2::io::parquet::read::read_metadata;
+
+    #[test]
+    fn resumed_run_matches_uninterrupted_one() {
+        let dir = TempDir::new("generate-resume");
+        let (reference, output) = (dir.file("reference.parquet"), dir.file("resumed.parquet"));
+        let config = |output: &str| RunConfig {
+            n_samples: 600,
+            output: output.to_string(),
+            checkpoint_interval: Some(0),
+            ..RunConfig::default()
+        };
+        generate(&config(&reference)).unwrap();
+
+        // A directory in place of the output makes the final merge fail and leaves the part
+        // files and the checkpoint of every round behind
+        fs::create_dir_all(&output).unwrap();
+        assert!(generate(&config(&output)).is_err());
+        fs::remove_dir(&output).unwrap();
+
+        // Rewind the checkpoint to the end of the first round
+        let partial = partial_dir(&output);
+        let mut checkpoint = Checkpoint::load(&partial).unwrap();
+        assert!(checkpoint.n_parts > 1, "the run should take several rounds");
+        let mut part = fs::File::open(part_path(&partial, 0)).unwrap();
+        let n_first = read_metadata(&mut part).unwrap().num_rows;
+        checkpoint.next_index = CHUNKS_PER_ROUND * CHUNK_SIZE as u64;
+        checkpoint.n_accepted = n_first as u64;
+        checkpoint.n_rows = n_first;
+        checkpoint.n_parts = 1;
+        checkpoint.save(&partial).unwrap();
+
+        let resumed = RunConfig {
+            resume: true,
+            ..config(&output)
+        };
+        generate(&resumed).unwrap();
+        assert!(!partial.exists());
+        assert!(fs::read(&output).unwrap() == fs::read(&reference).unwrap());
+    }
+}
@@ -0,0 +1,224 @@
+use crate::c1::C1Params;
+use peroxide::fuga::*;
+use std::f64::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+
+/// How the field values φ ∈ [0, 1] of a sample are chosen
+///
+/// Written as `kind:n[:param]`, e.g. `uniform:100`, `chebyshev:64`, `log:100:1e-4`, `adaptive:100`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GridSpec {
+    /// `n` equally spaced points
+    Uniform { n: usize },
+    /// `n` Chebyshev–Gauss–Lobatto nodes mapped to [0, 1]
+    Chebyshev { n: usize },
+    /// φ = 0 followed by `n - 1` log-spaced points from `phi_min` to 1, dense near the false vacuum
+    Log { n: usize, phi_min: f64 },
+    /// `n` points per potential, refined around its barrier top and inflection points
+    Adaptive { n: usize },
+}
+
+impl GridSpec {
+    pub fn len(&self) -> usize {
+        match *self {
+            GridSpec::Uniform { n }
+            | GridSpec::Chebyshev { n }
+            | GridSpec::Log { n, .. }
+            | GridSpec::Adaptive { n } => n,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether every potential shares the same nodes
+    pub fn is_shared(&self) -> bool {
+        !matches!(self, GridSpec::Adaptive { .. })
+    }
+
+    /// Nodes of a shared grid; for adaptive grids the uniform grid of the same size
+    pub fn nodes(&self) -> Vec<f64> {
+        match *self {
+            GridSpec::Uniform { n } | GridSpec::Adaptive { n } => linspace(0, 1, n),
+            GridSpec::Chebyshev { n } => (0..n)
+                .map(|j| 0.5 * (1f64 - (PI * j as f64 / (n - 1) as f64).cos()))
+                .collect(),
+            GridSpec::Log { n, phi_min } => {
+                let mut nodes = vec![0f64];
+                nodes.extend(logspace(phi_min.log10(), 0, n - 1, 10));
+                nodes
+            }
+        }
+    }
+
+    /// Nodes used for the potential with parameters `p`
+    pub fn nodes_for(&self, p: &C1Params) -> Vec<f64> {
+        match *self {
+            GridSpec::Adaptive { n } => adaptive_nodes(p, n),
+            _ => self.nodes(),
+        }
+    }
+}
+
+impl fmt::Display for GridSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            GridSpec::Uniform { n } => write!(f, "uniform:{}", n),
+            GridSpec::Chebyshev { n } => write!(f, "chebyshev:{}", n),
+            GridSpec::Log { n, phi_min } => write!(f, "log:{}:{:e}", n, phi_min),
+            GridSpec::Adaptive { n } => write!(f, "adaptive:{}", n),
+        }
+    }
+}
+
+impl FromStr for GridSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        let n = match parts.get(1) {
+            Some(n) => n
+                .parse::<usize>()
+                .map_err(|e| format!("invalid grid size in '{}': {}", s, e))?,
+            None => return Err(format!("grid '{}' is missing its size (kind:n)", s)),
+        };
+        if n < 3 {
+            return Err(format!("grid '{}' needs at least 3 points", s));
+        }
+        match (parts[0], parts.len()) {
+            ("uniform", 2) => Ok(GridSpec::Uniform { n }),
+            ("chebyshev", 2) => Ok(GridSpec::Chebyshev { n }),
+            ("adaptive", 2) => Ok(GridSpec::Adaptive { n }),
+            ("log", 2) => Ok(GridSpec::Log { n, phi_min: 1e-4 }),
+            ("log", 3) => {
+                let phi_min = parts[2]
+                    .parse::<f64>()
+                    .map_err(|e| format!("invalid phi_min in '{}': {}", s, e))?;
+                if !(phi_min > 0f64 && phi_min < 1f64) {
+                    return Err(format!("phi_min in '{}' must lie in (0, 1)", s));
+                }
+                Ok(GridSpec::Log { n, phi_min })
+            }
+            _ => Err(format!(
+                "unknown grid '{}' (expected uniform:n, chebyshev:n, log:n[:phi_min] or adaptive:n)",
+                s
+            )),
+        }
+    }
+}
+
+/// Equidistribute `n` nodes w.r.t. a density with Gaussian bumps on the barrier top
+/// and the inflection points `phi_1n`, `phi_1p`
+fn adaptive_nodes(p: &C1Params, n: usize) -> Vec<f64> {
+    const N_FINE: usize = 2001;
+    const WIDTH: f64 = 0.04;
+    const WEIGHT: f64 = 4f64;
+
+    let fine = linspace(0, 1, N_FINE);
+    let v = fine.fmap(p.potential());
+    let top = fine[v.arg_max()];
+    let features = [top, p.phi_1n, p.phi_1p];
+
+    let density = |x: f64| -> f64 {
+        1f64 + features
+            .iter()
+            .map(|&c| WEIGHT * (-(x - c).powi(2) / (2f64 * WIDTH * WIDTH)).exp())
+            .sum::<f64>()
+    };
+
+    // Cumulative density by the trapezoid rule, then invert it by linear interpolation
+    let mut cdf = vec![0f64; N_FINE];
+    for i in 1..N_FINE {
+        cdf[i] =
+            cdf[i - 1] + 0.5 * (density(fine[i - 1]) + density(fine[i])) * (fine[i] - fine[i - 1]);
+    }
+    let total = cdf[N_FINE - 1];
+
+    let mut nodes = Vec::with_capacity(n);
+    let mut i = 0;
+    for j in 0..n {
+        let target = total * j as f64 / (n - 1) as f64;
+        while i < N_FINE - 2 && cdf[i + 1] < target {
+            i += 1;
+        }
+        let t = (target - cdf[i]) / (cdf[i + 1] - cdf[i]);
+        nodes.push(fine[i] + t.clamp(0f64, 1f64) * (fine[i + 1] - fine[i]));
+    }
+    nodes[0] = 0f64;
+    nodes[n - 1] = 1f64;
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_nodes(spec: &str, expected: &[f64]) {
+        let nodes = spec.parse::<GridSpec>().unwrap().nodes();
+        assert_eq!(nodes.len(), expected.len(), "{}", spec);
+        for (a, b) in nodes.iter().zip(expected) {
+            assert!((a - b).abs() < 1e-14, "{}: {:?}", spec, nodes);
+        }
+    }
+
+    #[test]
+    fn shared_nodes() {
+        assert_nodes("uniform:5", &[0.0, 0.25, 0.5, 0.75, 1.0]);
+        let s = 0.5 * (1.0 - 0.5f64.sqrt());
+        assert_nodes("chebyshev:5", &[0.0, s, 0.5, 1.0 - s, 1.0]);
+        assert_nodes("log:4:1e-2", &[0.0, 0.01, 0.1, 1.0]);
+        assert_nodes("adaptive:3", &[0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn adaptive_nodes_cluster_at_features() {
+        let p = C1Params::new(0.3, 0.8, 0.15, 0.5);
+        let spec = GridSpec::Adaptive { n: 101 };
+        let nodes = spec.nodes_for(&p);
+        assert_eq!(nodes.len(), 101);
+        assert_eq!((nodes[0], nodes[100]), (0.0, 1.0));
+        assert!(nodes.windows(2).all(|w| w[0] < w[1]));
+
+        let spacing_at = |x: f64| {
+            let i = nodes.iter().position(|&y| y > x).unwrap();
+            nodes[i] - nodes[i - 1]
+        };
+        // Far from the features the density is ~1, at a feature ~1 + WEIGHT
+        assert!(spacing_at(p.phi_1n) < 0.5 * spacing_at(0.98));
+        assert!(spacing_at(p.phi_1p) < 0.5 * spacing_at(0.98));
+    }
+
+    #[test]
+    fn parse_display_round_trip() {
+        for s in [
+            "uniform:100",
+            "chebyshev:64",
+            "log:100:1e-4",
+            "adaptive:100",
+            "log:10:2.5e-3",
+        ] {
+            let spec: GridSpec = s.parse().unwrap();
+            assert_eq!(spec.to_string(), s);
+            assert_eq!(spec.to_string().parse::<GridSpec>(), Ok(spec));
+        }
+        assert_eq!(
+            "log:50".parse::<GridSpec>(),
+            Ok(GridSpec::Log {
+                n: 50,
+                phi_min: 1e-4
+            })
+        );
+        for bad in [
+            "uniform",
+            "uniform:2",
+            "uniform:x",
+            "log:10:0",
+            "log:10:1",
+            "spline:10",
+        ] {
+            assert!(bad.parse::<GridSpec>().is_err(), "{}", bad);
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use crate::output::{write_parquet, Codec};
+use crate::transition::Transition;
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+
+/// Speed of sound of a relativistic plasma
+const C_S: f64 = 0.577_350_269_189_625_8;
+
+/// Fraction of the vacuum energy going into bulk fluid motion, from the fits of
+/// Espinosa, Konstandin, No and Servant (2010) for deflagrations, hybrids and detonations
+pub fn kinetic_efficiency(alpha: f64, v_w: f64) -> f64 {
+    let sqrt_a = alpha.sqrt();
+    let kappa_a = v_w.powf(1.2) * 6.9 * alpha / (1.36 - 0.037 * sqrt_a + alpha);
+    let kappa_b = alpha.powf(0.4) / (0.017 + (0.997 + alpha).powf(0.4));
+    let kappa_c = sqrt_a / (0.135 + (0.98 + alpha).sqrt());
+    let kappa_d = alpha / (0.73 + 0.083 * sqrt_a + alpha);
+    // Jouguet velocity
+    let v_j = ((2f64 * alpha / 3f64 + alpha * alpha).sqrt() + C_S) / (1f64 + alpha);
+    if v_w < C_S {
+        let c = C_S.powf(2.2);
+        c * kappa_a * kappa_b / ((c - v_w.powf(2.2)) * kappa_b + v_w * C_S.powf(1.2) * kappa_a)
+    } else if v_w < v_j {
+        let delta = -0.9 * (sqrt_a / (1f64 + sqrt_a)).ln();
+        let x = v_w - C_S;
+        let x_j = v_j - C_S;
+        kappa_b + x * delta + (x / x_j).powi(3) * (kappa_c - kappa_b - x_j * delta)
+    } else {
+        let a = (v_j - 1f64).powi(3) * v_j.powf(2.5);
+        let b = (v_w - 1f64).powi(3);
+        a * v_w.powf(-2.5) * kappa_c * kappa_d
+            / (((v_j - 1f64).powi(3) - b) * v_j.powf(2.5) * kappa_c + b * kappa_d)
+    }
+}
+
+/// Efficiency of the sound waves: the fit of `kinetic_efficiency` or a fixed value, written
+/// `fit` or as a number
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Efficiency {
+    Fit,
+    Fixed(f64),
+}
+
+impl fmt::Display for Efficiency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Efficiency::Fit => write!(f, "fit"),
+            Efficiency::Fixed(kappa) => write!(f, "{}", kappa),
+        }
+    }
+}
+
+impl FromStr for Efficiency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "fit" {
+            return Ok(Efficiency::Fit);
+        }
+        match s.parse::<f64>() {
+            Ok(kappa) if (0f64..=1f64).contains(&kappa) => Ok(Efficiency::Fixed(kappa)),
+            _ => Err(format!(
+                "invalid efficiency '{}' (expected fit or a number in [0, 1])",
+                s
+            )),
+        }
+    }
+}
+
+/// Settings of the gravitational-wave templates
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GwOptions {
+    /// Bubble wall velocity
+    pub v_w: f64,
+    /// Fraction of the vacuum energy in sound waves
+    pub kappa_sw: Efficiency,
+    /// Fraction of the vacuum energy in the bubble walls; 0 unless the walls run away
+    pub kappa_col: f64,
+    /// Fraction of the sound-wave energy turned into turbulence
+    pub epsilon_turb: f64,
+    /// Relativistic degrees of freedom at the transition
+    pub g_star: f64,
+    /// Energy unit of the potential in GeV, which converts T_n to a physical temperature
+    pub unit_gev: f64,
+}
+
+impl Default for GwOptions {
+    fn default() -> Self {
+        Self {
+            v_w: 0.95,
+            kappa_sw: Efficiency::Fit,
+            kappa_col: 0f64,
+            epsilon_turb: 0.05,
+            g_star: 106.75,
+            unit_gev: 100f64,
+        }
+    }
+}
+
+/// Template spectra h²Ω_GW(f) today of a first-order transition, following the LISA
+/// cosmology working group (Caprini et al. 2016): sound waves from simulations, bubble
+/// collisions in the envelope approximation and Kolmogorov turbulence
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GwSpectrum {
+    pub alpha: f64,
+    pub beta_over_h: f64,
+    /// Nucleation temperature in GeV
+    pub t_gev: f64,
+    pub options: GwOptions,
+    pub kappa_sw: f64,
+}
+
+impl GwSpectrum {
+    pub fn new(alpha: f64, beta_over_h: f64, t_n: f64, options: GwOptions) -> Self {
+        let kappa_sw = match options.kappa_sw {
+            Efficiency::Fit => kinetic_efficiency(alpha, options.v_w),
+            Efficiency::Fixed(kappa) => kappa,
+        };
+        Self {
+            alpha,
+            beta_over_h,
+            t_gev: t_n * options.unit_gev,
+            options,
+            kappa_sw,
+        }
+    }
+
+    pub fn from_transition(transition: &Transition, options: GwOptions) -> Self {
+        Self::new(
+            transition.alpha,
+            transition.beta_over_h,
+            transition.t_n,
+            options,
+        )
+    }
+
+    /// Redshift factor `(T / 100 GeV) (g_* / 100)^{1/6}` of the frequencies
+    fn redshift(&self) -> f64 {
+        self.t_gev / 100f64 * (self.options.g_star / 100f64).powf(1f64 / 6f64)
+    }
+
+    /// Dilution `(100 / g_*)^{1/3}` of the amplitudes
+    fn dilution(&self) -> f64 {
+        (100f64 / self.options.g_star).powf(1f64 / 3f64)
+    }
+
+    /// `κ α / (1 + α)` for efficiency κ
+    fn strength(&self, kappa: f64) -> f64 {
+        kappa * self.alpha / (1f64 + self.alpha)
+    }
+
+    /// Peak frequencies in Hz of sound waves, collisions and turbulence
+    pub fn peaks(&self) -> [f64; 3] {
+        let (v_w, z) = (self.options.v_w, self.redshift() * self.beta_over_h);
+        [
+            1.9e-5 / v_w * z,
+            16.5e-6 * 0.62 / (1.8 - 0.1 * v_w + v_w * v_w) * z,
+            2.7e-5 / v_w * z,
+        ]
+    }
+
+    pub fn sound_waves(&self, f: f64) -> f64 {
+        let x = f / self.peaks()[0];
+        let shape = x.powi(3) * (7f64 / (4f64 + 3f64 * x * x)).powf(3.5);
+        2.65e-6 / self.beta_over_h
+            * self.strength(self.kappa_sw).powi(2)
+            * self.dilution()
+            * self.options.v_w
+            * shape
+    }
+
+    pub fn collisions(&self, f: f64) -> f64 {
+        let v_w = self.options.v_w;
+        let x = f / self.peaks()[1];
+        let shape = 3.8 * x.powf(2.8) / (1f64 + 2.8 * x.powf(3.8));
+        let velocity = 0.11 * v_w.powi(3) / (0.42 + v_w * v_w);
+        1.67e-5 / self.beta_over_h.powi(2)
+            * self.strength(self.options.kappa_col).powi(2)
+            * self.dilution()
+            * velocity
+            * shape
+    }
+
+    pub fn turbulence(&self, f: f64) -> f64 {
+        let x = f / self.peaks()[2];
+        // Hubble rate at the transition redshifted to today, in Hz
+        let h_star = 16.5e-6 * self.redshift();
+        let shape = x.powi(3) / ((1f64 + x).powf(11f64 / 3f64) * (1f64 + 8f64 * PI * f / h_star));
+        let kappa = self.options.epsilon_turb * self.kappa_sw;
+        3.35e-4 / self.beta_over_h
+            * self.strength(kappa).powf(1.5)
+            * self.dilution()
+            * self.options.v_w
+            * shape
+    }
+
+    /// `[sound waves, collisions, turbulence, total]` at frequency `f` in Hz
+    pub fn eval(&self, f: f64) -> [f64; 4] {
+        let (sw, col, turb) = (self.sound_waves(f), self.collisions(f), self.turbulence(f));
+        [sw, col, turb, sw + col + turb]
+    }
+
+    /// Write h²Ω_GW of each source and their sum at the frequencies `freqs`
+    pub fn write_table(
+        &self,
+        path: &str,
+        freqs: &[f64],
+        metadata: Vec<(String, String)>,
+    ) -> Result<(), Box<dyn Error>> {
+        let values: Vec<[f64; 4]> = freqs.iter().map(|&f| self.eval(f)).collect();
+        let mut columns = vec![("f".to_string(), freqs.to_vec())];
+        for (k, name) in ["h2_omega_sw", "h2_omega_col", "h2_omega_turb", "h2_omega"]
+            .iter()
+            .enumerate()
+        {
+            columns.push((name.to_string(), values.iter().map(|v| v[k]).collect()));
+        }
+        write_parquet(path, columns, metadata, Codec::Uncompressed.options())
+    }
+}
+
+impl fmt::Display for GwSpectrum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "alpha {:.4e}, beta/H {:.4e}, T_n {:.4e} GeV, v_w {}, g_* {}",
+            self.alpha, self.beta_over_h, self.t_gev, self.options.v_w, self.options.g_star
+        )?;
+        let kappas = [
+            self.kappa_sw,
+            self.options.kappa_col,
+            self.options.epsilon_turb * self.kappa_sw,
+        ];
+        writeln!(
+            f,
+            "{:<14} {:>12} {:>12} {:>12}",
+            "source", "kappa", "f_peak [Hz]", "h2 omega"
+        )?;
+        for (i, name) in ["sound waves", "collisions", "turbulence"]
+            .iter()
+            .enumerate()
+        {
+            let peak = self.peaks()[i];
+            write!(
+                f,
+                "{:<14} {:>12.4e} {:>12.4e} {:>12.4e}",
+                name,
+                kappas[i],
+                peak,
+                self.eval(peak)[i]
+            )?;
+            if i < 2 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Logarithmically spaced frequencies in Hz, written `lo:hi:n`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FrequencyRange {
+    pub lo: f64,
+    pub hi: f64,
+    pub n: usize,
+}
+
+impl Default for FrequencyRange {
+    fn default() -> Self {
+        Self {
+            lo: 1e-6,
+            hi: 1f64,
+            n: 121,
+        }
+    }
+}
+
+impl FrequencyRange {
+    pub fn values(&self) -> Vec<f64> {
+        let (a, b) = (self.lo.ln(), self.hi.ln());
+        (0..self.n)
+            .map(|i| (a + (b - a) * i as f64 / (self.n - 1) as f64).exp())
+            .collect()
+    }
+}
+
+impl fmt::Display for FrequencyRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.lo, self.hi, self.n)
+    }
+}
+
+impl FromStr for FrequencyRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        if parts.len() != 3 {
+            return Err(format!("invalid frequencies '{}' (expected lo:hi:n)", s));
+        }
+        let num = |x: &str| {
+            x.parse::<f64>()
+                .map_err(|e| format!("invalid number '{}' in frequencies: {}", x, e))
+        };
+        let n = parts[2]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid point count in frequencies '{}': {}", s, e))?;
+        let (lo, hi) = (num(parts[0])?, num(parts[1])?);
+        if n < 2 {
+            return Err(format!("frequencies '{}' need at least 2 points", s));
+        }
+        if lo <= 0f64 || lo >= hi {
+            return Err(format!("frequencies '{}' need 0 < lo < hi", s));
+        }
+        Ok(Self { lo, hi, n })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rel(a: f64, b: f64, tol: f64) {
+        assert!((a - b).abs() <= tol * b.abs(), "{} vs {}", a, b);
+    }
+
+    #[test]
+    fn efficiency_matches_fit_limits() {
+        for alpha in [0.01, 0.1, 1f64] {
+            let sqrt_a = alpha.sqrt();
+            let kappa_b = alpha.powf(0.4) / (0.017 + (0.997 + alpha).powf(0.4));
+            let kappa_c = sqrt_a / (0.135 + (0.98 + alpha).sqrt());
+            let kappa_d = alpha / (0.73 + 0.083 * sqrt_a + alpha);
+            let v_j = ((2f64 * alpha / 3f64 + alpha * alpha).sqrt() + C_S) / (1f64 + alpha);
+            // The branches join at the speed of sound and at the Jouguet velocity
+            assert_rel(kinetic_efficiency(alpha, C_S), kappa_b, 1e-12);
+            assert_rel(kinetic_efficiency(alpha, C_S - 1e-9), kappa_b, 1e-6);
+            assert_rel(kinetic_efficiency(alpha, v_j), kappa_c, 1e-12);
+            assert_rel(kinetic_efficiency(alpha, v_j - 1e-9), kappa_c, 1e-6);
+            // Runaway-free detonations approach κ_D, slow deflagrations κ_A ∝ v_w^{6/5}
+            assert_rel(kinetic_efficiency(alpha, 1f64 - 1e-9), kappa_d, 1e-6);
+            let v_w = 1e-4f64;
+            let kappa_a = v_w.powf(1.2) * 6.9 * alpha / (1.36 - 0.037 * sqrt_a + alpha);
+            assert_rel(kinetic_efficiency(alpha, v_w), kappa_a, 1e-3);
+            for v_w in [0.1, 0.4, 0.6, 0.8, 0.95] {
+                let kappa = kinetic_efficiency(alpha, v_w);
+                assert!(kappa > 0f64 && kappa < 1f64);
+                assert!(kinetic_efficiency(2f64 * alpha, v_w) > kappa);
+            }
+        }
+    }
+
+    /// T = 100 GeV, g_* = 100, β/H = 1 and v_w = 1, where the LISA templates reduce to their
+    /// bare coefficients
+    fn reference(alpha: f64, kappa_sw: f64, kappa_col: f64) -> GwSpectrum {
+        let options = GwOptions {
+            v_w: 1f64,
+            kappa_sw: Efficiency::Fixed(kappa_sw),
+            kappa_col,
+            epsilon_turb: 0.05,
+            g_star: 100f64,
+            unit_gev: 100f64,
+        };
+        GwSpectrum::new(alpha, 1f64, 1f64, options)
+    }
+
+    #[test]
+    fn peaks_match_templates() {
+        let spectrum = reference(0.5, 0.5, 1f64);
+        let peaks = spectrum.peaks();
+        assert_rel(peaks[0], 1.9e-5, 1e-12);
+        assert_rel(peaks[1], 16.5e-6 * 0.62 / 2.7, 1e-12);
+        assert_rel(peaks[2], 2.7e-5, 1e-12);
+
+        // κα/(1 + α) = 1/6 for sound waves and 1/3 for collisions
+        assert_rel(spectrum.sound_waves(peaks[0]), 2.65e-6 / 36f64, 1e-12);
+        assert_rel(
+            spectrum.collisions(peaks[1]),
+            1.67e-5 / 9f64 * 0.11 / 1.42,
+            1e-12,
+        );
+        let turbulence = 3.35e-4 * (0.05f64 / 6f64).powf(1.5)
+            / 2f64.powf(11f64 / 3f64)
+            / (1f64 + 8f64 * PI * 2.7e-5 / 16.5e-6);
+        assert_rel(spectrum.turbulence(peaks[2]), turbulence, 1e-12);
+
+        // Sound waves and collisions peak at their peak frequencies
+        for (k, f) in [(0, peaks[0]), (1, peaks[1])] {
+            let at = |f: f64| spectrum.eval(f)[k];
+            assert!(at(f) > at(f * 1.01) && at(f) > at(f / 1.01));
+        }
+
+        // The frequencies scale with T (β/H) (g_*/100)^{1/6}
+        let options = GwOptions {
+            g_star: 106.75,
+            ..spectrum.options
+        };
+        let scaled = GwSpectrum::new(0.5, 10f64, 2f64, options).peaks();
+        let z = 2f64 * 10f64 * (1.0675f64).powf(1f64 / 6f64);
+        for (a, b) in scaled.iter().zip(peaks) {
+            assert_rel(*a, b * z, 1e-12);
+        }
+    }
+}
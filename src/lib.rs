@@ -1,2 +1,33 @@
+pub mod batch;
+pub mod bounce;
 pub mod c1;
-
+pub mod cdl;
+pub mod checkpoint;
+pub mod compare;
+pub mod config;
+pub mod dataset;
+pub mod dual;
+pub mod features;
+pub mod fit;
+pub mod formats;
+pub mod generate;
+pub mod grid;
+pub mod gw;
+pub mod mcmc;
+pub mod migrate;
+pub mod output;
+pub mod pca;
+pub mod plot;
+pub mod precision;
+pub mod sampler;
+pub mod scan;
+pub mod sensitivity;
+pub mod split;
+pub mod stats;
+pub mod surrogate;
+pub mod target;
+#[cfg(test)]
+mod testutil;
+pub mod thermal;
+pub mod transition;
+pub mod validate;
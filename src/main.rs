@@ -1,89 +1,405 @@
-use bounce::c1::{c1_potential, c1_deriv};
-use peroxide::fuga::*;
-use rayon::prelude::*;
-use std::cmp::Ordering;
+use bounce::bounce::BounceOptions;
+use bounce::cdl::{scan_planck_mass, write_decays};
+use bounce::compare;
+use bounce::config::{
+    CdlConfig, CompareConfig, GwConfig, MigrateConfig, PcaConfig, PlotConfig, RunConfig,
+    ScanConfig, StatsConfig, SurrogateConfig, ThermalConfig, TransitionConfig, TransitionSource,
+    ValidateConfig,
+};
+use bounce::dataset::Dataset;
+use bounce::features::Acceptance;
+use bounce::generate::generate;
+use bounce::gw::GwSpectrum;
+use bounce::migrate::migrate_v1;
+use bounce::pca::Pca;
+use bounce::plot;
+use bounce::scan::{run_scan, write_scan};
+use bounce::split::SPLIT_NAMES;
+use bounce::stats::dataset_stats;
+use bounce::surrogate::{Labelled, Surrogate};
+use bounce::thermal::{scan_temperatures, write_thermal};
+use bounce::transition::find_transition;
+use bounce::validate;
+
+/// Subcommands; without one the arguments are options of `generate`
+const COMMANDS: [&str; 13] = [
+    "generate",
+    "scan",
+    "validate",
+    "plot",
+    "stats",
+    "compare",
+    "pca",
+    "surrogate",
+    "thermal",
+    "transition",
+    "gw",
+    "cdl",
+    "migrate",
+];
 
 fn main() {
-    let u = Uniform(0f64, 1f64);
-    let b = Bernoulli(0.5);
-    let mut vs = vec![(vec![0f64; 100], vec![0f64; 100]); 10000];
-    let phi = linspace(0, 1, 100);
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match args.first() {
+        Some(c) if COMMANDS.contains(&c.as_str()) => args.remove(0),
+        _ => "generate".to_string(),
+    };
 
-    // Parallel generation via rayon
-    vs.par_iter_mut().for_each(|(v, w)| loop {
-        let mut ps = u.sample(4);
-        ps.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    match command.as_str() {
+        "scan" => scan(args),
+        "validate" => validate(args),
+        "plot" => plot(args),
+        "stats" => stats(args),
+        "compare" => compare(args),
+        "pca" => pca(args),
+        "surrogate" => surrogate(args),
+        "thermal" => thermal(args),
+        "transition" => transition(args),
+        "gw" => gw(args),
+        "cdl" => cdl(args),
+        "migrate" => migrate(args),
+        _ => run(args),
+    }
+}
 
-        let p = b.sample(1)[0];
+fn parse_or_exit<T>(parsed: Result<T, String>) -> T {
+    match parsed {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(2);
+        }
+    }
+}
 
-        let (phi_1p, mut phi_0, mut phi_2, phi_1n) = (ps[0], ps[1], ps[2], ps[3]);
+fn run(args: Vec<String>) {
+    let config = parse_or_exit(RunConfig::from_args(args));
 
-        if p < 0.5 {
-            std::mem::swap(&mut phi_0, &mut phi_2);
+    let summary = match generate(&config) {
+        Ok(summary) => summary,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
         }
+    };
+    if summary.n_samples < config.n_samples {
+        eprintln!(
+            "warning: only {} of {} samples after {} candidates",
+            summary.n_samples, config.n_samples, summary.n_candidates
+        );
+    }
+    if let Some((kept, quotas)) = &summary.target_fill {
+        println!("target bins (kept/quota): {:?} / {:?}", kept, quotas);
+    }
 
-        let f = c1_potential(phi_0, phi_1n, phi_1p, phi_2);
-        let v_cand = phi.fmap(f);
-        let v_max = v_cand.max();
-        if v_max < 0.01 || v_max > 10f64.powf(-0.5) {
-            continue;
-        } else {
-            let (count_max, count_min) = count_local_extrema(&v_cand);
-            if count_max > 1 || count_min > 2 {
-                continue;
-            }
-            *v = v_cand;
-            let g = c1_deriv(phi_0, phi_1n, phi_1p, phi_2);
-            *w = phi.fmap(g);
-            break;
-        }
-    });
+    if let Some(counts) = summary.split_counts {
+        println!(
+            "split (train/val/test): {} / {} / {}",
+            counts[0], counts[1], counts[2]
+        );
+    }
+
+    if let Some(d) = &summary.mcmc {
+        println!(
+            "mcmc: acceptance rate {:.3}, tau {:.1?}, ess {:.0?}",
+            d.acceptance_rate, d.tau, d.ess
+        );
+    }
+
+    println!("done");
+}
+
+fn scan(args: Vec<String>) {
+    let config = parse_or_exit(ScanConfig::from_args(args));
 
-    let mut df = DataFrame::new(vec![]);
-    for (i, (v, w)) in vs.into_iter().enumerate() {
-        let mut v = v;
-        v.extend(w);
-        df.push(&format!("v{}", i), Series::new(v));
+    let rows = run_scan(
+        &config.spec,
+        &config.grid.nodes(),
+        Acceptance::default(),
+        config.action,
+    );
+    let n_accepted = rows.iter().filter(|r| r.accepted).count();
+    if let Err(e) = write_scan(&config.output, &rows, config.to_metadata()) {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
     }
-    df.write_parquet("c1.parquet", CompressionOptions::Uncompressed)
-        .unwrap();
 
+    println!("{} of {} scan points accepted", n_accepted, rows.len());
     println!("done");
 }
 
-fn count_local_extrema(ys: &[f64]) -> (usize, usize) {
-    let mut count_maxima = 0;
-    let mut count_minima = 0;
+fn validate(args: Vec<String>) {
+    let config = parse_or_exit(ValidateConfig::from_args(args));
 
-    ys.iter().enumerate().for_each(|(i, y)| {
-        if i == 0 {
-            if y > &ys[1] {
-                count_maxima += 1;
-            } else if y < &ys[1] {
-                count_minima += 1;
+    match validate::validate(
+        &config.path,
+        config.tol,
+        config.precision_tol,
+        Acceptance::default(),
+    ) {
+        Ok(report) => {
+            println!("{}", report);
+            if !report.is_ok() {
+                std::process::exit(1);
             }
-        } else if i == ys.len() - 1 {
-            if y > &ys[i - 1] {
-                count_maxima += 1;
-            } else if y < &ys[i - 1] {
-                count_minima += 1;
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn plot(args: Vec<String>) {
+    let config = parse_or_exit(PlotConfig::from_args(args));
+
+    let summary = Dataset::open(&config.path)
+        .and_then(|dataset| plot::plot(&dataset, &config.spec, &config.output));
+    match summary {
+        Ok(summary) => {
+            if summary.n_skipped > 0 {
+                eprintln!("warning: {} samples left out", summary.n_skipped);
             }
-        } else {
-            match y.partial_cmp(&ys[i - 1]).unwrap() {
-                Ordering::Greater => {
-                    if y > &ys[i + 1] {
-                        count_maxima += 1;
-                    }
-                }
-                Ordering::Less => {
-                    if y < &ys[i + 1] {
-                        count_minima += 1;
-                    }
+            println!("{} drawn to {}", summary.n_drawn, config.output);
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn stats(args: Vec<String>) {
+    let config = parse_or_exit(StatsConfig::from_args(args));
+
+    let stats = Dataset::open(&config.path).and_then(|dataset| dataset_stats(&dataset));
+    let written = stats.and_then(|stats| {
+        std::fs::write(&config.json, stats.to_json())?;
+        Ok(stats)
+    });
+    match written {
+        Ok(stats) => {
+            println!("{}", stats);
+            println!("written to {}", config.json);
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn compare(args: Vec<String>) {
+    let config = parse_or_exit(CompareConfig::from_args(args));
+
+    let [a, b] = &config.paths;
+    let comparison = Dataset::open(a)
+        .and_then(|a| Ok((a, Dataset::open(b)?)))
+        .and_then(|(a, b)| compare::compare(&a, &b, config.alpha));
+    match comparison {
+        Ok(comparison) => println!("{}", comparison),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn pca(args: Vec<String>) {
+    let config = parse_or_exit(PcaConfig::from_args(args));
+
+    let written = Dataset::open(&config.path).and_then(|dataset| {
+        let pca = Pca::fit(&dataset, config.derivatives)?;
+        let k = config.n_components.min(pca.mean.len());
+        let source = vec![("bounce.pca.source".to_string(), config.path.clone())];
+        pca.write_components(&config.components, k, source.clone())?;
+        pca.write_scores(&dataset, &config.scores, k, source)?;
+        if let Some(path) = &config.reconstruct {
+            pca.write_reconstruction(&dataset, path, k)?;
+        }
+        Ok((pca, k))
+    });
+    match written {
+        Ok((pca, k)) => {
+            print!("{}", pca);
+            println!("{} components written to {}", k, config.components);
+            println!("scores written to {}", config.scores);
+            if let Some(path) = &config.reconstruct {
+                println!("curves rebuilt from {} components written to {}", k, path);
+            }
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn surrogate(args: Vec<String>) {
+    let config = parse_or_exit(SurrogateConfig::from_args(args));
+
+    let result = match config {
+        SurrogateConfig::Train { path, spec, output } => Dataset::open(&path).and_then(|dataset| {
+            let data = Labelled::load(&dataset, &spec.target)?;
+            let model = Surrogate::train(&data, spec)?;
+            model.save(&output)?;
+            println!("{}", model);
+            if data.has_splits() {
+                for (i, name) in SPLIT_NAMES.iter().enumerate() {
+                    println!("{:<6} {}", name, model.evaluate(&data, Some(i))?);
                 }
-                _ => {} // This case covers equal elements, where neither condition is met.
+            } else {
+                println!("train  {}", model.evaluate(&data, None)?);
             }
+            println!("written to {}", output);
+            Ok(())
+        }),
+        SurrogateConfig::Evaluate { model, path, split } => {
+            Surrogate::load(&model).and_then(|model| {
+                let dataset = Dataset::open(&path)?;
+                let data = Labelled::load(&dataset, &model.spec.target)?;
+                println!("{}", model);
+                println!("{}", model.evaluate(&data, split)?);
+                Ok(())
+            })
+        }
+        SurrogateConfig::Predict {
+            model,
+            path,
+            output,
+        } => Surrogate::load(&model).and_then(|model| {
+            let n = model.write_predictions(&Dataset::open(&path)?, &output)?;
+            println!("{} predictions written to {}", n, output);
+            Ok(())
+        }),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn thermal(args: Vec<String>) {
+    let config = parse_or_exit(ThermalConfig::from_args(args));
+
+    let opts = BounceOptions {
+        dim: 3,
+        ..BounceOptions::default()
+    };
+    let points = scan_temperatures(&config.potential, &config.temps.values(), opts);
+    if let Err(e) = write_thermal(&config.output, &points, config.to_metadata()) {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+    println!(
+        "{:>10} {:>10} {:>10} {:>12} {:>12} {:>12}",
+        "T", "phi_false", "phi_true", "delta_v", "S_3", "S_3/T"
+    );
+    for point in &points {
+        println!("{}", point);
+    }
+    println!("written to {}", config.output);
+}
+
+fn transition(args: Vec<String>) {
+    let config = parse_or_exit(TransitionConfig::from_args(args));
+
+    let transition = find_transition(&config.potential, &config.temps.values(), &config.options);
+    let written = transition.map_err(|e| e.into()).and_then(|transition| {
+        std::fs::write(&config.json, transition.to_json())?;
+        Ok::<_, Box<dyn std::error::Error>>(transition)
+    });
+    match written {
+        Ok(transition) => {
+            println!("{}", transition);
+            println!("written to {}", config.json);
         }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn gw(args: Vec<String>) {
+    let config = parse_or_exit(GwConfig::from_args(args));
+
+    let spectrum = match &config.source {
+        TransitionSource::Potential {
+            potential,
+            temps,
+            options,
+        } => find_transition(potential, &temps.values(), options).map(|transition| {
+            println!("{}", transition);
+            GwSpectrum::from_transition(&transition, config.options)
+        }),
+        TransitionSource::Given {
+            alpha,
+            beta_over_h,
+            t_n,
+        } => Ok(GwSpectrum::new(*alpha, *beta_over_h, *t_n, config.options)),
+    };
+    let written = spectrum.map_err(|e| e.into()).and_then(|spectrum| {
+        spectrum.write_table(&config.output, &config.freqs.values(), config.to_metadata())?;
+        Ok::<_, Box<dyn std::error::Error>>(spectrum)
     });
+    match written {
+        Ok(spectrum) => {
+            println!("{}", spectrum);
+            println!("written to {}", config.output);
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
 
-    (count_maxima, count_minima)
+fn cdl(args: Vec<String>) {
+    let config = parse_or_exit(CdlConfig::from_args(args));
+
+    let decays = scan_planck_mass(
+        &config.params,
+        config.vacuum_energy,
+        &config.planck_masses,
+        BounceOptions::default(),
+    );
+    let written = decays.map_err(|e| e.into()).and_then(|decays| {
+        write_decays(&config.output, &decays, config.to_metadata())?;
+        Ok::<_, Box<dyn std::error::Error>>(decays)
+    });
+    match written {
+        Ok(decays) => {
+            println!(
+                "{:>12} {:>12} {:>14} {:>14} {:>14}",
+                "M_P", "phi_release", "B_cdl", "B_hm", "dominant"
+            );
+            for decay in &decays {
+                println!("{}", decay);
+            }
+            println!("written to {}", config.output);
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn migrate(args: Vec<String>) {
+    let config = parse_or_exit(MigrateConfig::from_args(args));
+
+    match migrate_v1(&config.path, &config.output, config.compression) {
+        Ok(migration) => {
+            println!(
+                "{} samples on {} grid points, parameters recovered with RMS residuals up to {:.2e}",
+                migration.n_samples, migration.grid_len, migration.max_rmse
+            );
+            println!("written to {}", config.output);
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,235 @@
+use crate::c1::C1Params;
+use crate::sampler::SplitMix64;
+use rayon::prelude::*;
+
+/// Random-walk Metropolis settings
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct McmcOptions {
+    pub n_chains: usize,
+    /// Steps discarded at the start of every chain
+    pub burn_in: usize,
+    /// Keep every `thin`-th step
+    pub thin: usize,
+    /// Standard deviation of the Gaussian step in the sorted coordinates
+    pub step: f64,
+    /// Probability of proposing the other branch (phi_0 <-> phi_2) in a step
+    pub flip_prob: f64,
+}
+
+impl Default for McmcOptions {
+    fn default() -> Self {
+        Self {
+            n_chains: 8,
+            burn_in: 1000,
+            thin: 10,
+            step: 0.05,
+            flip_prob: 0.1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct McmcDiagnostics {
+    pub acceptance_rate: f64,
+    /// Integrated autocorrelation time of (phi_0, phi_1n, phi_1p, phi_2), in kept draws
+    pub tau: [f64; 4],
+    /// Effective sample size of each parameter over all chains
+    pub ess: [f64; 4],
+}
+
+/// Sample uniformly from the region where `accept` holds
+///
+/// Each chain starts from one of the (already accepted) points in `init` and walks in the
+/// sorted coordinates `u_1 < u_2 < u_3 < u_4` of the sort-and-swap construction. A proposal
+/// is reflected back into the unit cube and then sorted, which is the reflection at the
+/// ordering boundaries `u_i = u_{i+1}`; both keep the proposal symmetric, so the chain is
+/// uniform over the accepted region once a rejected proposal simply repeats the state.
+/// Chains are returned one after the other.
+pub fn run_mcmc<F>(
+    accept: F,
+    init: &[C1Params],
+    n_samples: usize,
+    opts: McmcOptions,
+    seed: u64,
+) -> (Vec<C1Params>, McmcDiagnostics)
+where
+    F: Fn(&C1Params) -> bool + Sync,
+{
+    assert_eq!(
+        init.len(),
+        opts.n_chains,
+        "need one starting point per chain"
+    );
+    let per_chain = n_samples.div_ceil(opts.n_chains);
+    let thin = opts.thin.max(1);
+
+    let chains: Vec<(Vec<C1Params>, usize, usize)> = init
+        .par_iter()
+        .enumerate()
+        .map(|(c, &p0)| {
+            let mut rng = SplitMix64::stream(seed ^ 0x3c6e_f372_fe94_f82b, c as u64);
+            let (mut us, mut swap) = p0.to_sorted();
+            let mut draws = Vec::with_capacity(per_chain);
+            let (mut n_prop, mut n_acc) = (0usize, 0usize);
+            let total = opts.burn_in + per_chain * thin;
+            for step in 0..total {
+                let mut prop = us.map(|u| reflect_unit(u + opts.step * rng.next_normal()));
+                prop.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let prop_swap = if rng.next_f64() < opts.flip_prob {
+                    !swap
+                } else {
+                    swap
+                };
+                let p = C1Params::from_sorted(prop, prop_swap);
+                n_prop += 1;
+                if accept(&p) {
+                    us = prop;
+                    swap = prop_swap;
+                    n_acc += 1;
+                }
+                if step >= opts.burn_in && (step - opts.burn_in + 1).is_multiple_of(thin) {
+                    draws.push(C1Params::from_sorted(us, swap));
+                }
+            }
+            (draws, n_prop, n_acc)
+        })
+        .collect();
+
+    let n_prop: usize = chains.iter().map(|c| c.1).sum();
+    let n_acc: usize = chains.iter().map(|c| c.2).sum();
+    let draws: Vec<Vec<C1Params>> = chains.into_iter().map(|c| c.0).collect();
+
+    let mut tau = [0f64; 4];
+    let mut ess = [0f64; 4];
+    let n_total: usize = draws.iter().map(|d| d.len()).sum();
+    for k in 0..4 {
+        let series: Vec<Vec<f64>> = draws
+            .iter()
+            .map(|d| d.iter().map(|p| p.to_array()[k]).collect())
+            .collect();
+        tau[k] = autocorrelation_time(&series);
+        ess[k] = n_total as f64 / tau[k];
+    }
+
+    let mut samples: Vec<C1Params> = draws.into_iter().flatten().collect();
+    samples.truncate(n_samples);
+    (
+        samples,
+        McmcDiagnostics {
+            acceptance_rate: n_acc as f64 / n_prop as f64,
+            tau,
+            ess,
+        },
+    )
+}
+
+/// Fold a real number back into [0, 1] by mirroring at the walls
+fn reflect_unit(x: f64) -> f64 {
+    let y = x.rem_euclid(2f64);
+    if y > 1f64 {
+        2f64 - y
+    } else {
+        y
+    }
+}
+
+/// Integrated autocorrelation time from chain-averaged autocorrelations, truncated with
+/// Geyer's initial positive sequence
+pub fn autocorrelation_time(chains: &[Vec<f64>]) -> f64 {
+    let n = chains.iter().map(|c| c.len()).min().unwrap_or(0);
+    if n < 4 {
+        return 1f64;
+    }
+    let centered: Vec<(Vec<f64>, f64)> = chains
+        .iter()
+        .map(|c| {
+            let mean = c[..n].iter().sum::<f64>() / n as f64;
+            let xs: Vec<f64> = c[..n].iter().map(|x| x - mean).collect();
+            let var = xs.iter().map(|x| x * x).sum::<f64>() / n as f64;
+            (xs, var)
+        })
+        .collect();
+    if centered.iter().all(|(_, var)| *var == 0f64) {
+        return 1f64;
+    }
+    let rho = |t: usize| -> f64 {
+        let s: f64 = centered
+            .iter()
+            .filter(|(_, var)| *var > 0f64)
+            .map(|(xs, var)| {
+                xs.iter().zip(&xs[t..]).map(|(a, b)| a * b).sum::<f64>() / (n as f64 * var)
+            })
+            .sum();
+        s / centered.iter().filter(|(_, var)| *var > 0f64).count() as f64
+    };
+
+    let mut tau = -1f64;
+    let mut t = 0;
+    while t + 1 < n {
+        let pair = rho(t) + rho(t + 1);
+        if pair <= 0f64 {
+            break;
+        }
+        tau += 2f64 * pair;
+        t += 2;
+    }
+    tau.max(1f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_uniformly_from_a_box() {
+        // With phi_1n < 1/2 the sorted coordinates are the order statistics of four uniforms
+        // on [0, 1/2], whose smallest and largest have means 1/10 and 4/10
+        let accept = |p: &C1Params| p.phi_1n < 0.5;
+        let opts = McmcOptions {
+            burn_in: 200,
+            thin: 5,
+            step: 0.1,
+            ..McmcOptions::default()
+        };
+        let init: Vec<C1Params> = (0..opts.n_chains)
+            .map(|c| C1Params::from_sorted([0.1, 0.2, 0.3, 0.4], c % 2 == 0))
+            .collect();
+        let (samples, diag) = run_mcmc(accept, &init, 8000, opts, 5);
+
+        assert_eq!(samples.len(), 8000);
+        assert!(samples.iter().all(|p| accept(p) && p.is_ordered()));
+        assert!(diag.acceptance_rate > 0.1 && diag.acceptance_rate < 0.9);
+        let n = samples.len() as f64;
+        let mean_min = samples.iter().map(|p| p.phi_1p).sum::<f64>() / n;
+        let mean_max = samples.iter().map(|p| p.phi_1n).sum::<f64>() / n;
+        let swapped = samples.iter().filter(|p| p.is_swapped()).count() as f64 / n;
+        assert!((mean_min - 0.1).abs() < 0.01, "mean phi_1p = {}", mean_min);
+        assert!((mean_max - 0.4).abs() < 0.01, "mean phi_1n = {}", mean_max);
+        assert!(
+            (swapped - 0.5).abs() < 0.05,
+            "swapped fraction = {}",
+            swapped
+        );
+    }
+
+    #[test]
+    fn autocorrelation_time_of_ar1() {
+        // x_t = a x_{t-1} + e_t has rho(t) = a^t and tau = (1 + a) / (1 - a)
+        let series = |a: f64, c: u64| -> Vec<f64> {
+            let mut rng = SplitMix64::stream(17, c);
+            let mut x = 0f64;
+            (0..20000)
+                .map(|_| {
+                    x = a * x + rng.next_normal();
+                    x
+                })
+                .collect()
+        };
+        for (a, tau) in [(0f64, 1f64), (0.5, 3f64), (0.8, 9f64)] {
+            let chains: Vec<Vec<f64>> = (0..4).map(|c| series(a, c)).collect();
+            let est = autocorrelation_time(&chains);
+            assert!((est - tau).abs() < 0.1 * tau, "a = {}: tau = {}", a, est);
+        }
+        assert_eq!(autocorrelation_time(&[vec![2f64; 100]]), 1f64);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::c1::C1Params;
+use crate::dataset::read_v1;
+use crate::fit::{fit_c1, C1Fit, FitOptions};
+use crate::grid::GridSpec;
+use crate::output::{Codec, Sample, SampleWriter};
+use rayon::prelude::*;
+use std::error::Error;
+
+/// RMS residual up to which a fit started from the curve's own conditions is kept; worse ones
+/// are redone from the fixed starting points of `fit_c1`
+const GUESS_RMSE: f64 = 1e-8;
+
+/// Outcome of converting a v1 dataset
+#[derive(Debug, Copy, Clone)]
+pub struct Migration {
+    pub n_samples: usize,
+    pub grid_len: usize,
+    /// Largest RMS residual of the parameter fits
+    pub max_rmse: f64,
+}
+
+/// Convert a v1 dataset into the current schema
+///
+/// The stored curves are kept as they are. v1 did not store the parameters, so they are
+/// recovered by fitting each curve with `fit_c1`, started from the zeros of its stored V' and
+/// of their differences; the RMS residual of each fit goes into a
+/// `fit_rmse` label column. Curves equal up to round-off can come from several parameter
+/// sets, and the fit returns one of them.
+pub fn migrate_v1(input: &str, output: &str, codec: Codec) -> Result<Migration, Box<dyn Error>> {
+    let curves = read_v1(input)?;
+    let grid = GridSpec::Uniform {
+        n: curves[0].0.len(),
+    };
+    let phi = grid.nodes();
+    let fits: Vec<_> = curves
+        .par_iter()
+        .map(|(v, dv)| recover_params(&phi, v, dv))
+        .collect();
+
+    let labels = ["fit_rmse".to_string()];
+    let mut writer =
+        SampleWriter::create(output, grid.len(), false, &labels, codec.options(), 8192)?;
+    let mut max_rmse = 0f64;
+    for ((v, dv), fit) in curves.into_iter().zip(fits) {
+        let rmse = (fit.rss / grid.len() as f64).sqrt();
+        max_rmse = max_rmse.max(rmse);
+        let sample = Sample {
+            params: fit.params,
+            grid: None,
+            v,
+            dv,
+            weight: 1f64,
+        };
+        writer.push(&sample, &[rmse])?;
+    }
+    let n_samples = writer.n_rows();
+    let nodes: Vec<String> = phi.iter().map(|x| x.to_string()).collect();
+    writer.finish(vec![
+        ("bounce.n_samples".to_string(), n_samples.to_string()),
+        ("bounce.grid".to_string(), grid.to_string()),
+        ("bounce.grid.nodes".to_string(), nodes.join(",")),
+        ("bounce.migrated_from".to_string(), input.to_string()),
+    ])?;
+    Ok(Migration {
+        n_samples,
+        grid_len: grid.len(),
+        max_rmse,
+    })
+}
+
+/// Fit of a stored curve, from its estimated parameters when they lead to a close fit
+fn recover_params(phi: &[f64], v: &[f64], dv: &[f64]) -> C1Fit {
+    let rmse = |fit: &C1Fit| (fit.rss / phi.len() as f64).sqrt();
+    initial_guesses(phi, dv)
+        .into_iter()
+        .map(|init| {
+            let opts = FitOptions {
+                init: Some(init),
+                ..FitOptions::default()
+            };
+            fit_c1(phi, v, opts)
+        })
+        .min_by(|a, b| a.rss.total_cmp(&b.rss))
+        .filter(|fit| rmse(fit) < GUESS_RMSE)
+        .unwrap_or_else(|| fit_c1(phi, v, FitOptions::default()))
+}
+
+/// Parameter estimates from the conditions that define the family
+///
+/// φ_1p and φ_1n are the first and last zero of V'', and φ_0 and φ_2 the zeros of V' and
+/// V''' between them, with the derivatives taken as differences of the stored V'. Every
+/// ordered combination is returned.
+fn initial_guesses(phi: &[f64], dv: &[f64]) -> Vec<C1Params> {
+    // Linearly interpolated sign changes of ys on xs
+    let zeros = |xs: &[f64], ys: &[f64]| -> Vec<f64> {
+        (1..ys.len())
+            .filter(|&i| ys[i - 1] * ys[i] < 0f64)
+            .map(|i| xs[i - 1] + ys[i - 1] / (ys[i - 1] - ys[i]) * (xs[i] - xs[i - 1]))
+            .collect()
+    };
+    let midpoints =
+        |xs: &[f64]| -> Vec<f64> { xs.windows(2).map(|w| 0.5 * (w[0] + w[1])).collect() };
+    let differences = |xs: &[f64], ys: &[f64]| -> Vec<f64> {
+        xs.windows(2)
+            .zip(ys.windows(2))
+            .map(|(x, y)| (y[1] - y[0]) / (x[1] - x[0]))
+            .collect()
+    };
+    let (x2, d2) = (midpoints(phi), differences(phi, dv));
+    let (x3, d3) = (midpoints(&x2), differences(&x2, &d2));
+
+    let inflections = zeros(&x2, &d2);
+    let (phi_1p, phi_1n) = match (inflections.first(), inflections.last()) {
+        (Some(&a), Some(&b)) if a < b => (a, b),
+        _ => return vec![],
+    };
+    let inside = |xs: Vec<f64>| -> Vec<f64> {
+        xs.into_iter()
+            .filter(|&x| phi_1p < x && x < phi_1n)
+            .collect()
+    };
+    let (phi_0s, phi_2s) = (inside(zeros(phi, dv)), inside(zeros(&x3, &d3)));
+    phi_0s
+        .iter()
+        .flat_map(|&phi_0| {
+            phi_2s
+                .iter()
+                .map(move |&phi_2| C1Params::new(phi_0, phi_1n, phi_1p, phi_2))
+        })
+        .filter(|p| p.is_ordered())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataset::Dataset;
+    use crate::features::Acceptance;
+    use crate::testutil::TempDir;
+    use crate::validate::validate;
+    use peroxide::fuga::*;
+
+    /// A dataset in the layout of the v1 generator: one column of V then V' per sample
+    fn write_v1(path: &str, params: &[C1Params]) {
+        let phi = linspace(0, 1, 100);
+        let mut df = DataFrame::new(vec![]);
+        for (i, p) in params.iter().enumerate() {
+            let (v, dv) = (p.potential(), p.deriv());
+            let mut column = phi.fmap(v);
+            column.extend(phi.fmap(dv));
+            df.push(&format!("v{}", i), Series::new(column));
+        }
+        df.write_parquet(path, CompressionOptions::Uncompressed)
+            .unwrap();
+    }
+
+    #[test]
+    fn converts_v1_dataset() {
+        let dir = TempDir::new("migrate");
+        let (input, output) = (dir.file("v1.parquet"), dir.file("v2.parquet"));
+        let params = [
+            C1Params::new(0.4, 0.8, 0.2, 0.6),
+            C1Params::new(0.6, 0.85, 0.15, 0.35),
+        ];
+        write_v1(&input, &params);
+
+        let err = Dataset::open(&input).unwrap_err().to_string();
+        assert!(
+            err.contains("v1 dataset") && err.contains("bounce migrate"),
+            "{}",
+            err
+        );
+
+        let migration = migrate_v1(&input, &output, Codec::Uncompressed).unwrap();
+        assert_eq!(migration.n_samples, 2);
+        assert_eq!(migration.grid_len, 100);
+        assert!(migration.max_rmse < 1e-9, "{}", migration.max_rmse);
+
+        let dataset = Dataset::open(&output).unwrap();
+        assert_eq!(dataset.labels(), ["fit_rmse"]);
+        let records = dataset.load().unwrap();
+        let phi = dataset.grid().unwrap();
+        for (record, p) in records.iter().zip(&params) {
+            // The recovered parameters reproduce the stored curve, whichever member they are
+            let v = record.sample.params.potential();
+            let stored = p.potential();
+            for &x in phi {
+                assert!((v(x) - stored(x)).abs() < 1e-8);
+            }
+        }
+        let report = validate(&output, 1e-6, 1e-8, Acceptance::default()).unwrap();
+        assert_eq!(report.n_mismatch, 0);
+        assert_eq!(report.n_unreliable, 0);
+    }
+}
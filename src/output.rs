@@ -0,0 +1,334 @@
+use crate::c1::C1Params;
+use arrow2::array::{Array, Float64Array};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::io::parquet::write::{
+    BrotliLevel, CompressionOptions, Encoding, FileWriter, GzipLevel, KeyValue, RowGroupIterator,
+    Version, WriteOptions, ZstdLevel,
+};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::str::FromStr;
+
+/// Layout of generated files, stored as `bounce.schema_version`
+///
+/// 1: one column `v{i}` per sample holding V then V' (no parameters)
+/// 2: one row per sample with `phi_0, phi_1n, phi_1p, phi_2, weight`, optional `grid_{j}`,
+///    then `v_{j}` and `dv_{j}`, followed by any label columns (e.g. `split`)
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Parquet compression codec, written `uncompressed`, `snappy`, `lz4`, `gzip[:level]`,
+/// `zstd[:level]` or `brotli[:level]`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Codec {
+    Uncompressed,
+    Snappy,
+    Lz4,
+    Gzip(Option<u8>),
+    Zstd(Option<i32>),
+    Brotli(Option<u32>),
+}
+
+impl Codec {
+    pub fn options(&self) -> CompressionOptions {
+        // Levels are checked when parsing
+        match *self {
+            Codec::Uncompressed => CompressionOptions::Uncompressed,
+            Codec::Snappy => CompressionOptions::Snappy,
+            Codec::Lz4 => CompressionOptions::Lz4Raw,
+            Codec::Gzip(level) => {
+                CompressionOptions::Gzip(level.map(|l| GzipLevel::try_new(l).unwrap()))
+            }
+            Codec::Zstd(level) => {
+                CompressionOptions::Zstd(level.map(|l| ZstdLevel::try_new(l).unwrap()))
+            }
+            Codec::Brotli(level) => {
+                CompressionOptions::Brotli(level.map(|l| BrotliLevel::try_new(l).unwrap()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Codec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = |l: Option<String>| l.map_or(String::new(), |l| format!(":{}", l));
+        match *self {
+            Codec::Uncompressed => write!(f, "uncompressed"),
+            Codec::Snappy => write!(f, "snappy"),
+            Codec::Lz4 => write!(f, "lz4"),
+            Codec::Gzip(l) => write!(f, "gzip{}", level(l.map(|l| l.to_string()))),
+            Codec::Zstd(l) => write!(f, "zstd{}", level(l.map(|l| l.to_string()))),
+            Codec::Brotli(l) => write!(f, "brotli{}", level(l.map(|l| l.to_string()))),
+        }
+    }
+}
+
+impl FromStr for Codec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, level) = match s.split_once(':') {
+            Some((name, level)) => (name, Some(level)),
+            None => (s, None),
+        };
+        let invalid = |e: &dyn fmt::Display| format!("invalid compression level in '{}': {}", s, e);
+        match (name, level) {
+            ("uncompressed", None) => Ok(Codec::Uncompressed),
+            ("snappy", None) => Ok(Codec::Snappy),
+            ("lz4", None) => Ok(Codec::Lz4),
+            ("gzip", l) => {
+                let l = l.map(|l| l.parse::<u8>().map_err(|e| invalid(&e))).transpose()?;
+                if let Some(l) = l {
+                    GzipLevel::try_new(l).map_err(|e| invalid(&e))?;
+                }
+                Ok(Codec::Gzip(l))
+            }
+            ("zstd", l) => {
+                let l = l.map(|l| l.parse::<i32>().map_err(|e| invalid(&e))).transpose()?;
+                if let Some(l) = l {
+                    ZstdLevel::try_new(l).map_err(|e| invalid(&e))?;
+                }
+                Ok(Codec::Zstd(l))
+            }
+            ("brotli", l) => {
+                let l = l.map(|l| l.parse::<u32>().map_err(|e| invalid(&e))).transpose()?;
+                if let Some(l) = l {
+                    BrotliLevel::try_new(l).map_err(|e| invalid(&e))?;
+                }
+                Ok(Codec::Brotli(l))
+            }
+            _ => Err(format!(
+                "unknown compression '{}' (expected uncompressed, snappy, lz4, gzip[:level], zstd[:level] or brotli[:level])",
+                s
+            )),
+        }
+    }
+}
+
+/// One generated potential
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub params: C1Params,
+    /// Per-sample grid, `None` when all samples share the grid in the file metadata
+    pub grid: Option<Vec<f64>>,
+    pub v: Vec<f64>,
+    pub dv: Vec<f64>,
+    /// Importance weight back to the natural distribution (1 unless a target was used)
+    pub weight: f64,
+}
+
+/// Column names of the sample table
+pub fn sample_columns(grid_len: usize, per_sample_grid: bool) -> Vec<String> {
+    let mut names: Vec<String> = ["phi_0", "phi_1n", "phi_1p", "phi_2", "weight"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    if per_sample_grid {
+        names.extend((0..grid_len).map(|j| format!("grid_{}", j)));
+    }
+    names.extend((0..grid_len).map(|j| format!("v_{}", j)));
+    names.extend((0..grid_len).map(|j| format!("dv_{}", j)));
+    names
+}
+
+/// Streams samples to a parquet file, one row per sample
+///
+/// Rows are buffered until `row_group_size` of them are collected and then written out as
+/// one row group, so memory stays bounded however many samples are pushed. The key-value
+/// metadata goes into the footer written by `finish`.
+pub struct SampleWriter {
+    writer: FileWriter<File>,
+    schema: Schema,
+    options: WriteOptions,
+    columns: Vec<Vec<f64>>,
+    grid_len: usize,
+    per_sample_grid: bool,
+    row_group_size: usize,
+    n_rows: usize,
+}
+
+impl SampleWriter {
+    pub fn create(
+        path: &str,
+        grid_len: usize,
+        per_sample_grid: bool,
+        labels: &[String],
+        compression: CompressionOptions,
+        row_group_size: usize,
+    ) -> Result<Self, Box<dyn Error>> {
+        let file = File::create(path)?;
+        let mut names = sample_columns(grid_len, per_sample_grid);
+        names.extend(labels.iter().cloned());
+        let schema = f64_schema(&names);
+        let options = write_options(compression);
+        let writer = FileWriter::try_new(file, schema.clone(), options)?;
+        let row_group_size = row_group_size.max(1);
+        Ok(Self {
+            writer,
+            schema,
+            options,
+            columns: vec![Vec::with_capacity(row_group_size); names.len()],
+            grid_len,
+            per_sample_grid,
+            row_group_size,
+            n_rows: 0,
+        })
+    }
+
+    /// Number of samples pushed so far
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    /// Append a sample with the values of its label columns
+    pub fn push(&mut self, sample: &Sample, labels: &[f64]) -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            sample.v.len(),
+            self.grid_len,
+            "sample does not match the grid of the file"
+        );
+        let mut row = sample.params.to_array().to_vec();
+        row.push(sample.weight);
+        if self.per_sample_grid {
+            row.extend(sample.grid.as_ref().expect("sample without its grid"));
+        }
+        row.extend(&sample.v);
+        row.extend(&sample.dv);
+        row.extend(labels);
+        self.push_row(&row)
+    }
+
+    /// Append one row given in column order
+    pub fn push_row(&mut self, row: &[f64]) -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            row.len(),
+            self.columns.len(),
+            "row does not match the columns of the file"
+        );
+        for (col, &x) in self.columns.iter_mut().zip(row) {
+            col.push(x);
+        }
+        self.n_rows += 1;
+        if self.columns[0].len() >= self.row_group_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.columns[0].is_empty() {
+            return Ok(());
+        }
+        let columns = self
+            .columns
+            .iter_mut()
+            .map(|c| std::mem::replace(c, Vec::with_capacity(self.row_group_size)))
+            .collect();
+        write_chunk(&mut self.writer, &self.schema, self.options, columns)
+    }
+
+    /// Write the remaining rows and the footer; returns the number of samples in the file
+    pub fn finish(mut self, mut metadata: Vec<(String, String)>) -> Result<usize, Box<dyn Error>> {
+        self.flush()?;
+        metadata.push((
+            "bounce.schema_version".to_string(),
+            SCHEMA_VERSION.to_string(),
+        ));
+        self.writer.end(Some(key_values(metadata)))?;
+        Ok(self.n_rows)
+    }
+}
+
+/// Write samples one per row
+pub fn write_samples(
+    path: &str,
+    samples: &[Sample],
+    metadata: Vec<(String, String)>,
+    compression: CompressionOptions,
+) -> Result<(), Box<dyn Error>> {
+    let g = samples.first().map_or(0, |s| s.v.len());
+    let per_sample_grid = samples.first().is_some_and(|s| s.grid.is_some());
+    let mut writer =
+        SampleWriter::create(path, g, per_sample_grid, &[], compression, samples.len())?;
+    for sample in samples {
+        writer.push(sample, &[])?;
+    }
+    writer.finish(metadata)?;
+    Ok(())
+}
+
+/// Write equal-length f64 columns to a parquet file with key-value metadata attached
+pub fn write_parquet(
+    path: &str,
+    columns: Vec<(String, Vec<f64>)>,
+    metadata: Vec<(String, String)>,
+    compression: CompressionOptions,
+) -> Result<(), Box<dyn Error>> {
+    let file = File::create(path)?;
+    let names: Vec<String> = columns.iter().map(|(name, _)| name.clone()).collect();
+    let schema = f64_schema(&names);
+    let options = write_options(compression);
+
+    let mut writer = FileWriter::try_new(file, schema.clone(), options)?;
+    write_chunk(
+        &mut writer,
+        &schema,
+        options,
+        columns.into_iter().map(|(_, v)| v).collect(),
+    )?;
+    writer.end(Some(key_values(metadata)))?;
+
+    Ok(())
+}
+
+fn f64_schema(names: &[String]) -> Schema {
+    let fields: Vec<Field> = names
+        .iter()
+        .map(|name| Field::new(name, DataType::Float64, false))
+        .collect();
+    Schema::from(fields)
+}
+
+fn write_options(compression: CompressionOptions) -> WriteOptions {
+    WriteOptions {
+        write_statistics: true,
+        compression,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    }
+}
+
+/// Write equal-length columns as one row group
+fn write_chunk(
+    writer: &mut FileWriter<File>,
+    schema: &Schema,
+    options: WriteOptions,
+    columns: Vec<Vec<f64>>,
+) -> Result<(), Box<dyn Error>> {
+    let arrays: Vec<Box<dyn Array>> = columns
+        .into_iter()
+        .map(|v| Float64Array::from_vec(v).boxed())
+        .collect();
+    let encodings = (0..arrays.len()).map(|_| vec![Encoding::Plain]).collect();
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(Chunk::new(arrays))].into_iter(),
+        schema,
+        options,
+        encodings,
+    )?;
+    for row_group in row_groups {
+        writer.write(row_group?)?;
+    }
+    Ok(())
+}
+
+fn key_values(metadata: Vec<(String, String)>) -> Vec<KeyValue> {
+    metadata
+        .into_iter()
+        .map(|(key, value)| KeyValue {
+            key,
+            value: Some(value),
+        })
+        .collect()
+}
@@ -0,0 +1,516 @@
+use crate::c1::C1Param;
+use crate::dataset::Dataset;
+use crate::output::{write_parquet, Codec, Sample, SampleWriter};
+use std::error::Error;
+use std::fmt;
+
+/// Principal components of the curves of a dataset
+///
+/// Each sample is the vector of its V values on the shared grid, followed by its V' values
+/// when `derivatives` is set; V' is taken as stored, without rescaling, so it dominates
+/// the variance of the joint vector. The covariance is the population one (divided by the
+/// number of samples) and is diagonalized with cyclic Jacobi rotations.
+#[derive(Debug, Clone)]
+pub struct Pca {
+    pub derivatives: bool,
+    pub grid: Vec<f64>,
+    pub n_samples: usize,
+    /// Mean curve
+    pub mean: Vec<f64>,
+    /// Variance along each component, in descending order
+    pub variances: Vec<f64>,
+    /// Unit principal directions; `components[i]` belongs to `variances[i]`
+    pub components: Vec<Vec<f64>>,
+}
+
+impl Pca {
+    /// Analyse all curves of `dataset`, which must share one grid
+    pub fn fit(dataset: &Dataset, derivatives: bool) -> Result<Self, Box<dyn Error>> {
+        let grid = dataset
+            .grid()
+            .ok_or("PCA needs curves on a shared grid, not per-sample grids")?
+            .to_vec();
+        let samples = dataset.records().map(|r| r.map(|r| r.sample));
+        Self::fit_samples(grid, samples, derivatives)
+    }
+
+    /// Analyse the curves of `samples`, all evaluated on `grid`
+    pub fn fit_samples<I>(
+        grid: Vec<f64>,
+        samples: I,
+        derivatives: bool,
+    ) -> Result<Self, Box<dyn Error>>
+    where
+        I: IntoIterator<Item = Result<Sample, Box<dyn Error>>>,
+    {
+        let n = if derivatives {
+            2 * grid.len()
+        } else {
+            grid.len()
+        };
+
+        // Sums relative to the first curve, which keeps the cancellation in the
+        // covariance small
+        let mut shift: Option<Vec<f64>> = None;
+        let mut sum = vec![0f64; n];
+        let mut products = vec![0f64; n * n];
+        let mut n_samples = 0;
+        for sample in samples {
+            let x = entries(&sample?, derivatives);
+            let x0 = shift.get_or_insert_with(|| x.clone());
+            let d: Vec<f64> = x.iter().zip(x0.iter()).map(|(a, b)| a - b).collect();
+            for i in 0..n {
+                sum[i] += d[i];
+                for j in i..n {
+                    products[i * n + j] += d[i] * d[j];
+                }
+            }
+            n_samples += 1;
+        }
+        let shift = shift.ok_or("PCA needs at least one sample")?;
+
+        let m = n_samples as f64;
+        let mut cov = vec![0f64; n * n];
+        for i in 0..n {
+            for j in i..n {
+                let c = products[i * n + j] / m - sum[i] * sum[j] / (m * m);
+                cov[i * n + j] = c;
+                cov[j * n + i] = c;
+            }
+        }
+        let (eigenvalues, vectors) = symmetric_eigen(cov, n)?;
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| eigenvalues[b].total_cmp(&eigenvalues[a]));
+        let components = order
+            .iter()
+            .map(|&k| {
+                let mut u: Vec<f64> = (0..n).map(|i| vectors[i * n + k]).collect();
+                // Fix the sign: the largest entry is positive
+                let big = u
+                    .iter()
+                    .copied()
+                    .fold(0f64, |a, x| if x.abs() > a.abs() { x } else { a });
+                if big < 0f64 {
+                    u.iter_mut().for_each(|x| *x = -*x);
+                }
+                u
+            })
+            .collect();
+
+        Ok(Self {
+            derivatives,
+            grid,
+            n_samples,
+            mean: shift.iter().zip(&sum).map(|(x0, s)| x0 + s / m).collect(),
+            // Round-off can leave the smallest eigenvalues slightly negative
+            variances: order.iter().map(|&k| eigenvalues[k].max(0f64)).collect(),
+            components,
+        })
+    }
+
+    pub fn total_variance(&self) -> f64 {
+        self.variances.iter().sum()
+    }
+
+    /// Fraction of the total variance along each component
+    pub fn explained_ratio(&self) -> Vec<f64> {
+        let total = self.total_variance();
+        self.variances.iter().map(|v| v / total).collect()
+    }
+
+    /// Smallest number of components explaining at least `fraction` of the variance
+    pub fn n_components_for(&self, fraction: f64) -> usize {
+        let total = self.total_variance();
+        let mut cumulative = 0f64;
+        for (k, v) in self.variances.iter().enumerate() {
+            if cumulative >= fraction * total {
+                return k;
+            }
+            cumulative += v;
+        }
+        self.variances.len()
+    }
+
+    /// RMS error per curve entry of the fitted curves rebuilt from `k` components
+    pub fn rms_error(&self, k: usize) -> f64 {
+        let rest: f64 = self.variances.iter().skip(k).sum();
+        (rest / self.mean.len() as f64).sqrt()
+    }
+
+    /// Coordinates of `sample` along the first `k` components
+    pub fn scores(&self, sample: &Sample, k: usize) -> Vec<f64> {
+        let x = entries(sample, self.derivatives);
+        self.components[..k]
+            .iter()
+            .map(|u| {
+                u.iter()
+                    .zip(&x)
+                    .zip(&self.mean)
+                    .map(|((u, x), m)| u * (x - m))
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Curve entries given the scores of the leading `scores.len()` components
+    pub fn reconstruct(&self, scores: &[f64]) -> Vec<f64> {
+        let mut x = self.mean.clone();
+        for (u, s) in self.components.iter().zip(scores) {
+            for (x, u) in x.iter_mut().zip(u) {
+                *x += s * u;
+            }
+        }
+        x
+    }
+
+    /// `sample` with its curves replaced by their projection on the first `k` components
+    ///
+    /// V' is only rebuilt when it was part of the analysis; otherwise the stored one is kept.
+    pub fn reconstruct_sample(&self, sample: &Sample, k: usize) -> Sample {
+        let x = self.reconstruct(&self.scores(sample, k));
+        let g = self.grid.len();
+        Sample {
+            v: x[..g].to_vec(),
+            dv: if self.derivatives {
+                x[g..].to_vec()
+            } else {
+                sample.dv.clone()
+            },
+            ..sample.clone()
+        }
+    }
+
+    /// Write the grid, the mean and the first `k` components as columns, one row per curve
+    /// entry (V on the grid, then V' when included)
+    pub fn write_components(
+        &self,
+        path: &str,
+        k: usize,
+        metadata: Vec<(String, String)>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut phi = self.grid.clone();
+        if self.derivatives {
+            phi.extend(&self.grid);
+        }
+        let mut columns = vec![
+            ("phi".to_string(), phi),
+            ("mean".to_string(), self.mean.clone()),
+        ];
+        columns.extend((0..k).map(|i| (format!("pc_{}", i), self.components[i].clone())));
+        write_parquet(
+            path,
+            columns,
+            self.metadata(k, metadata),
+            Codec::Uncompressed.options(),
+        )
+    }
+
+    fn metadata(&self, k: usize, mut metadata: Vec<(String, String)>) -> Vec<(String, String)> {
+        let list = |xs: &[f64]| {
+            xs.iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        metadata.extend([
+            (
+                "bounce.pca.entries".to_string(),
+                if self.derivatives { "v,dv" } else { "v" }.to_string(),
+            ),
+            (
+                "bounce.pca.n_samples".to_string(),
+                self.n_samples.to_string(),
+            ),
+            (
+                "bounce.pca.variances".to_string(),
+                list(&self.variances[..k]),
+            ),
+            (
+                "bounce.pca.explained".to_string(),
+                list(&self.explained_ratio()[..k]),
+            ),
+        ]);
+        metadata
+    }
+
+    /// Write the parameters, weight, first `k` scores and labels of every sample of `dataset`
+    pub fn write_scores(
+        &self,
+        dataset: &Dataset,
+        path: &str,
+        k: usize,
+        metadata: Vec<(String, String)>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut names: Vec<String> = C1Param::ALL.iter().map(|p| p.name().to_string()).collect();
+        names.push("weight".to_string());
+        names.extend((0..k).map(|i| format!("pc_{}", i)));
+        names.extend(dataset.labels().iter().cloned());
+        let mut columns = vec![Vec::with_capacity(dataset.len()); names.len()];
+        for record in dataset.records() {
+            let record = record?;
+            let s = &record.sample;
+            let row = s
+                .params
+                .to_array()
+                .into_iter()
+                .chain([s.weight])
+                .chain(self.scores(s, k))
+                .chain(record.labels.iter().copied());
+            for (column, x) in columns.iter_mut().zip(row) {
+                column.push(x);
+            }
+        }
+        let columns = names.into_iter().zip(columns).collect();
+        write_parquet(
+            path,
+            columns,
+            self.metadata(k, metadata),
+            Codec::Uncompressed.options(),
+        )
+    }
+
+    /// Copy `dataset` with every curve rebuilt from `k` components
+    pub fn write_reconstruction(
+        &self,
+        dataset: &Dataset,
+        path: &str,
+        k: usize,
+    ) -> Result<usize, Box<dyn Error>> {
+        let mut writer = SampleWriter::create(
+            path,
+            self.grid.len(),
+            false,
+            dataset.labels(),
+            Codec::Uncompressed.options(),
+            65536,
+        )?;
+        for record in dataset.records() {
+            let record = record?;
+            writer.push(&self.reconstruct_sample(&record.sample, k), &record.labels)?;
+        }
+        let mut metadata: Vec<(String, String)> = dataset
+            .metadata_entries()
+            .iter()
+            .filter(|(key, _)| key != "bounce.schema_version")
+            .cloned()
+            .collect();
+        metadata.push(("bounce.pca.reconstructed".to_string(), k.to_string()));
+        writer.finish(self.metadata(k, metadata))
+    }
+}
+
+impl fmt::Display for Pca {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = if self.derivatives { "V and V'" } else { "V" };
+        writeln!(
+            f,
+            "{} samples, {} entries per curve ({} on {} grid points)",
+            self.n_samples,
+            self.mean.len(),
+            entries,
+            self.grid.len()
+        )?;
+        writeln!(
+            f,
+            "{:>4} {:>12} {:>10} {:>10} {:>12}",
+            "k", "variance", "ratio", "cumul.", "rms error"
+        )?;
+        let ratio = self.explained_ratio();
+        let mut cumulative = 0f64;
+        // Components beyond 99.9999 % of the variance are round-off
+        let n = (self.n_components_for(1f64 - 1e-6) + 1).min(self.variances.len());
+        for (k, (variance, ratio)) in self.variances.iter().zip(ratio).take(n).enumerate() {
+            cumulative += ratio;
+            writeln!(
+                f,
+                "{:>4} {:>12.4e} {:>10.6} {:>10.6} {:>12.4e}",
+                k + 1,
+                variance,
+                ratio,
+                cumulative,
+                self.rms_error(k + 1)
+            )?;
+        }
+        for fraction in [0.9, 0.99, 0.999] {
+            writeln!(
+                f,
+                "{} components explain {} of the variance",
+                self.n_components_for(fraction),
+                fraction
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Sweeps after which `symmetric_eigen` gives up; convergence is quadratic and usually
+/// takes fewer than ten
+const MAX_SWEEPS: usize = 100;
+
+/// Eigenvalues and eigenvectors of the symmetric `n × n` matrix `a` (row-major), by cyclic
+/// Jacobi rotations
+///
+/// Returns the eigenvalues in no particular order and the unit eigenvectors as the columns
+/// of a row-major matrix. Off-diagonal entries too small to change either diagonal entry
+/// they couple are set to zero, so the sweeps end with an exactly diagonal matrix.
+fn symmetric_eigen(mut a: Vec<f64>, n: usize) -> Result<(Vec<f64>, Vec<f64>), String> {
+    let mut v = vec![0f64; n * n];
+    for i in 0..n {
+        v[i * n + i] = 1f64;
+    }
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (app, aqq, apq) = (a[p * n + p], a[q * n + q], a[p * n + q]);
+                if apq == 0f64 {
+                    continue;
+                }
+                let small = 100f64 * apq.abs();
+                if app.abs() + small == app.abs() && aqq.abs() + small == aqq.abs() {
+                    a[p * n + q] = 0f64;
+                    a[q * n + p] = 0f64;
+                    continue;
+                }
+                rotated = true;
+                // Rotation angle that zeroes a_pq, taking the smaller root for stability
+                let theta = (aqq - app) / (2f64 * apq);
+                let t = theta.signum() / (theta.abs() + theta.hypot(1f64));
+                let c = 1f64 / t.hypot(1f64);
+                let s = t * c;
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                a[p * n + q] = 0f64;
+                a[q * n + p] = 0f64;
+                for k in 0..n {
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = c * vkp - s * vkq;
+                    v[k * n + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+        if !rotated {
+            return Ok(((0..n).map(|i| a[i * n + i]).collect(), v));
+        }
+    }
+    Err(format!(
+        "the covariance did not diagonalize in {} Jacobi sweeps",
+        MAX_SWEEPS
+    ))
+}
+
+/// Vector of curve entries of a sample
+fn entries(sample: &Sample, derivatives: bool) -> Vec<f64> {
+    let mut x = sample.v.clone();
+    if derivatives {
+        x.extend(&sample.dv);
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c1::C1Params;
+    use crate::sampler::SplitMix64;
+
+    /// Curves on 12 grid points spanned by three smooth shapes with decreasing amplitudes
+    fn samples() -> Vec<Sample> {
+        let grid: Vec<f64> = (0..12).map(|i| i as f64 / 11f64).collect();
+        let mut rng = SplitMix64::new(5);
+        (0..40)
+            .map(|_| {
+                let (a, b, c) = (rng.next_normal(), rng.next_normal(), rng.next_normal());
+                let curve = |f: &dyn Fn(f64) -> f64| grid.iter().map(|&x| f(x)).collect();
+                Sample {
+                    params: C1Params::new(0.4, 0.8, 0.2, 0.6),
+                    grid: None,
+                    v: curve(&|x| 1f64 + a * (3f64 * x).sin() + 0.3 * b * x * x + 0.05 * c * x),
+                    dv: curve(&|x| 3f64 * a * (3f64 * x).cos() + 0.6 * b * x + 0.05 * c),
+                    weight: 1f64,
+                }
+            })
+            .collect()
+    }
+
+    fn fit(derivatives: bool) -> Pca {
+        let grid = (0..12).map(|i| i as f64 / 11f64).collect();
+        Pca::fit_samples(grid, samples().into_iter().map(Ok), derivatives).unwrap()
+    }
+
+    #[test]
+    fn diagonalizes_known_matrix() {
+        let a = vec![2f64, 1f64, 0f64, 1f64, 2f64, 0f64, 0f64, 0f64, 5f64];
+        let (mut values, v) = symmetric_eigen(a.clone(), 3).unwrap();
+        for k in 0..3 {
+            for i in 0..3 {
+                let av: f64 = (0..3).map(|j| a[i * 3 + j] * v[j * 3 + k]).sum();
+                assert!((av - values[k] * v[i * 3 + k]).abs() < 1e-14);
+            }
+        }
+        values.sort_by(|a, b| a.total_cmp(b));
+        for (value, exact) in values.iter().zip([1f64, 3f64, 5f64]) {
+            assert!((value - exact).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn components_are_sorted_and_orthonormal() {
+        for derivatives in [false, true] {
+            let pca = fit(derivatives);
+            assert!(pca.variances.windows(2).all(|w| w[0] >= w[1]));
+            // Three shapes, so three components carry all of the variance
+            assert!(pca.variances[3] < 1e-12 * pca.variances[0]);
+            assert_eq!(pca.n_components_for(1f64 - 1e-9), 3);
+            for (i, u) in pca.components.iter().enumerate() {
+                for (j, w) in pca.components.iter().enumerate() {
+                    let dot: f64 = u.iter().zip(w).map(|(a, b)| a * b).sum();
+                    let expected = if i == j { 1f64 } else { 0f64 };
+                    assert!((dot - expected).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reconstruction_error_matches_discarded_variance() {
+        let pca = fit(false);
+        let samples = samples();
+        for k in 0..=pca.components.len() {
+            let mse: f64 = samples
+                .iter()
+                .map(|s| {
+                    let r = pca.reconstruct_sample(s, k);
+                    r.v.iter()
+                        .zip(&s.v)
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum::<f64>()
+                })
+                .sum::<f64>()
+                / (samples.len() * pca.mean.len()) as f64;
+            let rms = pca.rms_error(k);
+            assert!(
+                (mse.sqrt() - rms).abs() < 1e-6 * (1f64 + rms),
+                "k = {}: measured {} vs {}",
+                k,
+                mse.sqrt(),
+                rms
+            );
+        }
+        // All components give back the input
+        let k = pca.components.len();
+        for s in &samples {
+            let r = pca.reconstruct_sample(s, k);
+            assert!(r.v.iter().zip(&s.v).all(|(a, b)| (a - b).abs() < 1e-12));
+            assert_eq!(r.dv, s.dv);
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use crate::bounce::{c1_bounce, BounceOptions};
+use crate::dataset::{Dataset, Record};
+use crate::features::{is_finite_curve, FeatureKind, Features};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use rayon::prelude::*;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Line colours, in the order of the `science` matplotlib style
+const PALETTE: [RGBColor; 7] = [
+    RGBColor(0x0C, 0x5D, 0xA5),
+    RGBColor(0x00, 0xB9, 0x45),
+    RGBColor(0xFF, 0x95, 0x00),
+    RGBColor(0xFF, 0x2C, 0x00),
+    RGBColor(0x84, 0x5B, 0x97),
+    RGBColor(0x47, 0x47, 0x47),
+    RGBColor(0x9E, 0x9E, 0x9E),
+];
+
+/// What the `plot` subcommand draws
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PlotKind {
+    /// Overlay of V(φ) for the first samples
+    Potentials,
+    /// Overlay of V'(φ)
+    Derivatives,
+    /// V and V' side by side
+    Both,
+    /// Histogram of a feature over the whole dataset
+    Histogram,
+    /// Bounce profiles φ(r) of the first samples
+    Profiles,
+}
+
+impl fmt::Display for PlotKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlotKind::Potentials => write!(f, "potentials"),
+            PlotKind::Derivatives => write!(f, "derivatives"),
+            PlotKind::Both => write!(f, "both"),
+            PlotKind::Histogram => write!(f, "hist"),
+            PlotKind::Profiles => write!(f, "profiles"),
+        }
+    }
+}
+
+impl FromStr for PlotKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "potentials" => Ok(PlotKind::Potentials),
+            "derivatives" => Ok(PlotKind::Derivatives),
+            "both" => Ok(PlotKind::Both),
+            "hist" => Ok(PlotKind::Histogram),
+            "profiles" => Ok(PlotKind::Profiles),
+            _ => Err(format!(
+                "unknown plot '{}' (expected potentials, derivatives, both, hist or profiles)",
+                s
+            )),
+        }
+    }
+}
+
+/// Contents and size of a figure
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlotSpec {
+    pub kind: PlotKind,
+    /// Curves to overlay (the first samples of the file)
+    pub n_curves: usize,
+    /// Feature of histograms
+    pub feature: FeatureKind,
+    pub bins: usize,
+    /// Size in pixels
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Default for PlotSpec {
+    fn default() -> Self {
+        Self {
+            kind: PlotKind::Potentials,
+            n_curves: 100,
+            feature: FeatureKind::LogBarrier,
+            bins: 40,
+            width: 800,
+            height: 600,
+        }
+    }
+}
+
+/// Counts of a drawn figure
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PlotSummary {
+    /// Curves or histogram entries drawn
+    pub n_drawn: usize,
+    /// Samples left out: non-finite features, or bounces the solver could not find
+    pub n_skipped: usize,
+}
+
+struct Panel {
+    x_label: &'static str,
+    y_label: &'static str,
+    series: Series,
+}
+
+enum Series {
+    Lines(Vec<Vec<(f64, f64)>>),
+    /// Bins `(lo, hi, count)`
+    Bars(Vec<(f64, f64, f64)>),
+}
+
+/// Draw `spec` for the samples of `dataset` into an SVG or PNG file, chosen by the extension of `output`
+pub fn plot(
+    dataset: &Dataset,
+    spec: &PlotSpec,
+    output: &str,
+) -> Result<PlotSummary, Box<dyn Error>> {
+    let first = || -> Result<Vec<Record>, Box<dyn Error>> {
+        dataset.records().take(spec.n_curves).collect()
+    };
+    let (panels, summary) = match spec.kind {
+        PlotKind::Potentials => curves(&first()?, dataset.grid(), &[Channel::V]),
+        PlotKind::Derivatives => curves(&first()?, dataset.grid(), &[Channel::Dv]),
+        PlotKind::Both => curves(&first()?, dataset.grid(), &[Channel::V, Channel::Dv]),
+        PlotKind::Histogram => histogram(dataset, spec.feature, spec.bins)?,
+        PlotKind::Profiles => profiles(&first()?),
+    };
+
+    let size = (spec.width, spec.height);
+    if output.ends_with(".svg") {
+        draw(SVGBackend::new(output, size).into_drawing_area(), &panels)?;
+    } else if output.ends_with(".png") {
+        draw(
+            BitMapBackend::new(output, size).into_drawing_area(),
+            &panels,
+        )?;
+    } else {
+        return Err(format!(
+            "cannot tell the image format of '{}' (expected .svg or .png)",
+            output
+        )
+        .into());
+    }
+    Ok(summary)
+}
+
+#[derive(Copy, Clone)]
+enum Channel {
+    V,
+    Dv,
+}
+
+fn curves(
+    records: &[Record],
+    shared: Option<&[f64]>,
+    channels: &[Channel],
+) -> (Vec<Panel>, PlotSummary) {
+    let panels = channels
+        .iter()
+        .map(|channel| {
+            let lines = records
+                .iter()
+                .map(|r| {
+                    let phi = r.sample.grid.as_deref().or(shared).unwrap_or(&[]);
+                    let y = match channel {
+                        Channel::V => &r.sample.v,
+                        Channel::Dv => &r.sample.dv,
+                    };
+                    phi.iter().copied().zip(y.iter().copied()).collect()
+                })
+                .collect();
+            Panel {
+                x_label: "φ",
+                y_label: match channel {
+                    Channel::V => "V(φ)",
+                    Channel::Dv => "V'(φ)",
+                },
+                series: Series::Lines(lines),
+            }
+        })
+        .collect();
+    let summary = PlotSummary {
+        n_drawn: records.len(),
+        n_skipped: 0,
+    };
+    (panels, summary)
+}
+
+fn histogram(
+    dataset: &Dataset,
+    feature: FeatureKind,
+    bins: usize,
+) -> Result<(Vec<Panel>, PlotSummary), Box<dyn Error>> {
+    let mut values = Vec::with_capacity(dataset.len());
+    let mut n_skipped = 0;
+    for record in dataset.records() {
+        let s = record?.sample;
+        let phi = s.grid.as_deref().or(dataset.grid()).unwrap_or(&[]);
+        let x = feature.value(&Features::new(phi, &s.v));
+        if is_finite_curve(&s.v) && x.is_finite() {
+            values.push(x);
+        } else {
+            n_skipped += 1;
+        }
+    }
+    let lo = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let hi = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let width = if hi > lo {
+        (hi - lo) / bins as f64
+    } else {
+        1f64
+    };
+    let mut counts = vec![0usize; bins];
+    for x in &values {
+        counts[(((x - lo) / width) as usize).min(bins - 1)] += 1;
+    }
+    let bars = counts
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| (lo + i as f64 * width, lo + (i + 1) as f64 * width, c as f64))
+        .collect();
+    let panel = Panel {
+        x_label: feature_label(feature),
+        y_label: "samples",
+        series: Series::Bars(bars),
+    };
+    let summary = PlotSummary {
+        n_drawn: values.len(),
+        n_skipped,
+    };
+    Ok((vec![panel], summary))
+}
+
+fn feature_label(feature: FeatureKind) -> &'static str {
+    match feature {
+        FeatureKind::LogBarrier => "log10 V_max",
+        FeatureKind::DeltaRatio => "ΔV / V_max",
+        FeatureKind::ThinWall => "thin-wall parameter",
+    }
+}
+
+fn profiles(records: &[Record]) -> (Vec<Panel>, PlotSummary) {
+    let bounces: Vec<_> = records
+        .par_iter()
+        .map(|r| c1_bounce(&r.sample.params, BounceOptions::default()))
+        .collect();
+    // The tails at the false vacuum would stretch the radial axis
+    let lines: Vec<Vec<(f64, f64)>> = bounces
+        .iter()
+        .flatten()
+        .map(|b| {
+            let phi_false = b.phi[b.phi.len() - 1];
+            let cut = 1e-3 * (b.phi_release - phi_false).abs();
+            let n = b
+                .phi
+                .iter()
+                .rposition(|x| (x - phi_false).abs() > cut)
+                .map_or(0, |i| i + 1);
+            b.r.iter()
+                .copied()
+                .zip(b.phi.iter().copied())
+                .take(n + 1)
+                .collect()
+        })
+        .collect();
+    let summary = PlotSummary {
+        n_drawn: lines.len(),
+        n_skipped: records.len() - lines.len(),
+    };
+    let panel = Panel {
+        x_label: "r",
+        y_label: "φ(r)",
+        series: Series::Lines(lines),
+    };
+    (vec![panel], summary)
+}
+
+/// Tight bounds of the data of a panel
+fn bounds(series: &Series) -> ((f64, f64), (f64, f64)) {
+    let points: Vec<(f64, f64)> = match series {
+        Series::Lines(lines) => lines.iter().flatten().copied().collect(),
+        Series::Bars(bars) => bars
+            .iter()
+            .flat_map(|&(lo, hi, c)| [(lo, 0f64), (hi, c)])
+            .collect(),
+    };
+    let range = |values: &mut dyn Iterator<Item = f64>| {
+        let (lo, hi) = values
+            .filter(|x| x.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), x| {
+                (lo.min(x), hi.max(x))
+            });
+        if lo < hi {
+            (lo, hi)
+        } else if lo == hi {
+            (lo - 0.5, hi + 0.5)
+        } else {
+            (0f64, 1f64)
+        }
+    };
+    (
+        range(&mut points.iter().map(|p| p.0)),
+        range(&mut points.iter().map(|p| p.1)),
+    )
+}
+
+/// Tick label without float noise (`0.2` rather than `0.20000000000000004`, `0` rather than `-0.0`)
+fn tick(x: &f64) -> String {
+    let x = (x * 1e6).round() / 1e6;
+    format!("{}", if x == 0f64 { 0f64 } else { x })
+}
+
+fn draw<DB: DrawingBackend>(
+    root: DrawingArea<DB, Shift>,
+    panels: &[Panel],
+) -> Result<(), Box<dyn Error>>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
+    for (area, panel) in root.split_evenly((1, panels.len())).iter().zip(panels) {
+        let ((x_lo, x_hi), (y_lo, y_hi)) = bounds(&panel.series);
+        let mut chart = ChartBuilder::on(area)
+            .margin(15)
+            .x_label_area_size(45)
+            .y_label_area_size(65)
+            .build_cartesian_2d(x_lo..x_hi, y_lo..y_hi)?;
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .x_desc(panel.x_label)
+            .y_desc(panel.y_label)
+            .x_label_formatter(&tick)
+            .y_label_formatter(&tick)
+            .label_style(("sans-serif", 16))
+            .axis_desc_style(("sans-serif", 18))
+            .draw()?;
+        match &panel.series {
+            Series::Lines(lines) => {
+                for (i, line) in lines.iter().enumerate() {
+                    let style = PALETTE[i % PALETTE.len()].mix(0.7).stroke_width(2);
+                    chart.draw_series(LineSeries::new(line.iter().copied(), style))?;
+                }
+            }
+            Series::Bars(bars) => {
+                chart.draw_series(bars.iter().map(|&(lo, hi, c)| {
+                    Rectangle::new([(lo, 0f64), (hi, c)], PALETTE[0].mix(0.7).filled())
+                }))?;
+            }
+        }
+    }
+    root.present()?;
+    Ok(())
+}
@@ -0,0 +1,317 @@
+use crate::c1::{c1_deriv_expr, c1_potential_expr, C1Params};
+use peroxide::fuga::PowOps;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Double-double number `hi + lo` with `|lo| <= ulp(hi) / 2` (~32 significant digits)
+///
+/// Enough to evaluate the expanded C1 expressions, whose hundreds of large integer
+/// coefficients cancel heavily, without the f64 round-off piling up.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    pub hi: f64,
+    pub lo: f64,
+}
+
+impl DoubleDouble {
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = quick_two_sum(hi, lo);
+        Self { hi, lo }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.hi + self.lo
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.hi < 0f64 {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    fn recip(&self) -> Self {
+        Self::from(1f64) / *self
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(x: f64) -> Self {
+        Self { hi: x, lo: 0f64 }
+    }
+}
+
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (s, e) = two_sum(self.hi, rhs.hi);
+        let (t, f) = two_sum(self.lo, rhs.lo);
+        let (s, e) = quick_two_sum(s, e + t);
+        Self::new(s, e + f)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (p, e) = two_prod(self.hi, rhs.hi);
+        Self::new(p, e + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let q1 = self.hi / rhs.hi;
+        let r = self - rhs * Self::from(q1);
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs * Self::from(q2);
+        let q3 = r.hi / rhs.hi;
+        Self::new(q1, q2) + Self::from(q3)
+    }
+}
+
+impl Mul<DoubleDouble> for f64 {
+    type Output = DoubleDouble;
+
+    fn mul(self, rhs: DoubleDouble) -> DoubleDouble {
+        let (p, e) = two_prod(self, rhs.hi);
+        DoubleDouble::new(p, e + self * rhs.lo)
+    }
+}
+
+impl PowOps for DoubleDouble {
+    type Float = f64;
+
+    fn powi(&self, n: i32) -> Self {
+        let mut base = *self;
+        let mut k = n.unsigned_abs();
+        let mut z = Self::from(1f64);
+        while k > 0 {
+            if k & 1 == 1 {
+                z = z * base;
+            }
+            base = base * base;
+            k >>= 1;
+        }
+        if n < 0 {
+            z.recip()
+        } else {
+            z
+        }
+    }
+
+    /// Only f64 accurate
+    fn powf(&self, f: f64) -> Self {
+        Self::from(self.to_f64().powf(f))
+    }
+
+    /// Only f64 accurate
+    fn pow(&self, y: Self) -> Self {
+        Self::from(self.to_f64().powf(y.to_f64()))
+    }
+
+    fn sqrt(&self) -> Self {
+        if self.hi <= 0f64 {
+            return Self::from(self.hi.sqrt());
+        }
+        // One Newton step from the f64 root doubles the number of correct digits
+        let x = Self::from(self.hi.sqrt());
+        x + (*self - x * x) / (2f64 * x)
+    }
+}
+
+pub fn c1_potential_dd(
+    phi_0: f64,
+    phi_1n: f64,
+    phi_1p: f64,
+    phi_2: f64,
+) -> Box<dyn Fn(f64) -> DoubleDouble> {
+    let ps = [phi_0, phi_1n, phi_1p, phi_2].map(DoubleDouble::from);
+    Box::new(move |phi: f64| c1_potential_expr(phi.into(), ps[0], ps[1], ps[2], ps[3]))
+}
+
+pub fn c1_deriv_dd(
+    phi_0: f64,
+    phi_1n: f64,
+    phi_1p: f64,
+    phi_2: f64,
+) -> Box<dyn Fn(f64) -> DoubleDouble> {
+    let ps = [phi_0, phi_1n, phi_1p, phi_2].map(DoubleDouble::from);
+    Box::new(move |phi: f64| c1_deriv_expr(phi.into(), ps[0], ps[1], ps[2], ps[3]))
+}
+
+/// Estimated round-off of the f64 evaluation of V(φ): `|V_f64 - V_dd|`
+pub fn c1_rounding_error(
+    phi_0: f64,
+    phi_1n: f64,
+    phi_1p: f64,
+    phi_2: f64,
+) -> Box<dyn Fn(f64) -> f64> {
+    let f = c1_potential_dd(phi_0, phi_1n, phi_1p, phi_2);
+    let ps = [phi_0, phi_1n, phi_1p, phi_2];
+    Box::new(move |phi: f64| {
+        let v = c1_potential_expr(phi, ps[0], ps[1], ps[2], ps[3]);
+        (DoubleDouble::from(v) - f(phi)).abs().to_f64()
+    })
+}
+
+/// Relative round-off above which a stored curve counts as numerically unreliable
+///
+/// Curves of the batch evaluator are typically good to ~1e-13, the closed forms to ~1e-10.
+pub const RELIABLE_TOL: f64 = 1e-8;
+
+/// Round-off diagnostics of a whole f64 curve against its double-double reference
+#[derive(Debug, Copy, Clone)]
+pub struct PrecisionReport {
+    /// Largest `|V_f64 - V_dd|` over the grid
+    pub max_abs_err: f64,
+    /// Same for V'
+    pub max_abs_err_deriv: f64,
+    /// `max_abs_err / max|V_dd|`, the error relative to the scale of the curve
+    pub rel_err: f64,
+    /// `max_abs_err_deriv / max|V'_dd|`
+    pub rel_err_deriv: f64,
+}
+
+impl PrecisionReport {
+    /// Whether both relative errors stay below `tol`
+    pub fn is_reliable(&self, tol: f64) -> bool {
+        self.rel_err < tol && self.rel_err_deriv < tol
+    }
+}
+
+/// Round-off of the closed-form f64 evaluation of `p` on `phi`
+pub fn precision_report(p: &C1Params, phi: &[f64]) -> PrecisionReport {
+    let (v, dv) = (p.potential(), p.deriv());
+    let values: Vec<f64> = phi.iter().map(|&x| v(x)).collect();
+    let derivs: Vec<f64> = phi.iter().map(|&x| dv(x)).collect();
+    curve_precision(p, phi, &values, &derivs)
+}
+
+/// `|stored - reference|`, infinite for a non-finite stored value since `f64::max` would skip a NaN
+fn stored_err(stored: f64, reference: DoubleDouble) -> f64 {
+    if stored.is_finite() {
+        (DoubleDouble::from(stored) - reference).abs().to_f64()
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// Round-off of a stored curve `v`, `dv` on `phi` against the double-double reference of `p`
+pub fn curve_precision(p: &C1Params, phi: &[f64], v: &[f64], dv: &[f64]) -> PrecisionReport {
+    let v_dd = c1_potential_dd(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+    let dv_dd = c1_deriv_dd(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+
+    let mut max_abs_err = 0f64;
+    let mut max_abs_err_deriv = 0f64;
+    let mut scale = 0f64;
+    let mut scale_deriv = 0f64;
+    for ((&x, &y_f64), &dy_f64) in phi.iter().zip(v).zip(dv) {
+        let (y, dy) = (v_dd(x), dv_dd(x));
+        max_abs_err = max_abs_err.max(stored_err(y_f64, y));
+        max_abs_err_deriv = max_abs_err_deriv.max(stored_err(dy_f64, dy));
+        scale = scale.max(y.abs().to_f64());
+        scale_deriv = scale_deriv.max(dy.abs().to_f64());
+    }
+
+    PrecisionReport {
+        max_abs_err,
+        max_abs_err_deriv,
+        rel_err: max_abs_err / scale,
+        rel_err_deriv: max_abs_err_deriv / scale_deriv,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_double_keeps_the_low_word() {
+        let one = DoubleDouble::from(1f64);
+        let tiny = DoubleDouble::from(1e-20);
+        assert_eq!((one + tiny - one).to_f64(), 1e-20);
+
+        // (1 + 2^-30)^2 = 1 + 2^-29 + 2^-60, which needs more than 53 bits
+        let x = DoubleDouble::from(1f64 + 2f64.powi(-30));
+        let sq = x * x;
+        assert_eq!(sq.hi, 1f64 + 2f64.powi(-29));
+        assert_eq!(sq.lo, 2f64.powi(-60));
+
+        let third = one / DoubleDouble::from(3f64);
+        assert!((third * DoubleDouble::from(3f64) - one).abs().to_f64() < 1e-31);
+        let root = DoubleDouble::from(2f64).sqrt();
+        assert!((root * root - DoubleDouble::from(2f64)).abs().to_f64() < 1e-31);
+        assert_eq!(DoubleDouble::from(3f64).powi(-2).to_f64(), 1f64 / 9f64);
+    }
+
+    #[test]
+    fn curve_precision_flags_bad_values() {
+        let p = C1Params::new(0.3, 0.8, 0.15, 0.5);
+        let phi: Vec<f64> = (0..21).map(|i| i as f64 / 20f64).collect();
+        let v_dd = c1_potential_dd(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+        let dv_dd = c1_deriv_dd(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+        let mut v: Vec<f64> = phi.iter().map(|&x| v_dd(x).to_f64()).collect();
+        let mut dv: Vec<f64> = phi.iter().map(|&x| dv_dd(x).to_f64()).collect();
+
+        let exact = curve_precision(&p, &phi, &v, &dv);
+        assert!(
+            exact.rel_err < 1e-15 && exact.rel_err_deriv < 1e-15,
+            "{:?}",
+            exact
+        );
+        assert!(exact.is_reliable(RELIABLE_TOL));
+
+        v[10] += 1e-3;
+        let off = curve_precision(&p, &phi, &v, &dv);
+        assert!((off.max_abs_err - 1e-3).abs() < 1e-12, "{:?}", off);
+        assert!(!off.is_reliable(RELIABLE_TOL));
+        v[10] -= 1e-3;
+
+        for bad in [f64::NAN, f64::INFINITY] {
+            dv[0] = bad;
+            let report = curve_precision(&p, &phi, &v, &dv);
+            assert_eq!(report.max_abs_err_deriv, f64::INFINITY);
+            assert!(!report.is_reliable(RELIABLE_TOL));
+        }
+    }
+}
@@ -0,0 +1,404 @@
+use crate::c1::C1Params;
+use std::fmt;
+use std::str::FromStr;
+
+/// Design of candidate points in the unit hypercube [0, 1)^5
+///
+/// The first four coordinates go through the sort-and-swap construction of `params_from_unit`,
+/// the fifth decides the branch. Points are addressed by index so that chunks can be drawn
+/// independently (and reproducibly) from any thread.
+pub trait Sampler: Sync {
+    fn unit(&self, index: u64) -> [f64; 5];
+
+    fn draw(&self, start: u64, n: usize) -> Vec<[f64; 5]> {
+        (start..start + n as u64).map(|i| self.unit(i)).collect()
+    }
+
+    /// Points at arbitrary indices, e.g. the candidates kept by a targeted run
+    fn draw_at(&self, indices: &[u64]) -> Vec<[f64; 5]> {
+        indices.iter().map(|&i| self.unit(i)).collect()
+    }
+}
+
+/// Sort the first four coordinates and swap phi_0 and phi_2 if the fifth is below 1/2
+pub fn params_from_unit(u: [f64; 5]) -> C1Params {
+    let mut ps = [u[0], u[1], u[2], u[3]];
+    ps.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    C1Params::from_sorted(ps, u[4] < 0.5)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SamplerKind {
+    /// Independent uniforms
+    Random,
+    /// Sobol sequence (Joe–Kuo direction numbers) with a random digital shift
+    Sobol,
+    /// Halton sequence in bases 2, 3, 5, 7, 11 with a random shift modulo 1
+    Halton,
+    /// Latin hypercube; every block of `block` consecutive points is its own design
+    LatinHypercube { block: usize },
+}
+
+impl fmt::Display for SamplerKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SamplerKind::Random => write!(f, "random"),
+            SamplerKind::Sobol => write!(f, "sobol"),
+            SamplerKind::Halton => write!(f, "halton"),
+            SamplerKind::LatinHypercube { block } => write!(f, "lhs:{}", block),
+        }
+    }
+}
+
+impl FromStr for SamplerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        match (parts[0], parts.len()) {
+            ("random", 1) => Ok(SamplerKind::Random),
+            ("sobol", 1) => Ok(SamplerKind::Sobol),
+            ("halton", 1) => Ok(SamplerKind::Halton),
+            ("lhs", 1) => Ok(SamplerKind::LatinHypercube { block: 1024 }),
+            ("lhs", 2) => match parts[1].parse::<usize>() {
+                Ok(block) if block > 0 => Ok(SamplerKind::LatinHypercube { block }),
+                _ => Err(format!("invalid block size in '{}'", s)),
+            },
+            _ => Err(format!(
+                "unknown sampler '{}' (expected random, sobol, halton or lhs[:block])",
+                s
+            )),
+        }
+    }
+}
+
+impl SamplerKind {
+    /// Build the sampler; with `stratify_branch` even indices take the plain and odd indices
+    /// the swapped branch, so both are represented in equal proportion
+    pub fn build(&self, seed: u64, stratify_branch: bool) -> Box<dyn Sampler> {
+        let inner: Box<dyn Sampler> = match *self {
+            SamplerKind::Random => Box::new(RandomSampler { seed }),
+            SamplerKind::Sobol => Box::new(SobolSampler::new(seed)),
+            SamplerKind::Halton => Box::new(HaltonSampler::new(seed)),
+            SamplerKind::LatinHypercube { block } => Box::new(LatinHypercube { block, seed }),
+        };
+        if stratify_branch {
+            Box::new(BranchStratified { inner })
+        } else {
+            inner
+        }
+    }
+}
+
+// =============================================================================
+// Counter-based random numbers
+// =============================================================================
+
+/// SplitMix64 generator; also used to derive independent streams from (seed, index)
+#[derive(Debug, Copy, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Stream for the `index`-th item of a run seeded with `seed`
+    pub fn stream(seed: u64, index: u64) -> Self {
+        let mut s = Self::new(seed ^ 0x6a09_e667_f3bc_c909);
+        s.state = s.next_u64() ^ index.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        s
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1) with 53 random bits
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform integer in [0, n)
+    pub fn next_below(&mut self, n: u64) -> u64 {
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Standard normal deviate (Box–Muller)
+    pub fn next_normal(&mut self) -> f64 {
+        let u1 = 1f64 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2f64 * u1.ln()).sqrt() * (2f64 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+struct RandomSampler {
+    seed: u64,
+}
+
+impl Sampler for RandomSampler {
+    fn unit(&self, index: u64) -> [f64; 5] {
+        let mut rng = SplitMix64::stream(self.seed, index);
+        std::array::from_fn(|_| rng.next_f64())
+    }
+}
+
+// =============================================================================
+// Low-discrepancy sequences
+// =============================================================================
+
+const SOBOL_BITS: usize = 32;
+
+/// (degree s, coefficients a, initial m_1..m_s) for dimensions 2..5 (Joe & Kuo, new-joe-kuo-6.21201)
+const SOBOL_PARAMS: [(usize, u32, [u32; 3]); 4] = [
+    (1, 0, [1, 0, 0]),
+    (2, 1, [1, 3, 0]),
+    (3, 1, [1, 3, 1]),
+    (3, 2, [1, 1, 1]),
+];
+
+struct SobolSampler {
+    directions: [[u32; SOBOL_BITS]; 5],
+    shift: [u32; 5],
+}
+
+impl SobolSampler {
+    fn new(seed: u64) -> Self {
+        let mut directions = [[0u32; SOBOL_BITS]; 5];
+        for (k, v) in directions[0].iter_mut().enumerate() {
+            *v = 1 << (SOBOL_BITS - 1 - k);
+        }
+        for (d, &(s, a, m)) in SOBOL_PARAMS.iter().enumerate() {
+            let v = &mut directions[d + 1];
+            for k in 0..s {
+                v[k] = m[k] << (SOBOL_BITS - 1 - k);
+            }
+            for k in s..SOBOL_BITS {
+                let mut x = v[k - s] ^ (v[k - s] >> s);
+                for j in 1..s {
+                    if (a >> (s - 1 - j)) & 1 == 1 {
+                        x ^= v[k - j];
+                    }
+                }
+                v[k] = x;
+            }
+        }
+        let mut rng = SplitMix64::new(seed);
+        let shift = std::array::from_fn(|_| (rng.next_u64() >> 32) as u32);
+        Self { directions, shift }
+    }
+}
+
+impl Sampler for SobolSampler {
+    /// Skips the origin, which would give a degenerate potential
+    fn unit(&self, index: u64) -> [f64; 5] {
+        let n = index + 1;
+        let gray = n ^ (n >> 1);
+        std::array::from_fn(|d| {
+            let mut x = self.shift[d];
+            for (k, v) in self.directions[d].iter().enumerate() {
+                if (gray >> k) & 1 == 1 {
+                    x ^= v;
+                }
+            }
+            x as f64 / (1u64 << SOBOL_BITS) as f64
+        })
+    }
+}
+
+const HALTON_BASES: [u64; 5] = [2, 3, 5, 7, 11];
+
+struct HaltonSampler {
+    shift: [f64; 5],
+}
+
+impl HaltonSampler {
+    fn new(seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        Self {
+            shift: std::array::from_fn(|_| rng.next_f64()),
+        }
+    }
+}
+
+impl Sampler for HaltonSampler {
+    fn unit(&self, index: u64) -> [f64; 5] {
+        std::array::from_fn(|d| {
+            let x = radical_inverse(index + 1, HALTON_BASES[d]) + self.shift[d];
+            x - x.floor()
+        })
+    }
+}
+
+fn radical_inverse(mut n: u64, base: u64) -> f64 {
+    let inv = 1f64 / base as f64;
+    let mut f = inv;
+    let mut x = 0f64;
+    while n > 0 {
+        x += (n % base) as f64 * f;
+        n /= base;
+        f *= inv;
+    }
+    x
+}
+
+// =============================================================================
+// Stratified designs
+// =============================================================================
+
+struct LatinHypercube {
+    block: usize,
+    seed: u64,
+}
+
+impl LatinHypercube {
+    /// Strata of each dimension for one block: independent random permutations of 0..block
+    fn permutations(&self, block_index: u64) -> Vec<[u32; 5]> {
+        let mut rng = SplitMix64::stream(self.seed, block_index);
+        let mut perms = vec![[0u32; 5]; self.block];
+        for d in 0..5 {
+            for (i, p) in perms.iter_mut().enumerate() {
+                p[d] = i as u32;
+            }
+            for i in (1..self.block).rev() {
+                let j = rng.next_below(i as u64 + 1) as usize;
+                let tmp = perms[i][d];
+                perms[i][d] = perms[j][d];
+                perms[j][d] = tmp;
+            }
+        }
+        perms
+    }
+
+    fn point(&self, perm: &[u32; 5], index: u64) -> [f64; 5] {
+        let mut rng = SplitMix64::stream(self.seed ^ 0x5bd1_e995, index);
+        let b = self.block as f64;
+        std::array::from_fn(|d| (perm[d] as f64 + rng.next_f64()) / b)
+    }
+}
+
+impl Sampler for LatinHypercube {
+    fn unit(&self, index: u64) -> [f64; 5] {
+        let block = self.block as u64;
+        let perms = self.permutations(index / block);
+        self.point(&perms[(index % block) as usize], index)
+    }
+
+    fn draw(&self, start: u64, n: usize) -> Vec<[f64; 5]> {
+        let indices: Vec<u64> = (start..start + n as u64).collect();
+        self.draw_at(&indices)
+    }
+
+    /// Builds the permutations of a block once for each run of indices in it, so increasing
+    /// indices cost one set per block
+    fn draw_at(&self, indices: &[u64]) -> Vec<[f64; 5]> {
+        let block = self.block as u64;
+        let mut out = Vec::with_capacity(indices.len());
+        let mut cached: Option<(u64, Vec<[u32; 5]>)> = None;
+        for &i in indices {
+            let b = i / block;
+            if cached.as_ref().is_none_or(|(cb, _)| *cb != b) {
+                cached = Some((b, self.permutations(b)));
+            }
+            let perms = &cached.as_ref().unwrap().1;
+            out.push(self.point(&perms[(i % block) as usize], i));
+        }
+        out
+    }
+}
+
+struct BranchStratified {
+    inner: Box<dyn Sampler>,
+}
+
+impl BranchStratified {
+    fn assign(u: &mut [f64; 5], index: u64) {
+        u[4] = if index.is_multiple_of(2) { 0.75 } else { 0.25 };
+    }
+}
+
+impl Sampler for BranchStratified {
+    fn unit(&self, index: u64) -> [f64; 5] {
+        let mut u = self.inner.unit(index);
+        Self::assign(&mut u, index);
+        u
+    }
+
+    fn draw(&self, start: u64, n: usize) -> Vec<[f64; 5]> {
+        let mut us = self.inner.draw(start, n);
+        for (i, u) in us.iter_mut().enumerate() {
+            Self::assign(u, start + i as u64);
+        }
+        us
+    }
+
+    fn draw_at(&self, indices: &[u64]) -> Vec<[f64; 5]> {
+        let mut us = self.inner.draw_at(indices);
+        for (u, &i) in us.iter_mut().zip(indices) {
+            Self::assign(u, i);
+        }
+        us
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sobol_starts_with_the_unshifted_sequence() {
+        let mut sobol = SobolSampler::new(0);
+        sobol.shift = [0; 5];
+        let expected = [
+            [0.5, 0.5, 0.5, 0.5, 0.5],
+            [0.75, 0.25, 0.25, 0.25, 0.75],
+            [0.25, 0.75, 0.75, 0.75, 0.25],
+        ];
+        assert_eq!(sobol.draw(0, 3), expected);
+    }
+
+    #[test]
+    fn radical_inverse_reflects_digits() {
+        assert_eq!(radical_inverse(1, 2), 0.5);
+        assert_eq!(radical_inverse(3, 2), 0.75);
+        assert_eq!(radical_inverse(6, 2), 0.375);
+        assert!((radical_inverse(5, 3) - 7.0 / 9.0).abs() < 1e-15);
+        assert!((radical_inverse(12, 11) - 1.0 / 11.0 - 1.0 / 121.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn latin_hypercube_fills_each_stratum_once_per_block() {
+        let lhs = LatinHypercube { block: 8, seed: 3 };
+        for b in 0..3 {
+            let us = lhs.draw(8 * b, 8);
+            for d in 0..5 {
+                let mut strata: Vec<usize> = us.iter().map(|u| (u[d] * 8.0) as usize).collect();
+                strata.sort_unstable();
+                assert_eq!(
+                    strata,
+                    (0..8).collect::<Vec<_>>(),
+                    "block {b}, dimension {d}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn draw_at_matches_unit() {
+        let indices = [0, 1, 7, 8, 9, 30, 31, 2, 64];
+        for kind in ["random", "sobol", "halton", "lhs:8"] {
+            let kind: SamplerKind = kind.parse().unwrap();
+            for stratify in [false, true] {
+                let sampler = kind.build(11, stratify);
+                let expected: Vec<_> = indices.iter().map(|&i| sampler.unit(i)).collect();
+                assert_eq!(sampler.draw_at(&indices), expected, "{kind}");
+            }
+        }
+    }
+}
@@ -0,0 +1,326 @@
+use crate::batch::BatchEvaluator;
+use crate::bounce::{c1_bounce, BounceOptions};
+use crate::c1::{C1Param, C1Params};
+use crate::features::{Acceptance, Features};
+use crate::output::write_parquet;
+use peroxide::fuga::*;
+use rayon::prelude::*;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Regular sweep of two C1 parameters with the other two held fixed
+///
+/// Fixed values are written `name=value`, swept axes `name=lo:hi:n`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanSpec {
+    pub fixed: [(C1Param, f64); 2],
+    pub axes: [Axis; 2],
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Axis {
+    pub param: C1Param,
+    pub lo: f64,
+    pub hi: f64,
+    pub n: usize,
+}
+
+impl Axis {
+    pub fn values(&self) -> Vec<f64> {
+        linspace(self.lo, self.hi, self.n)
+    }
+}
+
+impl fmt::Display for Axis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}:{}:{}", self.param, self.lo, self.hi, self.n)
+    }
+}
+
+impl FromStr for Axis {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, range) = s
+            .split_once('=')
+            .ok_or_else(|| format!("invalid axis '{}' (expected name=lo:hi:n)", s))?;
+        let parts: Vec<&str> = range.split(':').collect();
+        if parts.len() != 3 {
+            return Err(format!("invalid axis '{}' (expected name=lo:hi:n)", s));
+        }
+        let num = |x: &str| {
+            x.parse::<f64>()
+                .map_err(|e| format!("invalid number '{}' in axis: {}", x, e))
+        };
+        let n = parts[2]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid point count in axis '{}': {}", s, e))?;
+        if n < 2 {
+            return Err(format!("axis '{}' needs at least 2 points", s));
+        }
+        Ok(Axis {
+            param: name.parse()?,
+            lo: num(parts[0])?,
+            hi: num(parts[1])?,
+            n,
+        })
+    }
+}
+
+/// Parse a fixed value `name=value`
+pub fn parse_fixed(s: &str) -> Result<(C1Param, f64), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid fixed parameter '{}' (expected name=value)", s))?;
+    let value = value
+        .parse::<f64>()
+        .map_err(|e| format!("invalid value in '{}': {}", s, e))?;
+    Ok((name.parse()?, value))
+}
+
+impl ScanSpec {
+    /// Check that the four parameters are each named exactly once
+    pub fn new(fixed: [(C1Param, f64); 2], axes: [Axis; 2]) -> Result<Self, String> {
+        let mut names: Vec<C1Param> = fixed.iter().map(|f| f.0).collect();
+        names.extend(axes.iter().map(|a| a.param));
+        for p in C1Param::ALL {
+            if names.iter().filter(|&&q| q == p).count() != 1 {
+                return Err(format!("{} must be either fixed or swept exactly once", p));
+            }
+        }
+        Ok(Self { fixed, axes })
+    }
+
+    /// Parameter sets of the scan, the first axis varying slowest
+    pub fn points(&self) -> Vec<C1Params> {
+        let (xs, ys) = (self.axes[0].values(), self.axes[1].values());
+        let mut ps = [0f64; 4];
+        for (p, x) in self.fixed {
+            ps[p.index()] = x;
+        }
+        let mut points = Vec::with_capacity(xs.len() * ys.len());
+        for &x in &xs {
+            for &y in &ys {
+                ps[self.axes[0].param.index()] = x;
+                ps[self.axes[1].param.index()] = y;
+                points.push(C1Params::from_array(ps));
+            }
+        }
+        points
+    }
+}
+
+/// Outcome at one point of the scan
+#[derive(Debug, Copy, Clone)]
+pub struct ScanRow {
+    pub params: C1Params,
+    /// Whether the ordering constraints of the family hold
+    pub ordered: bool,
+    /// Ordered and passing the acceptance criteria of the generator
+    pub accepted: bool,
+    pub features: Features,
+    /// Bounce action when requested; NaN where the solver finds no bounce
+    pub action: Option<f64>,
+}
+
+/// Evaluate every scan point; points outside the family (where the defining conditions
+/// are degenerate) are not evaluated and get NaN features
+///
+/// With `action` set, the bounce action of every ordered point is solved for as well.
+pub fn run_scan(
+    spec: &ScanSpec,
+    phi: &[f64],
+    acceptance: Acceptance,
+    action: Option<BounceOptions>,
+) -> Vec<ScanRow> {
+    let evaluator = BatchEvaluator::new(phi);
+    let undefined = Features {
+        v_max: f64::NAN,
+        phi_top: f64::NAN,
+        delta_v: f64::NAN,
+        delta_ratio: f64::NAN,
+        thin_wall: f64::NAN,
+        n_maxima: 0,
+        n_minima: 0,
+    };
+    spec.points()
+        .par_chunks(1024)
+        .flat_map_iter(|chunk| {
+            let ordered: Vec<C1Params> = chunk.iter().copied().filter(|p| p.is_ordered()).collect();
+            let out = (!ordered.is_empty()).then(|| evaluator.eval(&ordered));
+            let mut i = 0;
+            let mut rows = Vec::with_capacity(chunk.len());
+            for &p in chunk {
+                if !p.is_ordered() {
+                    rows.push(ScanRow {
+                        params: p,
+                        ordered: false,
+                        accepted: false,
+                        features: undefined,
+                        action: action.map(|_| f64::NAN),
+                    });
+                    continue;
+                }
+                let v = out.as_ref().unwrap().v.row(i);
+                i += 1;
+                rows.push(ScanRow {
+                    params: p,
+                    ordered: true,
+                    accepted: acceptance.accepts(&v),
+                    features: Features::new(phi, &v),
+                    action: None,
+                });
+            }
+            rows
+        })
+        .collect::<Vec<ScanRow>>()
+        .into_par_iter()
+        .map(|mut row| {
+            if let Some(opts) = action.filter(|_| row.ordered) {
+                row.action = Some(c1_bounce(&row.params, opts).map_or(f64::NAN, |b| b.action));
+            }
+            row
+        })
+        .collect()
+}
+
+/// Long-format table, one row per scan point (flags stored as 0/1), with an `action` column
+/// when the actions were solved for
+pub fn write_scan(
+    path: &str,
+    rows: &[ScanRow],
+    metadata: Vec<(String, String)>,
+) -> Result<(), Box<dyn Error>> {
+    let flag = |b: bool| if b { 1f64 } else { 0f64 };
+    let mut columns: Vec<(String, Vec<f64>)> = C1Param::ALL
+        .iter()
+        .map(|p| {
+            let col = rows
+                .iter()
+                .map(|r| r.params.to_array()[p.index()])
+                .collect();
+            (p.name().to_string(), col)
+        })
+        .collect();
+    let col = |f: &dyn Fn(&ScanRow) -> f64| -> Vec<f64> { rows.iter().map(f).collect() };
+    columns.extend([
+        ("ordered".to_string(), col(&|r| flag(r.ordered))),
+        ("accepted".to_string(), col(&|r| flag(r.accepted))),
+        ("v_max".to_string(), col(&|r| r.features.v_max)),
+        ("phi_top".to_string(), col(&|r| r.features.phi_top)),
+        ("delta_v".to_string(), col(&|r| r.features.delta_v)),
+        ("thin_wall".to_string(), col(&|r| r.features.thin_wall)),
+        ("n_maxima".to_string(), col(&|r| r.features.n_maxima as f64)),
+        ("n_minima".to_string(), col(&|r| r.features.n_minima as f64)),
+    ]);
+    if rows.iter().any(|r| r.action.is_some()) {
+        columns.push(("action".to_string(), col(&|r| r.action.unwrap_or(f64::NAN))));
+    }
+    write_parquet(path, columns, metadata, CompressionOptions::Uncompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+    use arrow2::array::Float64Array;
+    use arrow2::io::parquet::read;
+    use std::fs::File;
+
+    fn spec(phi_0: &str, phi_2: &str) -> ScanSpec {
+        let fixed = [
+            parse_fixed("phi_1p=0.1").unwrap(),
+            parse_fixed("phi_1n=0.9").unwrap(),
+        ];
+        ScanSpec::new(fixed, [phi_0.parse().unwrap(), phi_2.parse().unwrap()]).unwrap()
+    }
+
+    fn read_columns(path: &str) -> Vec<(String, Vec<f64>)> {
+        let mut file = File::open(path).unwrap();
+        let metadata = read::read_metadata(&mut file).unwrap();
+        let schema = read::infer_schema(&metadata).unwrap();
+        let names: Vec<String> = schema.fields.iter().map(|f| f.name.clone()).collect();
+        let reader = read::FileReader::new(file, metadata.row_groups, schema, None, None, None);
+        let mut columns = vec![Vec::new(); names.len()];
+        for chunk in reader {
+            for (col, array) in columns.iter_mut().zip(chunk.unwrap().arrays()) {
+                let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+                col.extend(array.values().iter().copied());
+            }
+        }
+        names.into_iter().zip(columns).collect()
+    }
+
+    #[test]
+    fn writes_one_row_per_point() {
+        // phi_0 = 0.05 lies below phi_1p, so the first three points are outside the family
+        let spec = spec("phi_0=0.05:0.65:4", "phi_2=0.3:0.7:3");
+        let phi = linspace(0, 1, 50);
+        let rows = run_scan(&spec, &phi, Acceptance::default(), None);
+        assert_eq!(rows.len(), 12);
+        assert!(rows.iter().all(|r| r.action.is_none()));
+
+        let dir = TempDir::new("scan-table");
+        let path = dir.file("scan.parquet");
+        write_scan(&path, &rows, vec![]).unwrap();
+        let columns = read_columns(&path);
+        let names: Vec<&str> = columns.iter().map(|c| c.0.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "phi_0",
+                "phi_1n",
+                "phi_1p",
+                "phi_2",
+                "ordered",
+                "accepted",
+                "v_max",
+                "phi_top",
+                "delta_v",
+                "thin_wall",
+                "n_maxima",
+                "n_minima"
+            ]
+        );
+        let col = |name: &str| &columns.iter().find(|c| c.0 == name).unwrap().1;
+
+        // The first axis varies slowest
+        assert_eq!(col("phi_0")[..4], [0.05, 0.05, 0.05, 0.25]);
+        assert_eq!(col("phi_2")[..4], [0.3, 0.5, 0.7, 0.3]);
+        assert!(col("phi_1p").iter().all(|&x| x == 0.1));
+        assert_eq!(col("ordered")[..4], [0.0, 0.0, 0.0, 1.0]);
+        assert!(col("v_max")[..3].iter().all(|x| x.is_nan()));
+        assert!(col("v_max")[3..].iter().all(|x| x.is_finite()));
+        for (r, row) in rows.iter().enumerate() {
+            assert_eq!(col("accepted")[r], if row.accepted { 1.0 } else { 0.0 });
+            assert!(!row.accepted || row.ordered);
+        }
+    }
+
+    #[test]
+    fn adds_the_action_column_when_solved_for() {
+        let spec = spec("phi_0=0.05:0.45:2", "phi_2=0.6:0.7:2");
+        let phi = linspace(0, 1, 50);
+        let rows = run_scan(
+            &spec,
+            &phi,
+            Acceptance::default(),
+            Some(BounceOptions::default()),
+        );
+
+        let dir = TempDir::new("scan-action");
+        let path = dir.file("scan.parquet");
+        write_scan(&path, &rows, vec![]).unwrap();
+        let columns = read_columns(&path);
+        let (name, action) = columns.last().unwrap();
+        assert_eq!(name, "action");
+        assert!(action[..2].iter().all(|x| x.is_nan()));
+        for (row, &s) in rows[2..].iter().zip(&action[2..]) {
+            let expected = c1_bounce(&row.params, BounceOptions::default())
+                .unwrap()
+                .action;
+            assert!(s > 0.0 && s == expected, "action {} at {:?}", s, row.params);
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use crate::bounce::{c1_bounce, c1_false_vacuum, sphere_area, BounceOptions};
+use crate::c1::{c1_deriv_grad, c1_potential_grad, C1Param, C1Params};
+use crate::output::Sample;
+use std::fmt;
+use std::str::FromStr;
+
+/// ∂V(φ_i)/∂θ on a grid, one `[phi_0, phi_1n, phi_1p, phi_2]` row per node
+pub fn potential_jacobian(p: &C1Params, phi: &[f64]) -> Vec<[f64; 4]> {
+    let g = c1_potential_grad(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+    phi.iter().map(|&x| g(x)).collect()
+}
+
+/// ∂V'(φ_i)/∂θ on a grid
+pub fn deriv_jacobian(p: &C1Params, phi: &[f64]) -> Vec<[f64; 4]> {
+    let g = c1_deriv_grad(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+    phi.iter().map(|&x| g(x)).collect()
+}
+
+/// A scalar and its gradient w.r.t. (phi_0, phi_1n, phi_1p, phi_2)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Gradient {
+    pub value: f64,
+    pub grad: [f64; 4],
+}
+
+fn sub(a: [f64; 4], b: [f64; 4]) -> [f64; 4] {
+    std::array::from_fn(|i| a[i] - b[i])
+}
+
+/// Barrier height `V(φ_top) - V(φ_false)`
+///
+/// Both points are stationary, so moving them with θ does not change V to first order and
+/// the gradient is `∂V/∂θ(φ_top) - ∂V/∂θ(φ_false)` (envelope theorem).
+pub fn barrier_gradient(p: &C1Params) -> Result<Gradient, String> {
+    let phi_false = c1_false_vacuum(p)?;
+    let v = p.potential();
+    let dv = p.deriv();
+    const N_SCAN: usize = 1000;
+    let xs: Vec<f64> = (0..=N_SCAN)
+        .map(|i| phi_false + (1f64 - phi_false) * i as f64 / N_SCAN as f64)
+        .collect();
+    let i_top = (0..=N_SCAN).fold(0, |i, j| if v(xs[j]) > v(xs[i]) { j } else { i });
+    if i_top == 0 || i_top == N_SCAN {
+        return Err("there is no barrier between the vacua".to_string());
+    }
+    let (mut lo, mut hi) = (xs[i_top - 1], xs[i_top + 1]);
+    for _ in 0..60 {
+        let mid = 0.5 * (lo + hi);
+        if dv(mid) > 0f64 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let phi_top = 0.5 * (lo + hi);
+    let g = c1_potential_grad(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+    Ok(Gradient {
+        value: v(phi_top) - v(phi_false),
+        grad: sub(g(phi_top), g(phi_false)),
+    })
+}
+
+/// Vacuum energy difference `V(φ_false) - V(1)`, with the gradient by the envelope theorem
+pub fn delta_v_gradient(p: &C1Params) -> Result<Gradient, String> {
+    let phi_false = c1_false_vacuum(p)?;
+    let v = p.potential();
+    let g = c1_potential_grad(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+    Ok(Gradient {
+        value: v(phi_false) - v(1f64),
+        grad: sub(g(phi_false), g(1f64)),
+    })
+}
+
+/// How `action_gradient` differentiates the bounce action
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ActionMethod {
+    /// First-order perturbation of the potential along the bounce,
+    /// `∂S/∂θ = Ω_{d-1} ∫ r^{d-1} (∂V/∂θ(φ_b) - ∂V/∂θ(φ_false)) dr`, which holds because the
+    /// bounce is a stationary point of S; needs one bounce, written `adjoint`
+    Adjoint,
+    /// Central differences with parameter step `step`; needs nine bounces, written `fd[:step]`
+    FiniteDifference { step: f64 },
+}
+
+impl fmt::Display for ActionMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionMethod::Adjoint => write!(f, "adjoint"),
+            ActionMethod::FiniteDifference { step } => write!(f, "fd:{}", step),
+        }
+    }
+}
+
+impl FromStr for ActionMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s == "adjoint" => Ok(ActionMethod::Adjoint),
+            None if s == "fd" => Ok(ActionMethod::FiniteDifference { step: 1e-5 }),
+            Some(("fd", step)) => match step.parse::<f64>() {
+                Ok(step) if step > 0f64 => Ok(ActionMethod::FiniteDifference { step }),
+                _ => Err(format!("invalid step in '{}'", s)),
+            },
+            _ => Err(format!(
+                "unknown gradient method '{}' (expected adjoint or fd[:step])",
+                s
+            )),
+        }
+    }
+}
+
+/// Bounce action and its gradient
+pub fn action_gradient(
+    p: &C1Params,
+    opts: BounceOptions,
+    method: ActionMethod,
+) -> Result<Gradient, String> {
+    let bounce = c1_bounce(p, opts)?;
+    let grad = match method {
+        ActionMethod::Adjoint => {
+            let g = c1_potential_grad(p.phi_0, p.phi_1n, p.phi_1p, p.phi_2);
+            let g_false = g(c1_false_vacuum(p)?);
+            let d = opts.dim as i32;
+            let integrand = |r: f64, phi: f64| sub(g(phi), g_false).map(|x| r.powi(d - 1) * x);
+            // Simpson's rule on each step of the solver, with φ at the midpoint from the cubic
+            // Hermite interpolant of φ and φ'; the steps are too long for the trapezoid rule
+            let (r, phi, dphi) = (&bounce.r, &bounce.phi, &bounce.dphi);
+            let mut grad = [0f64; 4];
+            for i in 1..r.len() {
+                let dr = r[i] - r[i - 1];
+                let phi_mid = 0.5 * (phi[i - 1] + phi[i]) + dr * (dphi[i - 1] - dphi[i]) / 8f64;
+                let (a, m, b) = (
+                    integrand(r[i - 1], phi[i - 1]),
+                    integrand(0.5 * (r[i - 1] + r[i]), phi_mid),
+                    integrand(r[i], phi[i]),
+                );
+                for (k, x) in grad.iter_mut().enumerate() {
+                    *x += dr / 6f64 * (a[k] + 4f64 * m[k] + b[k]);
+                }
+            }
+            grad.map(|x| sphere_area(opts.dim) * x)
+        }
+        ActionMethod::FiniteDifference { step } => {
+            let mut grad = [0f64; 4];
+            for (k, x) in grad.iter_mut().enumerate() {
+                let shifted = |h: f64| {
+                    let mut ps = p.to_array();
+                    ps[k] += h;
+                    c1_bounce(&C1Params::from_array(ps), opts).map(|b| b.action)
+                };
+                *x = (shifted(step)? - shifted(-step)?) / (2f64 * step);
+            }
+            grad
+        }
+    };
+    Ok(Gradient {
+        value: bounce.action,
+        grad,
+    })
+}
+
+/// A quantity whose parameter gradient can be stored with the samples
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Quantity {
+    /// V on the grid of the sample
+    Potential,
+    Barrier,
+    DeltaV,
+    /// O(4) bounce action, stored next to its gradient
+    Action,
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Quantity::Potential => write!(f, "potential"),
+            Quantity::Barrier => write!(f, "barrier"),
+            Quantity::DeltaV => write!(f, "delta_v"),
+            Quantity::Action => write!(f, "action"),
+        }
+    }
+}
+
+impl FromStr for Quantity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "potential" => Ok(Quantity::Potential),
+            "barrier" => Ok(Quantity::Barrier),
+            "delta_v" => Ok(Quantity::DeltaV),
+            "action" => Ok(Quantity::Action),
+            _ => Err(format!(
+                "unknown quantity '{}' (expected potential, barrier, delta_v or action)",
+                s
+            )),
+        }
+    }
+}
+
+/// Gradients stored as label columns, written as a comma-separated list of quantities
+/// optionally followed by `@method` for the action (e.g. `barrier,action@fd:1e-4`)
+///
+/// Columns are named `grad_<quantity>_<param>`, with `grad_v_<j>_<param>` for the
+/// potential at grid node j. Quantities that cannot be computed for a sample (e.g. a bounce
+/// the solver does not find) are stored as NaN.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientSpec {
+    pub quantities: Vec<Quantity>,
+    pub method: ActionMethod,
+}
+
+impl fmt::Display for GradientSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self.quantities.iter().map(|q| q.to_string()).collect();
+        write!(f, "{}", names.join(","))?;
+        if self.quantities.contains(&Quantity::Action) {
+            write!(f, "@{}", self.method)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GradientSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (list, method) = match s.split_once('@') {
+            Some((list, method)) => (list, method.parse()?),
+            None => (s, ActionMethod::Adjoint),
+        };
+        let mut quantities = vec![];
+        for q in list.split(',') {
+            let q: Quantity = q.parse()?;
+            if !quantities.contains(&q) {
+                quantities.push(q);
+            }
+        }
+        Ok(Self { quantities, method })
+    }
+}
+
+impl GradientSpec {
+    /// Label columns for curves of `grid_len` nodes
+    pub fn columns(&self, grid_len: usize) -> Vec<String> {
+        let mut names = vec![];
+        for q in &self.quantities {
+            let per_param =
+                |prefix: &str| C1Param::ALL.map(|p| format!("grad_{}_{}", prefix, p.name()));
+            match q {
+                Quantity::Potential => {
+                    names.extend((0..grid_len).flat_map(|j| per_param(&format!("v_{}", j))))
+                }
+                Quantity::Barrier => names.extend(per_param("v_max")),
+                Quantity::DeltaV => names.extend(per_param("delta_v")),
+                Quantity::Action => {
+                    names.push("action".to_string());
+                    names.extend(per_param("action"));
+                }
+            }
+        }
+        names
+    }
+
+    /// Values of the columns for a sample on the nodes `phi`
+    pub fn values(&self, sample: &Sample, phi: &[f64]) -> Vec<f64> {
+        let p = &sample.params;
+        let flat = |g: Result<Gradient, String>| g.map_or([f64::NAN; 4], |g| g.grad);
+        let mut values = vec![];
+        for q in &self.quantities {
+            match q {
+                Quantity::Potential => {
+                    values.extend(potential_jacobian(p, phi).into_iter().flatten())
+                }
+                Quantity::Barrier => values.extend(flat(barrier_gradient(p))),
+                Quantity::DeltaV => values.extend(flat(delta_v_gradient(p))),
+                Quantity::Action => {
+                    let g = action_gradient(p, BounceOptions::default(), self.method);
+                    values.push(g.as_ref().map_or(f64::NAN, |g| g.value));
+                    values.extend(flat(g));
+                }
+            }
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The difference step keeps the truncation error far below the tolerance
+    #[test]
+    fn adjoint_action_gradient_matches_finite_differences() {
+        for (p, dim) in [
+            (C1Params::new(0.4, 0.8, 0.2, 0.6), 4),
+            (C1Params::new(0.4, 0.8, 0.2, 0.6), 3),
+            (C1Params::new(0.3, 0.85, 0.15, 0.55), 4),
+        ] {
+            let opts = BounceOptions {
+                dim,
+                ..BounceOptions::default()
+            };
+            let adjoint = action_gradient(&p, opts, ActionMethod::Adjoint).unwrap();
+            let fd =
+                action_gradient(&p, opts, ActionMethod::FiniteDifference { step: 1e-5 }).unwrap();
+            assert_eq!(adjoint.value, fd.value);
+            let scale = fd.grad.iter().fold(0f64, |a, g| a.max(g.abs()));
+            for (a, b) in adjoint.grad.iter().zip(fd.grad) {
+                assert!(
+                    (a - b).abs() < 1e-6 * scale,
+                    "{:?}: adjoint {:?} vs fd {:?}",
+                    p,
+                    adjoint.grad,
+                    fd.grad
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,376 @@
+use crate::c1::C1Params;
+use crate::features::Acceptance;
+use crate::formats::json_string;
+use crate::output::Sample;
+use crate::sampler::SplitMix64;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub const SPLIT_NAMES: [&str; 3] = ["train", "val", "test"];
+
+/// Train/validation/test split, written `train:val:test` (relative sizes, e.g. `8:1:1`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitSpec {
+    pub ratios: [f64; 3],
+    pub stratify: Stratify,
+    /// Write one file per split instead of a `split` column
+    pub files: bool,
+}
+
+impl Default for SplitSpec {
+    fn default() -> Self {
+        Self {
+            ratios: [0.8, 0.1, 0.1],
+            stratify: Stratify::None,
+            files: false,
+        }
+    }
+}
+
+impl FromStr for SplitSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ratios = s
+            .split(':')
+            .map(|x| {
+                x.parse::<f64>()
+                    .map_err(|e| format!("invalid ratio '{}' in split: {}", x, e))
+            })
+            .collect::<Result<Vec<f64>, _>>()?;
+        let total: f64 = ratios.iter().sum();
+        if ratios.len() != 3 || ratios.iter().any(|&r| r < 0f64) || total <= 0f64 {
+            return Err(format!(
+                "invalid split '{}' (expected three non-negative ratios train:val:test)",
+                s
+            ));
+        }
+        Ok(SplitSpec {
+            ratios: [ratios[0], ratios[1], ratios[2]],
+            ..Default::default()
+        })
+    }
+}
+
+impl fmt::Display for SplitSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.ratios[0], self.ratios[1], self.ratios[2]
+        )
+    }
+}
+
+/// Strata whose split proportions are balanced separately
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Stratify {
+    None,
+    /// Plain and swapped branch (phi_0 < phi_2 or not)
+    Branch,
+    /// `bins` equal bins of log10 V_max over the accepted range
+    Barrier {
+        bins: usize,
+    },
+}
+
+impl fmt::Display for Stratify {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stratify::None => write!(f, "none"),
+            Stratify::Branch => write!(f, "branch"),
+            Stratify::Barrier { bins } => write!(f, "barrier:{}", bins),
+        }
+    }
+}
+
+impl FromStr for Stratify {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        match (parts[0], parts.len()) {
+            ("none", 1) => Ok(Stratify::None),
+            ("branch", 1) => Ok(Stratify::Branch),
+            ("barrier", 1) => Ok(Stratify::Barrier { bins: 10 }),
+            ("barrier", 2) => match parts[1].parse::<usize>() {
+                Ok(bins) if bins > 0 => Ok(Stratify::Barrier { bins }),
+                _ => Err(format!("invalid bin count in '{}'", s)),
+            },
+            _ => Err(format!(
+                "unknown stratification '{}' (expected none, branch or barrier[:bins])",
+                s
+            )),
+        }
+    }
+}
+
+/// Stable hash of the parameter bits, uniform in [0, 1)
+pub fn split_hash(p: &C1Params) -> f64 {
+    let mut h = 0x8f1b_bcdc_a8e4_2f4du64;
+    for x in p.to_array() {
+        h = SplitMix64::new(h ^ x.to_bits()).next_u64();
+    }
+    (h >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Assigns samples to splits
+///
+/// Without stratification the split of a sample depends only on the hash of its parameters,
+/// so the same potential always lands in the same split. Within strata the hash still picks
+/// the split, but a split with fraction f of a stratum holding n samples takes at most
+/// `ceil(n f) + sqrt(n f (1 - f))` of them, one binomial standard deviation above its share;
+/// a sample whose split is full goes to the split furthest below its share instead. Only the
+/// few samples moved by a full quota depend on the order in which the samples arrive.
+#[derive(Debug, Clone)]
+pub struct Splitter {
+    spec: SplitSpec,
+    /// Samples assigned to each split, per stratum
+    strata: Vec<[usize; 3]>,
+    counts: [usize; 3],
+}
+
+impl Splitter {
+    pub fn new(spec: SplitSpec) -> Self {
+        let n_strata = match spec.stratify {
+            Stratify::None => 0,
+            Stratify::Branch => 2,
+            Stratify::Barrier { bins } => bins,
+        };
+        Self {
+            spec,
+            strata: vec![[0; 3]; n_strata],
+            counts: [0; 3],
+        }
+    }
+
+    pub fn spec(&self) -> &SplitSpec {
+        &self.spec
+    }
+
+    /// Fractions of the samples going to train, val and test
+    fn fractions(&self) -> [f64; 3] {
+        let r = self.spec.ratios;
+        let total = r[0] + r[1] + r[2];
+        r.map(|x| x / total)
+    }
+
+    fn stratum(&self, sample: &Sample) -> Option<usize> {
+        match self.spec.stratify {
+            Stratify::None => None,
+            Stratify::Branch => Some(sample.params.is_swapped() as usize),
+            Stratify::Barrier { bins } => {
+                let acceptance = Acceptance::default();
+                let (lo, hi) = (acceptance.v_max_min.log10(), acceptance.v_max_max.log10());
+                let v_max = sample.v.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+                let x = ((v_max.log10() - lo) / (hi - lo)).clamp(0f64, 1f64);
+                Some(((x * bins as f64) as usize).min(bins - 1))
+            }
+        }
+    }
+
+    /// Index into `SPLIT_NAMES` of the split of `sample`
+    pub fn assign(&mut self, sample: &Sample) -> usize {
+        let fractions = self.fractions();
+        let u = split_hash(&sample.params);
+        let drawn = if u < fractions[0] {
+            0
+        } else if u < fractions[0] + fractions[1] {
+            1
+        } else {
+            2
+        };
+        let split = match self.stratum(sample) {
+            None => drawn,
+            Some(s) => {
+                let counts = &mut self.strata[s];
+                let n = (counts.iter().sum::<usize>() + 1) as f64;
+                let f = fractions[drawn];
+                // Without slack nearly every split sits at its quota and the order of the
+                // samples, not their hash, would decide most assignments
+                let quota = (n * f).ceil() + (n * f * (1f64 - f)).sqrt().floor();
+                let split = if (counts[drawn] as f64) < quota {
+                    drawn
+                } else {
+                    // The deficits sum to one, so some split is below its share
+                    (0..3)
+                        .max_by(|&a, &b| {
+                            let deficit = |i: usize| n * fractions[i] - counts[i] as f64;
+                            deficit(a).total_cmp(&deficit(b))
+                        })
+                        .unwrap()
+                };
+                counts[split] += 1;
+                split
+            }
+        };
+        self.counts[split] += 1;
+        split
+    }
+
+    pub fn counts(&self) -> [usize; 3] {
+        self.counts
+    }
+}
+
+/// File of one split: `c1.parquet` -> `c1.train.parquet`
+pub fn split_path(path: &str, split: usize) -> String {
+    let name = SPLIT_NAMES[split];
+    match path.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() && !ext.contains('/') => {
+            format!("{}.{}.{}", stem, name, ext)
+        }
+        _ => format!("{}.{}", path, name),
+    }
+}
+
+/// JSON manifest describing how a split dataset was produced
+pub fn write_manifest(
+    path: &str,
+    output: &str,
+    spec: &SplitSpec,
+    counts: [usize; 3],
+    metadata: &[(String, String)],
+) -> Result<(), Box<dyn Error>> {
+    let seed = metadata
+        .iter()
+        .find(|(k, _)| k == "bounce.seed")
+        .map_or("null", |(_, v)| v.as_str());
+    let outputs: Vec<String> = (0..3)
+        .map(|i| {
+            let file = if spec.files {
+                split_path(output, i)
+            } else {
+                output.to_string()
+            };
+            format!(
+                "    {}: {}",
+                json_string(SPLIT_NAMES[i]),
+                json_string(&file)
+            )
+        })
+        .collect();
+    let counts: Vec<String> = (0..3)
+        .map(|i| format!("    {}: {}", json_string(SPLIT_NAMES[i]), counts[i]))
+        .collect();
+    let config: Vec<String> = metadata
+        .iter()
+        .map(|(k, v)| format!("    {}: {}", json_string(k), json_string(v)))
+        .collect();
+    let assignment = if spec.files {
+        "separate files"
+    } else {
+        "column 'split' (0 = train, 1 = val, 2 = test)"
+    };
+    let text = format!(
+        "{{\n  \"crate\": {},\n  \"version\": {},\n  \"git_commit\": {},\n  \"seed\": {},\n  \
+         \"split\": {{\n    \"ratios\": [{}, {}, {}],\n    \"stratify\": {},\n    \"assignment\": {}\n  }},\n  \
+         \"outputs\": {{\n{}\n  }},\n  \"counts\": {{\n{}\n  }},\n  \"config\": {{\n{}\n  }}\n}}\n",
+        json_string(env!("CARGO_PKG_NAME")),
+        json_string(env!("CARGO_PKG_VERSION")),
+        json_string(env!("BOUNCE_GIT_COMMIT")),
+        seed,
+        spec.ratios[0],
+        spec.ratios[1],
+        spec.ratios[2],
+        json_string(&spec.stratify.to_string()),
+        json_string(assignment),
+        outputs.join(",\n"),
+        counts.join(",\n"),
+        config.join(",\n"),
+    );
+    std::fs::write(path, text)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Samples on both branches; only their parameters matter for a branch split
+    fn samples(n: usize) -> Vec<Sample> {
+        let mut rng = SplitMix64::new(11);
+        (0..n)
+            .map(|_| {
+                let mut us: Vec<f64> = (0..4).map(|_| rng.next_f64()).collect();
+                us.sort_by(f64::total_cmp);
+                Sample {
+                    params: C1Params::from_sorted(
+                        [us[0], us[1], us[2], us[3]],
+                        rng.next_f64() < 0.3,
+                    ),
+                    grid: None,
+                    v: vec![],
+                    dv: vec![],
+                    weight: 1f64,
+                }
+            })
+            .collect()
+    }
+
+    fn assign_all(samples: &[Sample], spec: &SplitSpec) -> Vec<usize> {
+        let mut splitter = Splitter::new(spec.clone());
+        samples.iter().map(|s| splitter.assign(s)).collect()
+    }
+
+    #[test]
+    fn stratified_split_keeps_ratios_and_hash_draws() {
+        let samples = samples(2000);
+        let spec = SplitSpec {
+            ratios: [0.7, 0.2, 0.1],
+            stratify: Stratify::Branch,
+            files: false,
+        };
+        let forward = assign_all(&samples, &spec);
+
+        for branch in [false, true] {
+            let splits: Vec<usize> = samples
+                .iter()
+                .zip(&forward)
+                .filter(|(s, _)| s.params.is_swapped() == branch)
+                .map(|(_, &split)| split)
+                .collect();
+            let n = splits.len() as f64;
+            // Largest excess over the share allowed by the quotas
+            let slack = spec.ratios.map(|r| (n * r * (1f64 - r)).sqrt() + 1f64);
+            for (i, r) in spec.ratios.iter().enumerate() {
+                let count = splits.iter().filter(|&&s| s == i).count() as f64;
+                let others: f64 = slack.iter().sum::<f64>() - slack[i];
+                assert!(
+                    count <= n * r + slack[i] && count >= n * r - others,
+                    "{} of {} in {}",
+                    count,
+                    n,
+                    SPLIT_NAMES[i]
+                );
+            }
+        }
+
+        // Assignments follow the parameter hash, so reversing the order moves only the few
+        // samples displaced by a full quota
+        let mut reversed = assign_all(&samples.iter().rev().cloned().collect::<Vec<_>>(), &spec);
+        reversed.reverse();
+        let moved = forward
+            .iter()
+            .zip(&reversed)
+            .filter(|(a, b)| a != b)
+            .count();
+        assert!(
+            moved < samples.len() / 10,
+            "{} samples changed split",
+            moved
+        );
+
+        let unstratified = SplitSpec {
+            stratify: Stratify::None,
+            ..spec
+        };
+        let mut reversed = assign_all(
+            &samples.iter().rev().cloned().collect::<Vec<_>>(),
+            &unstratified,
+        );
+        reversed.reverse();
+        assert_eq!(assign_all(&samples, &unstratified), reversed);
+    }
+}
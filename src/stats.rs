@@ -0,0 +1,312 @@
+use crate::c1::C1Param;
+use crate::dataset::Dataset;
+use crate::features::{is_finite_curve, Features};
+use crate::formats::{json_number, json_string};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+/// Distribution summary of one quantity, over its finite values
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Summary {
+    pub n: usize,
+    pub mean: f64,
+    pub std: f64,
+    pub min: f64,
+    pub q05: f64,
+    pub q25: f64,
+    pub median: f64,
+    pub q75: f64,
+    pub q95: f64,
+    pub max: f64,
+}
+
+impl Summary {
+    /// Every statistic is NaN without finite values; the standard deviation of a single
+    /// value is zero
+    pub fn new(values: &[f64]) -> Self {
+        let mut xs: Vec<f64> = values.iter().copied().filter(|x| x.is_finite()).collect();
+        xs.sort_by(|a, b| a.total_cmp(b));
+        let n = xs.len();
+        let mean = xs.iter().sum::<f64>() / n as f64;
+        let std = match n {
+            0 => f64::NAN,
+            1 => 0f64,
+            _ => {
+                let var = xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+                var.sqrt()
+            }
+        };
+        Self {
+            n,
+            mean,
+            std,
+            min: quantile(&xs, 0f64),
+            q05: quantile(&xs, 0.05),
+            q25: quantile(&xs, 0.25),
+            median: quantile(&xs, 0.5),
+            q75: quantile(&xs, 0.75),
+            q95: quantile(&xs, 0.95),
+            max: quantile(&xs, 1f64),
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            "{{\"n\": {}, \"mean\": {}, \"std\": {}, \"min\": {}, \"q05\": {}, \"q25\": {}, \"median\": {}, \"q75\": {}, \"q95\": {}, \"max\": {}}}",
+            self.n,
+            json_number(self.mean),
+            json_number(self.std),
+            json_number(self.min),
+            json_number(self.q05),
+            json_number(self.q25),
+            json_number(self.median),
+            json_number(self.q75),
+            json_number(self.q95),
+            json_number(self.max),
+        )
+    }
+}
+
+/// Linearly interpolated quantile of sorted values; NaN when there are none
+pub fn quantile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+    let x = q * (sorted.len() - 1) as f64;
+    let i = (x.floor() as usize).min(sorted.len() - 1);
+    let j = (i + 1).min(sorted.len() - 1);
+    sorted[i] + (x - i as f64) * (sorted[j] - sorted[i])
+}
+
+/// Summary of a generated dataset
+///
+/// Features are recomputed from the stored curves. All statistics are unweighted, i.e. they
+/// describe the file as written; the samples of targeted runs carry weights in their
+/// `weight` column for reweighting.
+#[derive(Debug, Clone)]
+pub struct DatasetStats {
+    pub n_samples: usize,
+    /// Features, parameters and label columns (e.g. actions) other than `split`, by name
+    pub summaries: Vec<(String, Summary)>,
+    /// Samples by number of local maxima on the grid
+    pub n_maxima: BTreeMap<usize, usize>,
+    /// Samples by number of local minima on the grid
+    pub n_minima: BTreeMap<usize, usize>,
+    /// Samples of the swapped branch (phi_0 > phi_2)
+    pub n_swapped: usize,
+    /// Samples whose stored curve has non-finite values, left out of the feature summaries
+    pub n_nonfinite: usize,
+    /// Pearson correlations of the parameters, in the order of `C1Param::ALL`
+    pub correlations: [[f64; 4]; 4],
+}
+
+/// Names of the per-sample features in the order of `feature_values`
+pub const FEATURES: [&str; 5] = ["v_max", "phi_top", "delta_v", "delta_ratio", "thin_wall"];
+
+pub fn feature_values(f: &Features) -> [f64; 5] {
+    [f.v_max, f.phi_top, f.delta_v, f.delta_ratio, f.thin_wall]
+}
+
+pub fn dataset_stats(dataset: &Dataset) -> Result<DatasetStats, Box<dyn Error>> {
+    let n_labels = dataset.labels().len();
+    let mut columns: Vec<Vec<f64>> =
+        vec![Vec::with_capacity(dataset.len()); FEATURES.len() + 4 + n_labels];
+    let mut n_maxima = BTreeMap::new();
+    let mut n_minima = BTreeMap::new();
+    let mut n_swapped = 0;
+    let mut n_nonfinite = 0;
+    for record in dataset.records() {
+        let record = record?;
+        let s = &record.sample;
+        let phi = s.grid.as_deref().or(dataset.grid()).unwrap_or(&[]);
+        // Features of a curve with non-finite values are left out of the summaries
+        let features = if is_finite_curve(&s.v) {
+            let f = Features::new(phi, &s.v);
+            *n_maxima.entry(f.n_maxima).or_insert(0) += 1;
+            *n_minima.entry(f.n_minima).or_insert(0) += 1;
+            feature_values(&f)
+        } else {
+            n_nonfinite += 1;
+            [f64::NAN; 5]
+        };
+        let values = features
+            .into_iter()
+            .chain(s.params.to_array())
+            .chain(record.labels.iter().copied());
+        for (column, x) in columns.iter_mut().zip(values) {
+            column.push(x);
+        }
+        n_swapped += s.params.is_swapped() as usize;
+    }
+
+    let params = &columns[FEATURES.len()..FEATURES.len() + 4];
+    let mut correlations = [[0f64; 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            correlations[i][j] = pearson(&params[i], &params[j]);
+        }
+    }
+
+    let names = FEATURES
+        .iter()
+        .map(|s| s.to_string())
+        .chain(C1Param::ALL.iter().map(|p| p.name().to_string()))
+        .chain(dataset.labels().iter().cloned());
+    Ok(DatasetStats {
+        n_samples: dataset.len(),
+        summaries: names
+            .zip(&columns)
+            .filter(|(name, _)| name != "split")
+            .map(|(name, xs)| (name, Summary::new(xs)))
+            .collect(),
+        n_maxima,
+        n_minima,
+        n_swapped,
+        n_nonfinite,
+        correlations,
+    })
+}
+
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let (mx, my) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+    let (mut sxy, mut sxx, mut syy) = (0f64, 0f64, 0f64);
+    for (a, b) in x.iter().zip(y) {
+        sxy += (a - mx) * (b - my);
+        sxx += (a - mx).powi(2);
+        syy += (b - my).powi(2);
+    }
+    sxy / (sxx * syy).sqrt()
+}
+
+impl DatasetStats {
+    /// Fraction of samples on the swapped branch
+    pub fn swapped_fraction(&self) -> f64 {
+        self.n_swapped as f64 / self.n_samples as f64
+    }
+
+    pub fn to_json(&self) -> String {
+        let summaries: Vec<String> = self
+            .summaries
+            .iter()
+            .map(|(name, s)| format!("    {}: {}", json_string(name), s.to_json()))
+            .collect();
+        let counts = |m: &BTreeMap<usize, usize>| {
+            let entries: Vec<String> = m.iter().map(|(k, v)| format!("\"{}\": {}", k, v)).collect();
+            format!("{{{}}}", entries.join(", "))
+        };
+        let rows: Vec<String> = self
+            .correlations
+            .iter()
+            .map(|row| {
+                let row: Vec<String> = row.iter().map(|&x| json_number(x)).collect();
+                format!("[{}]", row.join(", "))
+            })
+            .collect();
+        let names: Vec<String> = C1Param::ALL.iter().map(|p| json_string(p.name())).collect();
+        format!(
+            "{{\n  \"n_samples\": {},\n  \"n_nonfinite\": {},\n  \"branches\": {{\"plain\": {}, \"swapped\": {}}},\n  \
+             \"n_maxima\": {},\n  \"n_minima\": {},\n  \"summaries\": {{\n{}\n  }},\n  \
+             \"correlations\": {{\"parameters\": [{}], \"matrix\": [{}]}}\n}}\n",
+            self.n_samples,
+            self.n_nonfinite,
+            self.n_samples - self.n_swapped,
+            self.n_swapped,
+            counts(&self.n_maxima),
+            counts(&self.n_minima),
+            summaries.join(",\n"),
+            names.join(", "),
+            rows.join(", "),
+        )
+    }
+}
+
+impl fmt::Display for DatasetStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "samples: {}", self.n_samples)?;
+        if self.n_nonfinite > 0 {
+            writeln!(f, "non-finite curves: {}", self.n_nonfinite)?;
+        }
+        writeln!(
+            f,
+            "branches: plain {:.3}, swapped {:.3}",
+            1f64 - self.swapped_fraction(),
+            self.swapped_fraction()
+        )?;
+        let counts = |m: &BTreeMap<usize, usize>| {
+            let entries: Vec<String> = m.iter().map(|(k, v)| format!("{}: {}", k, v)).collect();
+            entries.join(", ")
+        };
+        writeln!(f, "local maxima: {}", counts(&self.n_maxima))?;
+        writeln!(f, "local minima: {}", counts(&self.n_minima))?;
+        writeln!(f)?;
+        // Label columns such as gradients can have long names
+        let width = self
+            .summaries
+            .iter()
+            .map(|(name, _)| name.len())
+            .fold(12, usize::max);
+        writeln!(
+            f,
+            "{:<width$} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "", "mean", "std", "min", "q05", "median", "q95", "max", "n"
+        )?;
+        for (name, s) in &self.summaries {
+            writeln!(
+                f,
+                "{:<width$} {:>10.4e} {:>10.4e} {:>10.4e} {:>10.4e} {:>10.4e} {:>10.4e} {:>10.4e} {:>10}",
+                name, s.mean, s.std, s.min, s.q05, s.median, s.q95, s.max, s.n
+            )?;
+        }
+        writeln!(f)?;
+        write!(f, "{:<12}", "correlation")?;
+        for p in C1Param::ALL {
+            write!(f, " {:>8}", p.name())?;
+        }
+        for (p, row) in C1Param::ALL.iter().zip(&self.correlations) {
+            write!(f, "\n{:<12}", p.name())?;
+            for x in row {
+                write!(f, " {:>8.3}", x)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantile_interpolates_linearly() {
+        let xs = [1.0, 2.0, 4.0, 8.0, 16.0];
+        assert_eq!(quantile(&xs, 0.0), 1.0);
+        assert_eq!(quantile(&xs, 0.5), 4.0);
+        assert_eq!(quantile(&xs, 0.625), 6.0);
+        assert!((quantile(&xs, 0.95) - 14.4).abs() < 1e-12);
+        assert_eq!(quantile(&xs, 1.0), 16.0);
+        assert_eq!(quantile(&[3.0], 0.3), 3.0);
+        assert!(quantile(&[], 0.5).is_nan());
+    }
+
+    #[test]
+    fn summary_of_known_values() {
+        let s = Summary::new(&[4.0, f64::NAN, 1.0, 3.0, f64::INFINITY, 2.0]);
+        assert_eq!(s.n, 4);
+        assert_eq!(s.mean, 2.5);
+        assert!((s.std - (5f64 / 3f64).sqrt()).abs() < 1e-15);
+        assert_eq!((s.min, s.median, s.max), (1.0, 2.5, 4.0));
+        assert_eq!((s.q25, s.q75), (1.75, 3.25));
+
+        let one = Summary::new(&[7.0]);
+        assert_eq!(
+            (one.n, one.mean, one.std, one.q05, one.q95),
+            (1, 7.0, 0.0, 7.0, 7.0)
+        );
+
+        let none = Summary::new(&[f64::NAN]);
+        assert_eq!(none.n, 0);
+        assert!(none.mean.is_nan() && none.std.is_nan() && none.median.is_nan());
+    }
+}
@@ -0,0 +1,800 @@
+use crate::bounce::{c1_bounce, BounceOptions};
+use crate::c1::C1Param;
+use crate::dataset::{Dataset, Record};
+use crate::output::{write_parquet, Codec, Sample};
+use crate::pca::Pca;
+use crate::stats::quantile;
+use peroxide::fuga::*;
+use rayon::prelude::*;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+/// Regression model of a surrogate
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ModelKind {
+    /// Ridge regression on all monomials of the inputs up to `degree`, written `poly[:degree]`
+    Polynomial { degree: usize },
+    /// Kernel ridge regression with a Gaussian kernel of width `length` (in units of the
+    /// standard deviation of each input), written `krr[:length]`
+    KernelRidge { length: f64 },
+}
+
+impl fmt::Display for ModelKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelKind::Polynomial { degree } => write!(f, "poly:{}", degree),
+            ModelKind::KernelRidge { length } => write!(f, "krr:{}", length),
+        }
+    }
+}
+
+impl FromStr for ModelKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, arg) = match s.split_once(':') {
+            Some((name, arg)) => (name, Some(arg)),
+            None => (s, None),
+        };
+        let invalid = |e: &dyn fmt::Display| format!("invalid model '{}': {}", s, e);
+        match name {
+            "poly" => {
+                let degree = arg.map_or(Ok(4), |d| d.parse()).map_err(|e| invalid(&e))?;
+                if degree == 0 {
+                    return Err(invalid(&"the degree must be positive"));
+                }
+                Ok(ModelKind::Polynomial { degree })
+            }
+            "krr" => {
+                let length = arg
+                    .map_or(Ok(1f64), |l| l.parse())
+                    .map_err(|e| invalid(&e))?;
+                if length.is_nan() || length <= 0f64 {
+                    return Err(invalid(&"the kernel length must be positive"));
+                }
+                Ok(ModelKind::KernelRidge { length })
+            }
+            _ => Err(format!(
+                "unknown model '{}' (expected poly[:degree] or krr[:length])",
+                s
+            )),
+        }
+    }
+}
+
+/// Inputs of a surrogate
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Inputs {
+    /// The four C1 parameters
+    Params,
+    /// Scores of the first `k` principal components of V, written `pca[:k]`
+    Pca { k: usize },
+}
+
+impl fmt::Display for Inputs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Inputs::Params => write!(f, "params"),
+            Inputs::Pca { k } => write!(f, "pca:{}", k),
+        }
+    }
+}
+
+impl FromStr for Inputs {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s == "params" => Ok(Inputs::Params),
+            None if s == "pca" => Ok(Inputs::Pca { k: 4 }),
+            Some(("pca", k)) => match k.parse::<usize>() {
+                Ok(k) if k > 0 => Ok(Inputs::Pca { k }),
+                _ => Err(format!("invalid component count in '{}'", s)),
+            },
+            _ => Err(format!(
+                "unknown inputs '{}' (expected params or pca[:k])",
+                s
+            )),
+        }
+    }
+}
+
+/// How a surrogate is trained
+#[derive(Debug, Clone, PartialEq)]
+pub struct SurrogateSpec {
+    pub model: ModelKind,
+    pub inputs: Inputs,
+    /// Label column to predict
+    pub target: String,
+    /// Regress the logarithm of the target, which must be positive
+    pub log: bool,
+    /// Ridge penalty, relative to the number of training samples for polynomials
+    pub lambda: f64,
+    /// Most training samples of kernel ridge regression, taken evenly from the training set
+    pub max_train: usize,
+}
+
+impl Default for SurrogateSpec {
+    fn default() -> Self {
+        Self {
+            model: ModelKind::Polynomial { degree: 4 },
+            inputs: Inputs::Pca { k: 4 },
+            target: "action".to_string(),
+            log: true,
+            lambda: 1e-8,
+            max_train: 2000,
+        }
+    }
+}
+
+/// Samples of a dataset with the values of a regression target
+///
+/// The target is read from its label column. A dataset without an `action` column gets the
+/// O(4) bounce action of every sample from the shooting solver instead, with NaN where the
+/// solver fails. Samples with a non-finite target are left out of training and evaluation.
+pub struct Labelled {
+    pub records: Vec<Record>,
+    pub targets: Vec<f64>,
+    /// Shared grid of the curves
+    pub grid: Option<Vec<f64>>,
+    /// Position of the `split` label, if any
+    split: Option<usize>,
+}
+
+impl Labelled {
+    pub fn load(dataset: &Dataset, target: &str) -> Result<Self, Box<dyn Error>> {
+        let records = dataset.load()?;
+        let column = dataset.labels().iter().position(|l| l == target);
+        let targets = match column {
+            Some(j) => records.iter().map(|r| r.labels[j]).collect(),
+            None if target == "action" => records
+                .par_iter()
+                .map(|r| {
+                    c1_bounce(&r.sample.params, BounceOptions::default())
+                        .map_or(f64::NAN, |b| b.action)
+                })
+                .collect(),
+            None => return Err(format!("the dataset has no label column '{}'", target).into()),
+        };
+        Ok(Self {
+            records,
+            targets,
+            grid: dataset.grid().map(|g| g.to_vec()),
+            split: dataset.labels().iter().position(|l| l == "split"),
+        })
+    }
+
+    /// Samples and targets of one split (index into `SPLIT_NAMES`), or all of them
+    fn rows(&self, split: Option<usize>) -> impl Iterator<Item = (&Sample, f64)> + '_ {
+        self.records
+            .iter()
+            .zip(&self.targets)
+            .filter(move |(r, _)| match (split, self.split) {
+                (Some(s), Some(j)) => r.labels[j] as usize == s,
+                _ => true,
+            })
+            .filter(|(_, y)| y.is_finite())
+            .map(|(r, &y)| (&r.sample, y))
+    }
+
+    pub fn has_splits(&self) -> bool {
+        self.split.is_some()
+    }
+}
+
+/// Accuracy of a surrogate on a set of samples
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Metrics {
+    pub n: usize,
+    /// RMS error and coefficient of determination of the regressed quantity
+    /// (the logarithm of the target when `SurrogateSpec::log` is set)
+    pub rmse: f64,
+    pub r2: f64,
+    /// Quantiles of the relative error `|prediction / target - 1|`
+    pub median_rel: f64,
+    pub q95_rel: f64,
+    pub max_rel: f64,
+}
+
+impl fmt::Display for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "n {}, rmse {:.4e}, R² {:.6}, relative error median {:.3e} / 95% {:.3e} / max {:.3e}",
+            self.n, self.rmse, self.r2, self.median_rel, self.q95_rel, self.max_rel
+        )
+    }
+}
+
+/// Classical regression baseline for a label of the samples (by default the bounce action)
+///
+/// Inputs are standardized with the mean and standard deviation of the training set, as is
+/// the regressed quantity. Models are saved as `key=value` lines with full-precision floats,
+/// so a loaded model predicts exactly what the trained one did.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Surrogate {
+    pub spec: SurrogateSpec,
+    pub n_train: usize,
+    /// Mean curve and principal components of the `pca` inputs
+    projection: Option<(Vec<f64>, Vec<Vec<f64>>)>,
+    /// Mean and standard deviation of each input and of the regressed quantity
+    x_scale: Vec<(f64, f64)>,
+    y_scale: (f64, f64),
+    /// Coefficients of the monomials, or kernel weights of the centres
+    weights: Vec<f64>,
+    /// Standardized inputs of the training samples used by kernel ridge regression
+    centres: Vec<Vec<f64>>,
+}
+
+impl Surrogate {
+    /// Fit `spec` to the training split of `data`, or to all of it without a `split` column
+    pub fn train(data: &Labelled, spec: SurrogateSpec) -> Result<Self, Box<dyn Error>> {
+        let split = data.has_splits().then_some(0);
+        let rows: Vec<(&Sample, f64)> = data.rows(split).collect();
+        if spec.log && rows.iter().any(|&(_, y)| y <= 0f64) {
+            return Err(format!(
+                "cannot regress the logarithm of '{}': it is not positive",
+                spec.target
+            )
+            .into());
+        }
+        let projection = match spec.inputs {
+            Inputs::Params => None,
+            Inputs::Pca { k } => {
+                let grid = data
+                    .grid
+                    .clone()
+                    .ok_or("PCA inputs need curves on a shared grid")?;
+                let pca = Pca::fit_samples(grid, rows.iter().map(|&(s, _)| Ok(s.clone())), false)?;
+                if k > pca.components.len() {
+                    return Err(format!(
+                        "cannot take {} components of {} grid points",
+                        k,
+                        pca.components.len()
+                    )
+                    .into());
+                }
+                Some((pca.mean, pca.components[..k].to_vec()))
+            }
+        };
+
+        let mut model = Self {
+            spec,
+            n_train: rows.len(),
+            projection,
+            x_scale: vec![],
+            y_scale: (0f64, 1f64),
+            weights: vec![],
+            centres: vec![],
+        };
+        if rows.len() < 2 {
+            return Err("need at least two training samples".into());
+        }
+        let xs: Vec<Vec<f64>> = rows.iter().map(|&(s, _)| model.inputs(s)).collect();
+        let ys: Vec<f64> = rows.iter().map(|&(_, y)| model.forward(y)).collect();
+        model.x_scale = (0..xs[0].len())
+            .map(|i| mean_std(xs.iter().map(|x| x[i])))
+            .collect();
+        model.y_scale = mean_std(ys.iter().copied());
+        let xs: Vec<Vec<f64>> = xs.iter().map(|x| model.standardize(x)).collect();
+        let ys: Vec<f64> = ys
+            .iter()
+            .map(|y| (y - model.y_scale.0) / model.y_scale.1)
+            .collect();
+
+        match model.spec.model {
+            ModelKind::Polynomial { degree } => {
+                let powers = monomials(xs[0].len(), degree);
+                let m = powers.len();
+                // The tiny default ridge penalty cannot keep an underdetermined fit in check
+                if m >= xs.len() {
+                    return Err(format!(
+                        "a degree {} polynomial in {} inputs has {} coefficients but there are only {} training samples; lower the degree or the number of inputs, or use more samples",
+                        degree,
+                        xs[0].len(),
+                        m,
+                        xs.len()
+                    )
+                    .into());
+                }
+                let mut ata = zeros(m, m);
+                let mut aty = vec![0f64; m];
+                for (x, y) in xs.iter().zip(&ys) {
+                    let phi = evaluate_monomials(&powers, x);
+                    for i in 0..m {
+                        aty[i] += phi[i] * y;
+                        for j in i..m {
+                            ata[(i, j)] += phi[i] * phi[j];
+                        }
+                    }
+                }
+                for i in 0..m {
+                    for j in 0..i {
+                        ata[(i, j)] = ata[(j, i)];
+                    }
+                    ata[(i, i)] += model.spec.lambda * xs.len() as f64;
+                }
+                model.weights = ata.solve(&aty, LU);
+            }
+            ModelKind::KernelRidge { length } => {
+                let stride = xs.len().div_ceil(model.spec.max_train.max(1));
+                let centres: Vec<Vec<f64>> = xs.iter().step_by(stride).cloned().collect();
+                let targets: Vec<f64> = ys.iter().step_by(stride).copied().collect();
+                let n = centres.len();
+                let mut k = zeros(n, n);
+                for i in 0..n {
+                    for j in i..n {
+                        let kij = gaussian(&centres[i], &centres[j], length);
+                        k[(i, j)] = kij;
+                        k[(j, i)] = kij;
+                    }
+                    k[(i, i)] += model.spec.lambda;
+                }
+                model.weights = k.solve(&targets, LU);
+                model.centres = centres;
+            }
+        }
+        if !model.weights.iter().all(|w| w.is_finite()) {
+            return Err("the regression system is singular; increase --lambda".into());
+        }
+        Ok(model)
+    }
+
+    /// Raw inputs of a sample
+    fn inputs(&self, sample: &Sample) -> Vec<f64> {
+        match &self.projection {
+            None => sample.params.to_array().to_vec(),
+            Some((mean, components)) => components
+                .iter()
+                .map(|u| {
+                    u.iter()
+                        .zip(&sample.v)
+                        .zip(mean)
+                        .map(|((u, v), m)| u * (v - m))
+                        .sum()
+                })
+                .collect(),
+        }
+    }
+
+    fn standardize(&self, x: &[f64]) -> Vec<f64> {
+        x.iter()
+            .zip(&self.x_scale)
+            .map(|(x, (m, s))| (x - m) / s)
+            .collect()
+    }
+
+    /// Target to regressed quantity
+    fn forward(&self, y: f64) -> f64 {
+        if self.spec.log {
+            y.ln()
+        } else {
+            y
+        }
+    }
+
+    fn backward(&self, z: f64) -> f64 {
+        if self.spec.log {
+            z.exp()
+        } else {
+            z
+        }
+    }
+
+    /// Prediction of the regressed quantity (before undoing the logarithm)
+    fn predict_raw(&self, sample: &Sample) -> f64 {
+        let x = self.standardize(&self.inputs(sample));
+        let z: f64 = match self.spec.model {
+            ModelKind::Polynomial { degree } => {
+                let phi = evaluate_monomials(&monomials(x.len(), degree), &x);
+                phi.iter().zip(&self.weights).map(|(p, w)| p * w).sum()
+            }
+            ModelKind::KernelRidge { length } => self
+                .centres
+                .iter()
+                .zip(&self.weights)
+                .map(|(c, w)| w * gaussian(&x, c, length))
+                .sum(),
+        };
+        self.y_scale.0 + self.y_scale.1 * z
+    }
+
+    /// Predicted target of one sample
+    pub fn predict(&self, sample: &Sample) -> f64 {
+        self.backward(self.predict_raw(sample))
+    }
+
+    /// PCA inputs only make sense on the grid the model was trained on
+    fn check_grid(&self, grid_len: usize) -> Result<(), String> {
+        match &self.projection {
+            Some((mean, _)) if mean.len() != grid_len => Err(format!(
+                "the model projects curves of {} grid points, the dataset has {}",
+                mean.len(),
+                grid_len
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Accuracy on one split of `data` (index into `SPLIT_NAMES`), or on all of it
+    pub fn evaluate(
+        &self,
+        data: &Labelled,
+        split: Option<usize>,
+    ) -> Result<Metrics, Box<dyn Error>> {
+        if let Some(r) = data.records.first() {
+            self.check_grid(r.sample.v.len())?;
+        }
+        let (errors, relative): (Vec<f64>, Vec<f64>) = data
+            .rows(split)
+            .map(|(s, y)| {
+                let z = self.predict_raw(s);
+                (z - self.forward(y), (self.backward(z) / y - 1f64).abs())
+            })
+            .unzip();
+        let n = errors.len();
+        let truth: Vec<f64> = data.rows(split).map(|(_, y)| self.forward(y)).collect();
+        let (_, std) = mean_std(truth.iter().copied());
+        let mse = errors.iter().map(|e| e * e).sum::<f64>() / n as f64;
+        let mut relative = relative;
+        relative.sort_by(|a, b| a.total_cmp(b));
+        Ok(Metrics {
+            n,
+            rmse: mse.sqrt(),
+            r2: 1f64 - mse / (std * std),
+            median_rel: quantile(&relative, 0.5),
+            q95_rel: quantile(&relative, 0.95),
+            max_rel: relative.last().copied().unwrap_or(f64::NAN),
+        })
+    }
+
+    /// Write the parameters and predicted target of every sample of `dataset`
+    pub fn write_predictions(
+        &self,
+        dataset: &Dataset,
+        path: &str,
+    ) -> Result<usize, Box<dyn Error>> {
+        self.check_grid(dataset.grid_len())?;
+        let mut columns: Vec<Vec<f64>> = vec![vec![]; 5];
+        for record in dataset.records() {
+            let s = record?.sample;
+            let row = s.params.to_array().into_iter().chain([self.predict(&s)]);
+            for (column, x) in columns.iter_mut().zip(row) {
+                column.push(x);
+            }
+        }
+        let n = columns[0].len();
+        let names = C1Param::ALL
+            .iter()
+            .map(|p| p.name().to_string())
+            .chain([format!("{}_pred", self.spec.target)]);
+        let metadata = vec![
+            (
+                "bounce.surrogate.model".to_string(),
+                self.spec.model.to_string(),
+            ),
+            (
+                "bounce.surrogate.inputs".to_string(),
+                self.spec.inputs.to_string(),
+            ),
+        ];
+        write_parquet(
+            path,
+            names.zip(columns).collect(),
+            metadata,
+            Codec::Uncompressed.options(),
+        )?;
+        Ok(n)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        let mut lines = vec![
+            format!("model={}", self.spec.model),
+            format!("inputs={}", self.spec.inputs),
+            format!("target={}", self.spec.target),
+            format!("log={}", self.spec.log),
+            format!("lambda={}", self.spec.lambda),
+            format!("max_train={}", self.spec.max_train),
+            format!("n_train={}", self.n_train),
+            format!("x_mean={}", join(self.x_scale.iter().map(|s| s.0))),
+            format!("x_std={}", join(self.x_scale.iter().map(|s| s.1))),
+            format!("y_scale={}", join([self.y_scale.0, self.y_scale.1])),
+            format!("weights={}", join(self.weights.iter().copied())),
+        ];
+        if let Some((mean, components)) = &self.projection {
+            lines.push(format!("pca_mean={}", join(mean.iter().copied())));
+            lines.extend(
+                components
+                    .iter()
+                    .map(|u| format!("pca_component={}", join(u.iter().copied()))),
+            );
+        }
+        lines.extend(
+            self.centres
+                .iter()
+                .map(|c| format!("centre={}", join(c.iter().copied()))),
+        );
+        fs::write(path, lines.join("\n") + "\n")?;
+        Ok(())
+    }
+
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        let text =
+            fs::read_to_string(path).map_err(|e| format!("cannot read model {}: {}", path, e))?;
+        let mut spec = SurrogateSpec::default();
+        let mut model = Self {
+            spec: spec.clone(),
+            n_train: 0,
+            projection: None,
+            x_scale: vec![],
+            y_scale: (0f64, 1f64),
+            weights: vec![],
+            centres: vec![],
+        };
+        let (mut x_mean, mut x_std, mut pca_mean, mut components) = (vec![], vec![], None, vec![]);
+        for line in text.lines() {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("invalid model line '{}'", line))?;
+            let invalid = |e: &dyn fmt::Display| format!("invalid {} in model: {}", key, e);
+            match key {
+                "model" => spec.model = value.parse()?,
+                "inputs" => spec.inputs = value.parse()?,
+                "target" => spec.target = value.to_string(),
+                "log" => spec.log = value.parse().map_err(|e| invalid(&e))?,
+                "lambda" => spec.lambda = value.parse().map_err(|e| invalid(&e))?,
+                "max_train" => spec.max_train = value.parse().map_err(|e| invalid(&e))?,
+                "n_train" => model.n_train = value.parse().map_err(|e| invalid(&e))?,
+                "x_mean" => x_mean = split(value)?,
+                "x_std" => x_std = split(value)?,
+                "y_scale" => match split(value)?[..] {
+                    [m, s] => model.y_scale = (m, s),
+                    _ => return Err(invalid(&"expected two values").into()),
+                },
+                "weights" => model.weights = split(value)?,
+                "pca_mean" => pca_mean = Some(split(value)?),
+                "pca_component" => components.push(split(value)?),
+                "centre" => model.centres.push(split(value)?),
+                _ => return Err(format!("unknown model key '{}'", key).into()),
+            }
+        }
+        let n_inputs = match spec.inputs {
+            Inputs::Params => 4,
+            Inputs::Pca { k } => {
+                let mean = pca_mean.ok_or("model without its PCA mean")?;
+                if components.len() != k || components.iter().any(|u| u.len() != mean.len()) {
+                    return Err("PCA components of the model do not match its inputs".into());
+                }
+                model.projection = Some((mean, components));
+                k
+            }
+        };
+        let n_weights = match spec.model {
+            ModelKind::Polynomial { degree } => monomials(n_inputs, degree).len(),
+            ModelKind::KernelRidge { .. } => model.centres.len(),
+        };
+        if x_mean.len() != n_inputs
+            || x_std.len() != n_inputs
+            || model.weights.len() != n_weights
+            || model.centres.iter().any(|c| c.len() != n_inputs)
+        {
+            return Err(format!("{} does not hold a complete {} model", path, spec.model).into());
+        }
+        model.x_scale = x_mean.into_iter().zip(x_std).collect();
+        model.spec = spec;
+        Ok(model)
+    }
+}
+
+impl fmt::Display for Surrogate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let size = match self.spec.model {
+            ModelKind::Polynomial { .. } => format!("{} coefficients", self.weights.len()),
+            ModelKind::KernelRidge { .. } => format!("{} centres", self.centres.len()),
+        };
+        let target = if self.spec.log {
+            format!("ln {}", self.spec.target)
+        } else {
+            self.spec.target.clone()
+        };
+        write!(
+            f,
+            "{} on {} for {} ({}, {} training samples)",
+            self.spec.model, self.spec.inputs, target, size, self.n_train
+        )
+    }
+}
+
+fn mean_std(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let values: Vec<f64> = values.collect();
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let var = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    // Constant inputs are left unscaled
+    (mean, if var > 0f64 { var.sqrt() } else { 1f64 })
+}
+
+/// Exponents of all monomials in `n` variables of total degree at most `degree`
+fn monomials(n: usize, degree: usize) -> Vec<Vec<usize>> {
+    let mut powers = vec![vec![]];
+    for _ in 0..n {
+        powers = powers
+            .into_iter()
+            .flat_map(|p: Vec<usize>| {
+                let used: usize = p.iter().sum();
+                (0..=degree - used).map(move |e| {
+                    let mut q = p.clone();
+                    q.push(e);
+                    q
+                })
+            })
+            .collect();
+    }
+    powers
+}
+
+fn evaluate_monomials(powers: &[Vec<usize>], x: &[f64]) -> Vec<f64> {
+    powers
+        .iter()
+        .map(|p| p.iter().zip(x).map(|(&e, x)| x.powi(e as i32)).product())
+        .collect()
+}
+
+fn gaussian(a: &[f64], b: &[f64], length: f64) -> f64 {
+    let d2: f64 = a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum();
+    (-0.5 * d2 / (length * length)).exp()
+}
+
+fn join(xs: impl IntoIterator<Item = f64>) -> String {
+    xs.into_iter()
+        .map(|x| format!("{:e}", x))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn split(s: &str) -> Result<Vec<f64>, String> {
+    if s.is_empty() {
+        return Ok(vec![]);
+    }
+    s.split(',')
+        .map(|x| {
+            x.parse()
+                .map_err(|e| format!("invalid number '{}' in model: {}", x, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c1::C1Params;
+    use crate::config::RunConfig;
+    use crate::generate::generate;
+    use crate::sampler::{params_from_unit, SamplerKind};
+    use crate::testutil::TempDir;
+
+    /// Curves of `n` random points on a shared grid, every fourth in the test split
+    fn synthetic(n: usize, target: fn(&C1Params) -> f64) -> Labelled {
+        let grid = linspace(0, 1, 50);
+        let records: Vec<Record> = SamplerKind::Random
+            .build(7, false)
+            .draw(0, n)
+            .into_iter()
+            .enumerate()
+            .map(|(i, u)| {
+                let params = params_from_unit(u);
+                let sample = Sample {
+                    params,
+                    grid: None,
+                    v: grid.fmap(params.potential()),
+                    dv: grid.fmap(params.deriv()),
+                    weight: 1f64,
+                };
+                let split = if i % 4 == 3 { 2f64 } else { 0f64 };
+                Record {
+                    sample,
+                    labels: vec![split],
+                }
+            })
+            .collect();
+        Labelled {
+            targets: records.iter().map(|r| target(&r.sample.params)).collect(),
+            records,
+            grid: Some(grid),
+            split: Some(0),
+        }
+    }
+
+    /// Positive target whose logarithm is quadratic in the parameters
+    fn smooth(p: &C1Params) -> f64 {
+        (1f64 + p.phi_0 - 0.5 * p.phi_2 + p.phi_1n * p.phi_1p).exp()
+    }
+
+    /// The polynomial is exact for this target; kernel ridge only has to interpolate it
+    #[test]
+    fn fits_smooth_target() {
+        let data = synthetic(400, smooth);
+        for (model, tol) in [
+            (ModelKind::Polynomial { degree: 2 }, 1e-6),
+            (ModelKind::KernelRidge { length: 2f64 }, 5e-2),
+        ] {
+            let spec = SurrogateSpec {
+                model,
+                inputs: Inputs::Params,
+                target: "smooth".to_string(),
+                ..SurrogateSpec::default()
+            };
+            let surrogate = Surrogate::train(&data, spec).unwrap();
+            assert_eq!(surrogate.n_train, 300);
+            let metrics = surrogate.evaluate(&data, Some(2)).unwrap();
+            assert_eq!(metrics.n, 100);
+            assert!(metrics.max_rel < tol, "{}: {}", model, metrics);
+            assert!(metrics.r2 > 1f64 - tol * tol, "{}: {}", model, metrics);
+        }
+    }
+
+    #[test]
+    fn saved_model_predicts_exactly() {
+        let data = synthetic(60, smooth);
+        let dir = TempDir::new("surrogate-round-trip");
+        for model in [
+            ModelKind::Polynomial { degree: 2 },
+            ModelKind::KernelRidge { length: 0.7 },
+        ] {
+            let spec = SurrogateSpec {
+                model,
+                inputs: Inputs::Pca { k: 3 },
+                target: "smooth".to_string(),
+                ..SurrogateSpec::default()
+            };
+            let surrogate = Surrogate::train(&data, spec).unwrap();
+            let path = dir.file(&format!("{}.txt", model));
+            surrogate.save(&path).unwrap();
+            let loaded = Surrogate::load(&path).unwrap();
+            assert_eq!(loaded, surrogate);
+            for r in &data.records {
+                assert_eq!(
+                    loaded.predict(&r.sample).to_bits(),
+                    surrogate.predict(&r.sample).to_bits()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_polynomial_with_more_coefficients_than_samples() {
+        let dir = TempDir::new("surrogate-overfit");
+        let path = dir.file("train.parquet");
+        let config = RunConfig {
+            n_samples: 20,
+            output: path.clone(),
+            ..RunConfig::default()
+        };
+        generate(&config).unwrap();
+        let records = Dataset::open(&path).unwrap().load().unwrap();
+        let targets = records
+            .iter()
+            .map(|r| r.sample.params.to_array().iter().sum::<f64>().exp())
+            .collect();
+        let data = Labelled {
+            records,
+            targets,
+            grid: None,
+            split: None,
+        };
+
+        let spec = |degree: usize| SurrogateSpec {
+            model: ModelKind::Polynomial { degree },
+            inputs: Inputs::Params,
+            ..SurrogateSpec::default()
+        };
+        // 70 coefficients at degree 4, 15 at degree 2
+        let err = Surrogate::train(&data, spec(4)).unwrap_err().to_string();
+        assert!(err.contains("70 coefficients"), "{}", err);
+        let model = Surrogate::train(&data, spec(2)).unwrap();
+        assert_eq!(model.n_train, 20);
+        assert_eq!(model.weights.len(), 15);
+    }
+}
@@ -0,0 +1,272 @@
+use crate::features::FeatureKind;
+use std::fmt;
+use std::str::FromStr;
+
+/// Target histogram over a feature, written `feature:lo:hi:bins` with optional
+/// comma-separated relative bin weights (`log_barrier:-2:-0.5:15`, `thin_wall:0:2:4:1,2,2,1`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetSpec {
+    pub feature: FeatureKind,
+    pub lo: f64,
+    pub hi: f64,
+    /// Relative weight of each bin; uniform if not given
+    pub weights: Vec<f64>,
+}
+
+impl TargetSpec {
+    pub fn bins(&self) -> usize {
+        self.weights.len()
+    }
+
+    pub fn bin(&self, x: f64) -> Option<usize> {
+        if !(x >= self.lo && x < self.hi) {
+            return None;
+        }
+        let b = ((x - self.lo) / (self.hi - self.lo) * self.bins() as f64) as usize;
+        Some(b.min(self.bins() - 1))
+    }
+
+    /// Number of samples per bin for a dataset of size `n` (largest remainder rounding)
+    pub fn quotas(&self, n: usize) -> Vec<usize> {
+        let total: f64 = self.weights.iter().sum();
+        let exact: Vec<f64> = self.weights.iter().map(|w| w / total * n as f64).collect();
+        let mut quotas: Vec<usize> = exact.iter().map(|x| x.floor() as usize).collect();
+        let mut order: Vec<usize> = (0..exact.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (ra, rb) = (exact[a] - exact[a].floor(), exact[b] - exact[b].floor());
+            rb.total_cmp(&ra)
+        });
+        let missing = n - quotas.iter().sum::<usize>();
+        for &b in order.iter().take(missing) {
+            quotas[b] += 1;
+        }
+        quotas
+    }
+}
+
+impl fmt::Display for TargetSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let weights: Vec<String> = self.weights.iter().map(|w| w.to_string()).collect();
+        write!(
+            f,
+            "{}:{}:{}:{}:{}",
+            self.feature,
+            self.lo,
+            self.hi,
+            self.bins(),
+            weights.join(",")
+        )
+    }
+}
+
+impl FromStr for TargetSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        if parts.len() != 4 && parts.len() != 5 {
+            return Err(format!(
+                "invalid target '{}' (expected feature:lo:hi:bins[:w1,w2,...])",
+                s
+            ));
+        }
+        let feature = parts[0].parse()?;
+        let num = |x: &str| {
+            x.parse::<f64>()
+                .map_err(|e| format!("invalid number '{}' in target: {}", x, e))
+        };
+        let (lo, hi) = (num(parts[1])?, num(parts[2])?);
+        if !lo.is_finite() || !hi.is_finite() {
+            return Err(format!("target range [{}, {}) must be finite", lo, hi));
+        }
+        if lo >= hi {
+            return Err(format!("target range [{}, {}) is empty", lo, hi));
+        }
+        let bins = parts[3]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid bin count in target: {}", e))?;
+        if bins == 0 {
+            return Err("target needs at least one bin".to_string());
+        }
+        let weights = match parts.get(4) {
+            Some(ws) => ws.split(',').map(num).collect::<Result<Vec<f64>, _>>()?,
+            None => vec![1f64; bins],
+        };
+        if weights.len() != bins || weights.iter().any(|&w| !w.is_finite() || w < 0f64) {
+            return Err(format!(
+                "target needs {} finite non-negative weights, got '{}'",
+                bins,
+                parts.get(4).unwrap_or(&"")
+            ));
+        }
+        if weights.iter().all(|&w| w == 0f64) {
+            return Err("target weights must not all be zero".to_string());
+        }
+        Ok(TargetSpec {
+            feature,
+            lo,
+            hi,
+            weights,
+        })
+    }
+}
+
+/// Adaptive rejection towards a target histogram
+///
+/// Candidates are offered in a fixed order and kept while their bin is below its quota.
+/// Every offer is counted, so the natural distribution of the feature is known at the end
+/// and each kept sample can be given the importance weight that undoes the targeting.
+#[derive(Debug, Clone)]
+pub struct QuotaFilter {
+    spec: TargetSpec,
+    quotas: Vec<usize>,
+    kept: Vec<usize>,
+    seen: Vec<usize>,
+}
+
+impl QuotaFilter {
+    pub fn new(spec: TargetSpec, n: usize) -> Self {
+        let bins = spec.bins();
+        Self {
+            quotas: spec.quotas(n),
+            spec,
+            kept: vec![0; bins],
+            seen: vec![0; bins],
+        }
+    }
+
+    pub fn feature(&self) -> FeatureKind {
+        self.spec.feature
+    }
+
+    /// Offer a candidate with feature value `x`; returns its bin if it is kept
+    pub fn offer(&mut self, x: f64) -> Option<usize> {
+        let b = self.spec.bin(x)?;
+        self.seen[b] += 1;
+        if self.kept[b] < self.quotas[b] {
+            self.kept[b] += 1;
+            Some(b)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.kept == self.quotas
+    }
+
+    pub fn quotas(&self) -> &[usize] {
+        &self.quotas
+    }
+
+    pub fn kept(&self) -> &[usize] {
+        &self.kept
+    }
+
+    /// Candidates offered so far in each bin
+    pub fn seen(&self) -> &[usize] {
+        &self.seen
+    }
+
+    /// Continue from counters saved by `seen` and `kept`
+    pub fn restore(&mut self, seen: Vec<usize>, kept: Vec<usize>) -> Result<(), String> {
+        if seen.len() != self.seen.len() || kept.len() != self.kept.len() {
+            return Err("saved target counters do not match the number of bins".to_string());
+        }
+        self.seen = seen;
+        self.kept = kept;
+        Ok(())
+    }
+
+    /// Importance weight of a sample in each bin: natural over realized bin probability,
+    /// normalized so the weights of all kept samples average to one
+    pub fn weights(&self) -> Vec<f64> {
+        let seen: f64 = self.seen.iter().sum::<usize>() as f64;
+        let kept: f64 = self.kept.iter().sum::<usize>() as f64;
+        self.seen
+            .iter()
+            .zip(&self.kept)
+            .map(|(&s, &k)| {
+                if k == 0 {
+                    0f64
+                } else {
+                    (s as f64 / seen) / (k as f64 / kept)
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(weights: &[f64]) -> TargetSpec {
+        TargetSpec {
+            feature: FeatureKind::ThinWall,
+            lo: 0f64,
+            hi: 4f64,
+            weights: weights.to_vec(),
+        }
+    }
+
+    #[test]
+    fn bins_and_quotas() {
+        let s = spec(&[1f64, 2f64, 2f64, 1f64]);
+        assert_eq!(s.bin(0f64), Some(0));
+        assert_eq!(s.bin(0.99), Some(0));
+        assert_eq!(s.bin(1f64), Some(1));
+        assert_eq!(s.bin(3.99), Some(3));
+        assert_eq!(s.bin(4f64), None);
+        assert_eq!(s.bin(-0.01), None);
+        assert_eq!(s.bin(f64::NAN), None);
+        // Exact shares 1.67, 3.33, 3.33, 1.67: the two leftover samples go to the outer bins
+        assert_eq!(s.quotas(10), vec![2, 3, 3, 2]);
+        assert_eq!(s.quotas(6), vec![1, 2, 2, 1]);
+        assert_eq!(spec(&[1f64, 0f64, 1f64]).quotas(5), vec![3, 0, 2]);
+    }
+
+    #[test]
+    fn parses_and_rejects_specs() {
+        let s: TargetSpec = "thin_wall:0:4:4:1,2,2,1".parse().unwrap();
+        assert_eq!(s, spec(&[1f64, 2f64, 2f64, 1f64]));
+        assert_eq!(s.to_string().parse::<TargetSpec>().unwrap(), s);
+        for bad in [
+            "thin_wall:0:4:4:0,0,0,0",
+            "thin_wall:0:4:2:1,NaN",
+            "thin_wall:0:4:2:1,inf",
+            "thin_wall:0:4:2:1,-1",
+            "thin_wall:0:inf:2",
+            "thin_wall:4:0:2",
+        ] {
+            assert!(bad.parse::<TargetSpec>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn weights_undo_the_targeting() {
+        // Natural bin probabilities 3/8, 1/8, 2/8, 2/8 against a uniform target of 2 per bin
+        let mut filter = QuotaFilter::new(spec(&[1f64; 4]), 8);
+        let cycle = [0.5, 0.5, 0.5, 1.5, 2.5, 2.5, 3.5, 3.5];
+        let mut kept = vec![];
+        for _ in 0..100 {
+            for x in cycle {
+                if let Some(b) = filter.offer(x) {
+                    kept.push(b);
+                }
+            }
+        }
+        assert!(filter.offer(5f64).is_none());
+        assert!(filter.is_full());
+        assert_eq!(filter.seen(), &[300, 100, 200, 200]);
+        let weights = filter.weights();
+        assert_eq!(weights, vec![1.5, 0.5, 1f64, 1f64]);
+
+        let total: f64 = kept.iter().map(|&b| weights[b]).sum();
+        assert_eq!(total, kept.len() as f64);
+        for (b, natural) in [0.375, 0.125, 0.25, 0.25].into_iter().enumerate() {
+            let share: f64 = kept.iter().filter(|&&k| k == b).map(|&k| weights[k]).sum();
+            assert_eq!(share / total, natural);
+        }
+    }
+}
@@ -0,0 +1,632 @@
+use crate::batch::c1_coefficients;
+use crate::bounce::{solve_bounce, Bounce, BounceOptions};
+use crate::c1::C1Params;
+use crate::output::{write_parquet, Codec};
+use peroxide::fuga::*;
+use rayon::prelude::*;
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// ln a_b and ln a_f of the high-temperature expansions, with a_b = 16 π² e^{3/2 - 2γ_E}
+/// and a_f = π² e^{3/2 - 2γ_E}
+const LN_A_B: f64 = 5.407_633_400_685_24;
+const LN_A_F: f64 = 2.635_044_679_445_458;
+
+/// Largest m/T of the exact tables; beyond it the thermal functions are below 1e-15
+const Y_MAX: f64 = 40.0;
+const N_TABLE: usize = 2001;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Statistics {
+    Boson,
+    Fermion,
+}
+
+/// How the thermal functions are evaluated
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ThermalApprox {
+    /// Expansion in y² = m²/T² up to y⁴ ln y², accurate for m ≲ 2T (bosons) or m ≲ T (fermions)
+    HighT,
+    /// Numerical integrals, tabulated once for 0 ≤ m/T ≤ 40 and interpolated
+    Exact,
+}
+
+impl fmt::Display for ThermalApprox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThermalApprox::HighT => write!(f, "high_t"),
+            ThermalApprox::Exact => write!(f, "exact"),
+        }
+    }
+}
+
+impl FromStr for ThermalApprox {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high_t" => Ok(ThermalApprox::HighT),
+            "exact" => Ok(ThermalApprox::Exact),
+            _ => Err(format!(
+                "unknown thermal approximation '{}' (expected high_t or exact)",
+                s
+            )),
+        }
+    }
+}
+
+/// `∫_0^{x_max} f(x) dx` by 16-point Gauss–Legendre on panels uniform in t = sqrt(x),
+/// which smooths the x² ln x behaviour of the massless integrands at x = 0
+fn quad<F: Fn(f64) -> f64 + Copy>(f: F, x_max: f64) -> f64 {
+    const PANELS: usize = 16;
+    let h = x_max.sqrt() / PANELS as f64;
+    (0..PANELS)
+        .map(|i| {
+            let (a, b) = (i as f64 * h, (i + 1) as f64 * h);
+            integrate(move |t: f64| 2f64 * t * f(t * t), (a, b), GaussLegendre(20))
+        })
+        .sum()
+}
+
+/// `∫_0^∞ x² ln(1 + sign exp(-sqrt(x² + y²))) dx`, i.e. J_B for `sign = -1` and J_F for
+/// `sign = 1`, with its derivative w.r.t. y², and the derivatives of both w.r.t. y; the
+/// integrals are cut where the integrand is below e^{-40}
+fn j_integral(y: f64, sign: f64) -> [f64; 4] {
+    let x_max = ((y + 40f64).powi(2) - y * y).sqrt();
+    let e = move |x: f64| (x * x + y * y).sqrt();
+    // Occupation number 1 / (e^E ∓ 1)
+    let n = move |e: f64| 1f64 / (e.exp() + sign);
+    let j = quad(move |x| x * x * (sign * (-e(x)).exp()).ln_1p(), x_max);
+    let dj = quad(
+        move |x| {
+            if x == 0f64 {
+                0f64
+            } else {
+                -sign * x * x * n(e(x)) / (2f64 * e(x))
+            }
+        },
+        x_max,
+    );
+    let d2j = if y == 0f64 {
+        // Limits of the integral below, which vanishes pointwise but not uniformly
+        if sign < 0f64 {
+            -PI / 4f64
+        } else {
+            0f64
+        }
+    } else {
+        quad(
+            move |x| {
+                let (e, n) = (e(x), n(e(x)));
+                sign * y * x * x * n * (e.exp() * n * e + 1f64) / (2f64 * e.powi(3))
+            },
+            x_max,
+        )
+    };
+    [j, 2f64 * y * dj, dj, d2j]
+}
+
+/// Cubic Hermite interpolation of a function of y = m/T tabulated with its derivative on a
+/// uniform grid
+struct Table {
+    step: f64,
+    values: Vec<f64>,
+    slopes: Vec<f64>,
+}
+
+impl Table {
+    fn eval(&self, y: f64) -> f64 {
+        let i = ((y / self.step) as usize).min(self.values.len() - 2);
+        let t = y / self.step - i as f64;
+        let (h00, h10) = (
+            (1f64 + 2f64 * t) * (1f64 - t).powi(2),
+            t * (1f64 - t).powi(2),
+        );
+        let (h01, h11) = (t * t * (3f64 - 2f64 * t), t * t * (t - 1f64));
+        h00 * self.values[i]
+            + h10 * self.step * self.slopes[i]
+            + h01 * self.values[i + 1]
+            + h11 * self.step * self.slopes[i + 1]
+    }
+}
+
+/// J and dJ/dy² against y for bosons and fermions
+struct Tables {
+    j_b: Table,
+    dj_b: Table,
+    j_f: Table,
+    dj_f: Table,
+}
+
+fn tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        let step = Y_MAX / (N_TABLE - 1) as f64;
+        let tabulate = |sign: f64| {
+            let rows: Vec<[f64; 4]> = (0..N_TABLE)
+                .into_par_iter()
+                .map(|i| j_integral(i as f64 * step, sign))
+                .collect();
+            let column = |k: usize| rows.iter().map(|r| r[k]).collect();
+            (
+                Table {
+                    step,
+                    values: column(0),
+                    slopes: column(1),
+                },
+                Table {
+                    step,
+                    values: column(2),
+                    slopes: column(3),
+                },
+            )
+        };
+        let (j_b, dj_b) = tabulate(-1f64);
+        let (j_f, dj_f) = tabulate(1f64);
+        Tables {
+            j_b,
+            dj_b,
+            j_f,
+            dj_f,
+        }
+    })
+}
+
+/// `y⁴ ln|y²|` and its derivative w.r.t. y², continued to 0 at y = 0
+fn log_term(y2: f64, ln_a: f64) -> (f64, f64) {
+    if y2 == 0f64 {
+        return (0f64, 0f64);
+    }
+    let l = y2.abs().ln() - ln_a;
+    (y2 * y2 * l, 2f64 * y2 * l + y2)
+}
+
+/// Bosonic thermal function J_B(y²) and dJ_B/dy²
+///
+/// Negative y² (tachyonic masses) take the real part of the high-T expansion in both modes.
+pub fn j_b(y2: f64, approx: ThermalApprox) -> (f64, f64) {
+    if approx == ThermalApprox::Exact && y2 >= 0f64 {
+        let y = y2.sqrt();
+        if y >= Y_MAX {
+            return (0f64, 0f64);
+        }
+        let t = tables();
+        return (t.j_b.eval(y), t.dj_b.eval(y));
+    }
+    let (l, dl) = log_term(y2, LN_A_B);
+    let y = y2.max(0f64).sqrt();
+    (
+        -PI.powi(4) / 45f64 + PI * PI / 12f64 * y2 - PI / 6f64 * y * y * y - l / 32f64,
+        PI * PI / 12f64 - PI / 4f64 * y - dl / 32f64,
+    )
+}
+
+/// Fermionic thermal function J_F(y²) (positive, with ln(1 + e^{-E})) and dJ_F/dy²
+pub fn j_f(y2: f64, approx: ThermalApprox) -> (f64, f64) {
+    if approx == ThermalApprox::Exact && y2 >= 0f64 {
+        let y = y2.sqrt();
+        if y >= Y_MAX {
+            return (0f64, 0f64);
+        }
+        let t = tables();
+        return (t.j_f.eval(y), t.dj_f.eval(y));
+    }
+    let (l, dl) = log_term(y2, LN_A_F);
+    (
+        7f64 * PI.powi(4) / 360f64 - PI * PI / 24f64 * y2 - l / 32f64,
+        -PI * PI / 24f64 - dl / 32f64,
+    )
+}
+
+/// Particles with a field-dependent mass `m²(φ) = Σ_k c_k φ^k`, written
+/// `boson:dof:c_0,c_1,...` or `fermion:dof:c_0,c_1,...`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Species {
+    pub statistics: Statistics,
+    /// Degrees of freedom, e.g. 6 for W± or 12 for the top quark
+    pub dof: f64,
+    pub mass2: Vec<f64>,
+}
+
+impl Species {
+    /// m²(φ) and dm²/dφ
+    pub fn mass2(&self, phi: f64) -> (f64, f64) {
+        self.mass2
+            .iter()
+            .rev()
+            .fold((0f64, 0f64), |(m, dm), &c| (m * phi + c, dm * phi + m))
+    }
+}
+
+impl fmt::Display for Species {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let statistics = match self.statistics {
+            Statistics::Boson => "boson",
+            Statistics::Fermion => "fermion",
+        };
+        let coefficients: Vec<String> = self.mass2.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}:{}:{}", statistics, self.dof, coefficients.join(","))
+    }
+}
+
+impl FromStr for Species {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        let expected = || {
+            format!(
+                "invalid species '{}' (expected boson|fermion:dof:c_0,c_1,...)",
+                s
+            )
+        };
+        if parts.len() != 3 {
+            return Err(expected());
+        }
+        let statistics = match parts[0] {
+            "boson" => Statistics::Boson,
+            "fermion" => Statistics::Fermion,
+            _ => return Err(expected()),
+        };
+        let dof: f64 = parts[1].parse().map_err(|_| expected())?;
+        let mass2 = parts[2]
+            .split(',')
+            .map(|c| c.parse::<f64>())
+            .collect::<Result<Vec<f64>, _>>()
+            .map_err(|_| expected())?;
+        if dof.is_nan() || dof <= 0f64 {
+            return Err(format!(
+                "the degrees of freedom of '{}' must be positive",
+                s
+            ));
+        }
+        Ok(Self {
+            statistics,
+            dof,
+            mass2,
+        })
+    }
+}
+
+/// One-loop finite-temperature effective potential around a C1 tree potential
+///
+/// `V(φ, T) = V_C1(φ / v) + T⁴ / (2π²) [Σ_b n_b J_B(m_b²(φ) / T²) - Σ_f n_f J_F(m_f²(φ) / T²)]`
+///
+/// The field scale v is in units of the energy scale of the tree potential (|V_C1(1)| = 1), in
+/// which φ, T and the masses are measured; actions grow as v³, so v ≫ 1 reaches the
+/// S_3/T ~ 100 of cosmological transitions away from the thin-wall limit.
+///
+/// The zero-temperature Coleman–Weinberg correction is taken to be part of the tree shape.
+/// The field-independent T⁴ terms are kept, so differences of V between phases are
+/// free-energy (pressure) differences of the species included.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalPotential {
+    pub tree: C1Params,
+    pub species: Vec<Species>,
+    pub approx: ThermalApprox,
+    /// Field value of the true vacuum of the tree potential
+    pub scale: f64,
+    /// Field interval searched for minima
+    pub range: (f64, f64),
+    coefficients: [f64; 6],
+}
+
+/// Phases at one temperature: the minimum the transition starts from and the deepest other one
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Phases {
+    pub phi_false: f64,
+    pub phi_true: f64,
+    /// `V(phi_false, T) - V(phi_true, T)`, negative above the critical temperature
+    pub delta_v: f64,
+}
+
+impl ThermalPotential {
+    pub fn new(tree: C1Params, species: Vec<Species>, approx: ThermalApprox) -> Self {
+        Self {
+            tree,
+            species,
+            approx,
+            scale: 1f64,
+            range: (0f64, 1.5),
+            coefficients: c1_coefficients(&tree),
+        }
+    }
+
+    /// The same potential with field scale `scale`, searching minima in `[0, 1.5 scale]`
+    pub fn with_scale(self, scale: f64) -> Self {
+        Self {
+            scale,
+            range: (0f64, 1.5 * scale),
+            ..self
+        }
+    }
+
+    fn tree(&self, phi: f64) -> (f64, f64) {
+        let a = &self.coefficients;
+        let x = phi / self.scale;
+        let v = x * x * a.iter().rev().fold(0f64, |acc, &c| acc * x + c);
+        let dv = x * a
+            .iter()
+            .enumerate()
+            .rev()
+            .fold(0f64, |acc, (k, &c)| acc * x + (k + 2) as f64 * c);
+        (v, dv / self.scale)
+    }
+
+    /// V(φ, T) and ∂V/∂φ
+    pub fn eval(&self, phi: f64, t: f64) -> (f64, f64) {
+        let (mut v, mut dv) = self.tree(phi);
+        if t == 0f64 {
+            return (v, dv);
+        }
+        let (t2, t4) = (t * t, t.powi(4));
+        for s in &self.species {
+            let (m2, dm2) = s.mass2(phi);
+            let (j, dj) = match s.statistics {
+                Statistics::Boson => j_b(m2 / t2, self.approx),
+                Statistics::Fermion => {
+                    let (j, dj) = j_f(m2 / t2, self.approx);
+                    (-j, -dj)
+                }
+            };
+            v += s.dof * t4 / (2f64 * PI * PI) * j;
+            dv += s.dof * t2 / (2f64 * PI * PI) * dj * dm2;
+        }
+        (v, dv)
+    }
+
+    pub fn v(&self, phi: f64, t: f64) -> f64 {
+        self.eval(phi, t).0
+    }
+
+    pub fn dv(&self, phi: f64, t: f64) -> f64 {
+        self.eval(phi, t).1
+    }
+
+    /// Local minima in `range` at temperature `t`, in increasing φ
+    pub fn minima(&self, t: f64) -> Vec<f64> {
+        const N_SCAN: usize = 1000;
+        let (lo, hi) = self.range;
+        let xs: Vec<f64> = (0..=N_SCAN)
+            .map(|i| lo + (hi - lo) * i as f64 / N_SCAN as f64)
+            .collect();
+        let ds: Vec<f64> = xs.iter().map(|&x| self.dv(x, t)).collect();
+        let mut minima = vec![];
+        // A minimum at the lower end with V' >= 0 (e.g. the symmetric phase at φ = 0)
+        if ds[0] >= 0f64 && ds[1] > 0f64 {
+            minima.push(lo);
+        }
+        for i in 0..N_SCAN {
+            if ds[i] < 0f64 && ds[i + 1] >= 0f64 {
+                let (mut a, mut b) = (xs[i], xs[i + 1]);
+                for _ in 0..60 {
+                    let mid = 0.5 * (a + b);
+                    if self.dv(mid, t) < 0f64 {
+                        a = mid;
+                    } else {
+                        b = mid;
+                    }
+                }
+                minima.push(0.5 * (a + b));
+            }
+        }
+        minima
+    }
+
+    /// The lowest-φ minimum (the high-temperature phase) and the deepest minimum past it
+    pub fn phases(&self, t: f64) -> Option<Phases> {
+        let minima = self.minima(t);
+        let phi_false = *minima.first()?;
+        let phi_true = minima[1..]
+            .iter()
+            .copied()
+            .min_by(|&a, &b| self.v(a, t).total_cmp(&self.v(b, t)))?;
+        Some(Phases {
+            phi_false,
+            phi_true,
+            delta_v: self.v(phi_false, t) - self.v(phi_true, t),
+        })
+    }
+
+    /// Bounce from the high-temperature phase at temperature `t` (O(3) for thermal transitions)
+    pub fn bounce(&self, t: f64, opts: BounceOptions) -> Result<Bounce, String> {
+        let phases = self
+            .phases(t)
+            .ok_or_else(|| format!("there are no two phases at T = {}", t))?;
+        let v = |phi: f64| self.v(phi, t);
+        let dv = |phi: f64| self.dv(phi, t);
+        solve_bounce(&v, &dv, phases.phi_false, phases.phi_true, opts)
+    }
+}
+
+/// Phases and O(3) bounce action at one temperature of a scan
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalPoint {
+    pub t: f64,
+    /// `None` with fewer than two minima
+    pub phases: Option<Phases>,
+    /// S_3, NaN above the critical temperature or where the solver fails
+    pub action: f64,
+}
+
+impl ThermalPoint {
+    pub fn action_over_t(&self) -> f64 {
+        self.action / self.t
+    }
+}
+
+/// Phases and actions at each of `temps`, solved in parallel
+pub fn scan_temperatures(
+    potential: &ThermalPotential,
+    temps: &[f64],
+    opts: BounceOptions,
+) -> Vec<ThermalPoint> {
+    temps
+        .par_iter()
+        .map(|&t| {
+            let phases = potential.phases(t);
+            let action = match phases {
+                Some(p) if p.delta_v > 0f64 => {
+                    potential.bounce(t, opts).map_or(f64::NAN, |b| b.action)
+                }
+                _ => f64::NAN,
+            };
+            ThermalPoint { t, phases, action }
+        })
+        .collect()
+}
+
+/// Evenly spaced temperatures, written `lo:hi:n`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TemperatureRange {
+    pub lo: f64,
+    pub hi: f64,
+    pub n: usize,
+}
+
+impl TemperatureRange {
+    pub fn values(&self) -> Vec<f64> {
+        linspace(self.lo, self.hi, self.n)
+    }
+}
+
+impl fmt::Display for TemperatureRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.lo, self.hi, self.n)
+    }
+}
+
+impl FromStr for TemperatureRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        if parts.len() != 3 {
+            return Err(format!("invalid temperatures '{}' (expected lo:hi:n)", s));
+        }
+        let num = |x: &str| {
+            x.parse::<f64>()
+                .map_err(|e| format!("invalid number '{}' in temperatures: {}", x, e))
+        };
+        let n = parts[2]
+            .parse::<usize>()
+            .map_err(|e| format!("invalid point count in temperatures '{}': {}", s, e))?;
+        let (lo, hi) = (num(parts[0])?, num(parts[1])?);
+        if n < 2 {
+            return Err(format!("temperatures '{}' need at least 2 points", s));
+        }
+        if lo <= 0f64 || lo >= hi {
+            return Err(format!("temperatures '{}' need 0 < lo < hi", s));
+        }
+        Ok(Self { lo, hi, n })
+    }
+}
+
+/// Write one row per temperature: phases, ΔV, S_3 and S_3/T (NaN where undefined)
+pub fn write_thermal(
+    path: &str,
+    points: &[ThermalPoint],
+    metadata: Vec<(String, String)>,
+) -> Result<(), Box<dyn Error>> {
+    let col = |f: &dyn Fn(&ThermalPoint) -> f64| -> Vec<f64> { points.iter().map(f).collect() };
+    let phase =
+        |f: fn(&Phases) -> f64| move |p: &ThermalPoint| p.phases.as_ref().map_or(f64::NAN, f);
+    let columns = vec![
+        ("t".to_string(), col(&|p| p.t)),
+        ("phi_false".to_string(), col(&phase(|p| p.phi_false))),
+        ("phi_true".to_string(), col(&phase(|p| p.phi_true))),
+        ("delta_v".to_string(), col(&phase(|p| p.delta_v))),
+        ("action".to_string(), col(&|p| p.action)),
+        ("action_over_t".to_string(), col(&|p| p.action_over_t())),
+    ];
+    write_parquet(path, columns, metadata, Codec::Uncompressed.options())
+}
+
+impl fmt::Display for ThermalPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.phases {
+            Some(p) => write!(
+                f,
+                "{:>10.5} {:>10.5} {:>10.5} {:>12.4e} {:>12.4e} {:>12.4e}",
+                self.t,
+                p.phi_false,
+                p.phi_true,
+                p.delta_v,
+                self.action,
+                self.action_over_t()
+            ),
+            None => write!(f, "{:>10.5} {:>10}", self.t, "one phase"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ZETA_3: f64 = 1.202_056_903_159_594_3;
+
+    #[test]
+    fn exact_tables_start_at_massless_values() {
+        let (b, _) = j_b(0f64, ThermalApprox::Exact);
+        let (f, _) = j_f(0f64, ThermalApprox::Exact);
+        assert!((b + PI.powi(4) / 45f64).abs() < 1e-13);
+        assert!((f - 7f64 * PI.powi(4) / 360f64).abs() < 1e-13);
+        assert_eq!(j_b(Y_MAX * Y_MAX, ThermalApprox::Exact), (0f64, 0f64));
+    }
+
+    /// The first term left out of the high-T expansions is `c y⁶` with c = ζ(3) / (384 π²)
+    /// for bosons and 7 times that for fermions
+    #[test]
+    fn high_t_expansions_agree_with_exact_tables() {
+        let c_b = ZETA_3 / (384f64 * PI * PI);
+        for (j, c) in [
+            (j_b as fn(f64, ThermalApprox) -> (f64, f64), c_b),
+            (j_f, 7f64 * c_b),
+        ] {
+            for y in [0.3, 0.5, 0.8] {
+                let y2 = y * y;
+                let (exact, d_exact) = j(y2, ThermalApprox::Exact);
+                let (high_t, d_high_t) = j(y2, ThermalApprox::HighT);
+                let (term, d_term) = (c * y2.powi(3), 3f64 * c * y2 * y2);
+                assert!(
+                    (exact - high_t - term).abs() < 0.05 * term,
+                    "y = {}: {} vs {}",
+                    y,
+                    exact - high_t,
+                    term
+                );
+                assert!(
+                    (d_exact - d_high_t - d_term).abs() < 0.05 * d_term,
+                    "y = {}: {} vs {}",
+                    y,
+                    d_exact - d_high_t,
+                    d_term
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn derivative_tables_match_finite_differences() {
+        for j in [j_b, j_f] {
+            for y in [0.05, 0.3, 1f64, 3f64, 10f64, 30f64] {
+                let y2 = y * y;
+                let h = 1e-4 * y2;
+                let fd = (j(y2 + h, ThermalApprox::Exact).0 - j(y2 - h, ThermalApprox::Exact).0)
+                    / (2f64 * h);
+                let d = j(y2, ThermalApprox::Exact).1;
+                assert!(
+                    (fd - d).abs() < 1e-6 * d.abs(),
+                    "y = {}: {} vs {}",
+                    y,
+                    fd,
+                    d
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use crate::bounce::{Bounce, BounceOptions};
+use crate::formats::json_number;
+use crate::thermal::{Phases, ThermalPotential};
+use rayon::prelude::*;
+use std::f64::consts::PI;
+use std::fmt;
+
+/// Settings of the search for the transition temperatures
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TransitionOptions {
+    /// S_3/T at nucleation; about 140 for electroweak-scale transitions
+    pub target: f64,
+    /// Relativistic degrees of freedom of the plasma, setting ρ_rad = π² g_* T⁴ / 30
+    pub g_star: f64,
+    /// Temperature step of the numerical derivatives at T_n, relative to T_c - T_n (or to T_n
+    /// if that is smaller), the scale on which S_3/T and ΔV change near a transition
+    pub step: f64,
+    /// Relative precision of T_c and T_n
+    pub tol: f64,
+    pub bounce: BounceOptions,
+}
+
+impl Default for TransitionOptions {
+    fn default() -> Self {
+        Self {
+            target: 140f64,
+            g_star: 106.75,
+            step: 1e-3,
+            tol: 1e-8,
+            bounce: BounceOptions {
+                dim: 3,
+                ..BounceOptions::default()
+            },
+        }
+    }
+}
+
+/// What the transition search needs of a temperature-dependent potential
+pub trait ThermalModel: Sync {
+    /// High-temperature phase and the deepest minimum past it, if there are two minima
+    fn phases(&self, t: f64) -> Option<Phases>;
+
+    /// O(3) bounce from the high-temperature phase
+    fn bounce(&self, t: f64, opts: BounceOptions) -> Result<Bounce, String>;
+}
+
+impl ThermalModel for ThermalPotential {
+    fn phases(&self, t: f64) -> Option<Phases> {
+        ThermalPotential::phases(self, t)
+    }
+
+    fn bounce(&self, t: f64, opts: BounceOptions) -> Result<Bounce, String> {
+        ThermalPotential::bounce(self, t, opts)
+    }
+}
+
+/// Critical temperature, where the two phases are degenerate
+///
+/// `temps` must be increasing; T_c is bisected in the first interval where ΔV changes from
+/// positive to non-positive.
+pub fn critical_temperature(
+    potential: &dyn ThermalModel,
+    temps: &[f64],
+    tol: f64,
+) -> Result<f64, String> {
+    let delta_v = |t: f64| potential.phases(t).map(|p| p.delta_v);
+    let values: Vec<Option<f64>> = temps.par_iter().map(|&t| delta_v(t)).collect();
+    let i = (1..temps.len())
+        .find(
+            |&i| matches!((values[i - 1], values[i]), (Some(a), Some(b)) if a > 0f64 && b <= 0f64),
+        )
+        .ok_or_else(|| {
+            format!(
+                "the phases do not become degenerate between T = {} and T = {}",
+                temps[0],
+                temps[temps.len() - 1]
+            )
+        })?;
+    let (mut lo, mut hi) = (temps[i - 1], temps[i]);
+    while hi - lo > tol * hi {
+        let mid = 0.5 * (lo + hi);
+        match delta_v(mid) {
+            Some(d) if d > 0f64 => lo = mid,
+            Some(_) => hi = mid,
+            None => {
+                return Err(format!(
+                    "a phase disappears at T = {} near the critical temperature",
+                    mid
+                ))
+            }
+        }
+    }
+    Ok(0.5 * (lo + hi))
+}
+
+/// S_3/T of the bounce from the high-temperature phase
+pub fn action_over_t(
+    potential: &dyn ThermalModel,
+    t: f64,
+    opts: BounceOptions,
+) -> Result<f64, String> {
+    potential.bounce(t, opts).map(|b| b.action / t)
+}
+
+/// Whether S_3/T is still above `target`, or an error if the false phase is gone
+///
+/// The solver fails close to T_c, where the bounce is deep in the thin-wall limit and the
+/// action diverges; such failures count as above the target.
+fn above(
+    potential: &dyn ThermalModel,
+    t: f64,
+    target: f64,
+    opts: BounceOptions,
+) -> Result<bool, String> {
+    match potential.phases(t) {
+        Some(p) if p.delta_v > 0f64 => {
+            Ok(action_over_t(potential, t, opts).map_or(true, |s| s > target))
+        }
+        _ => Err(format!(
+            "the high-temperature phase is not metastable at T = {}",
+            t
+        )),
+    }
+}
+
+/// Nucleation temperature, the highest temperature below `t_c` where S_3/T falls to the target
+///
+/// The grid points of `temps` below T_c are scanned downwards from T_c for the first one
+/// below the target, and T_n is bisected in the interval above it.
+pub fn nucleation_temperature(
+    potential: &dyn ThermalModel,
+    t_c: f64,
+    temps: &[f64],
+    opts: &TransitionOptions,
+) -> Result<f64, String> {
+    let mut below: Vec<f64> = temps.iter().copied().filter(|&t| t < t_c).collect();
+    below.reverse();
+    let flags: Vec<Result<bool, String>> = below
+        .par_iter()
+        .map(|&t| above(potential, t, opts.target, opts.bounce))
+        .collect();
+    let mut hi = t_c;
+    let mut lo = None;
+    for (&t, flag) in below.iter().zip(flags) {
+        if flag? {
+            hi = t;
+        } else {
+            lo = Some(t);
+            break;
+        }
+    }
+    let mut lo = lo.ok_or_else(|| {
+        format!(
+            "S_3/T stays above {} between T = {} and T_c = {}",
+            opts.target,
+            below.last().copied().unwrap_or(t_c),
+            t_c
+        )
+    })?;
+    while hi - lo > opts.tol * hi {
+        let mid = 0.5 * (lo + hi);
+        if above(potential, mid, opts.target, opts.bounce)? {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Ok(0.5 * (lo + hi))
+}
+
+/// Parameters of a first-order transition, as used by gravitational-wave templates
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transition {
+    pub t_c: f64,
+    pub t_n: f64,
+    /// S_3/T at T_n
+    pub action_over_t: f64,
+    /// Phases at T_n
+    pub phases: Phases,
+    /// Strength `(ΔV - T/4 ∂ΔV/∂T) / ρ_rad` at T_n, from the trace of the energy-momentum tensor
+    pub alpha: f64,
+    /// Inverse duration in Hubble units, `T d(S_3/T)/dT` at T_n
+    pub beta_over_h: f64,
+}
+
+impl Transition {
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\n  \"t_c\": {},\n  \"t_n\": {},\n  \"action_over_t\": {},\n  \"phi_false\": {},\n  \"phi_true\": {},\n  \"delta_v\": {},\n  \"alpha\": {},\n  \"beta_over_h\": {}\n}}\n",
+            json_number(self.t_c),
+            json_number(self.t_n),
+            json_number(self.action_over_t),
+            json_number(self.phases.phi_false),
+            json_number(self.phases.phi_true),
+            json_number(self.phases.delta_v),
+            json_number(self.alpha),
+            json_number(self.beta_over_h),
+        )
+    }
+}
+
+impl fmt::Display for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "critical temperature   T_c   = {:.6e}", self.t_c)?;
+        writeln!(f, "nucleation temperature T_n   = {:.6e}", self.t_n)?;
+        writeln!(
+            f,
+            "S_3/T at T_n                 = {:.4}",
+            self.action_over_t
+        )?;
+        writeln!(
+            f,
+            "phases at T_n                = {:.6} -> {:.6} (delta_v {:.4e})",
+            self.phases.phi_false, self.phases.phi_true, self.phases.delta_v
+        )?;
+        writeln!(f, "alpha                        = {:.4e}", self.alpha)?;
+        write!(f, "beta/H                       = {:.4e}", self.beta_over_h)
+    }
+}
+
+/// Central difference of `f` at `t`, falling back to a one-sided one when `f` fails on a side
+fn derivative(f: &dyn Fn(f64) -> Result<f64, String>, t: f64, h: f64) -> Result<f64, String> {
+    match (f(t + h), f(t - h)) {
+        (Ok(up), Ok(down)) => Ok((up - down) / (2f64 * h)),
+        (Err(_), Ok(down)) => Ok((f(t)? - down) / h),
+        (Ok(up), Err(_)) => Ok((up - f(t)?) / h),
+        (Err(e), Err(_)) => Err(e),
+    }
+}
+
+/// T_c, T_n, α and β/H, with T_c searched on the increasing grid `temps`
+pub fn find_transition(
+    potential: &dyn ThermalModel,
+    temps: &[f64],
+    opts: &TransitionOptions,
+) -> Result<Transition, String> {
+    let t_c = critical_temperature(potential, temps, opts.tol)?;
+    let t_n = nucleation_temperature(potential, t_c, temps, opts)?;
+    let phases = potential
+        .phases(t_n)
+        .ok_or_else(|| format!("there are no two phases at T_n = {}", t_n))?;
+    let h = opts.step * (t_c - t_n).min(t_n);
+
+    let s = |t: f64| action_over_t(potential, t, opts.bounce);
+    let ds = derivative(&s, t_n, h).map_err(|e| format!("no derivative of S_3/T at T_n: {}", e))?;
+
+    let delta_v = |t: f64| {
+        potential
+            .phases(t)
+            .map(|p| p.delta_v)
+            .ok_or_else(|| format!("there are no two phases at T = {}", t))
+    };
+    let d_delta_v = derivative(&delta_v, t_n, h)?;
+    let rho_rad = PI * PI * opts.g_star * t_n.powi(4) / 30f64;
+
+    Ok(Transition {
+        t_c,
+        t_n,
+        action_over_t: s(t_n)?,
+        phases,
+        alpha: (phases.delta_v - t_n / 4f64 * d_delta_v) / rho_rad,
+        beta_over_h: t_n * ds,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bounce::solve_bounce;
+
+    /// `V = D (T² - T_0²) φ² - E T φ³ + λ/4 φ⁴`, the classic high-temperature quartic
+    struct Quartic {
+        d: f64,
+        e: f64,
+        lambda: f64,
+        t0: f64,
+    }
+
+    impl Quartic {
+        fn v(&self, phi: f64, t: f64) -> f64 {
+            let phi2 = phi * phi;
+            phi2 * (self.d * (t * t - self.t0 * self.t0) - self.e * t * phi
+                + self.lambda / 4f64 * phi2)
+        }
+
+        fn dv(&self, phi: f64, t: f64) -> f64 {
+            phi * (2f64 * self.d * (t * t - self.t0 * self.t0) - 3f64 * self.e * t * phi
+                + self.lambda * phi * phi)
+        }
+
+        /// The broken minimum, where it exists
+        fn phi_true(&self, t: f64) -> Option<f64> {
+            let disc = 9f64 * (self.e * t).powi(2)
+                - 8f64 * self.lambda * self.d * (t * t - self.t0 * self.t0);
+            (disc >= 0f64).then(|| (3f64 * self.e * t + disc.sqrt()) / (2f64 * self.lambda))
+        }
+    }
+
+    impl ThermalModel for Quartic {
+        fn phases(&self, t: f64) -> Option<Phases> {
+            let phi_true = self.phi_true(t)?;
+            (t > self.t0).then(|| Phases {
+                phi_false: 0f64,
+                phi_true,
+                delta_v: -self.v(phi_true, t),
+            })
+        }
+
+        fn bounce(&self, t: f64, opts: BounceOptions) -> Result<Bounce, String> {
+            let phi_true = self.phi_true(t).ok_or("no broken phase")?;
+            let v = |phi: f64| self.v(phi, t);
+            let dv = |phi: f64| self.dv(phi, t);
+            solve_bounce(&v, &dv, 0f64, phi_true, opts)
+        }
+    }
+
+    #[test]
+    fn quartic_transition_matches_analytic_values() {
+        let quartic = Quartic {
+            d: 0.1,
+            e: 0.01,
+            lambda: 0.1,
+            t0: 100f64,
+        };
+        let opts = TransitionOptions::default();
+        let temps: Vec<f64> = (0..=40).map(|i| 100.01 + 0.02 * i as f64).collect();
+        let transition = find_transition(&quartic, &temps, &opts).unwrap();
+
+        // Degenerate phases where φ_true = 2 E T / λ, at T_c = T_0 / sqrt(1 - E² / (λ D))
+        let t_c = quartic.t0 / (1f64 - quartic.e.powi(2) / (quartic.lambda * quartic.d)).sqrt();
+        assert!((transition.t_c - t_c).abs() < 1e-6 * t_c);
+
+        // φ_false = 0 stays put and V' = 0 at φ_true, so dΔV/dT = -∂V/∂T at φ_true
+        let t_n = transition.t_n;
+        assert!(t_n > quartic.t0 && t_n < t_c);
+        assert!((transition.action_over_t - opts.target).abs() < 1e-3 * opts.target);
+        let phi = quartic.phi_true(t_n).unwrap();
+        let delta_v = -quartic.v(phi, t_n);
+        let d_delta_v = -(2f64 * quartic.d * t_n * phi * phi - quartic.e * phi.powi(3));
+        let rho_rad = PI * PI * opts.g_star * t_n.powi(4) / 30f64;
+        let alpha = (delta_v - t_n / 4f64 * d_delta_v) / rho_rad;
+        assert!((transition.phases.delta_v - delta_v).abs() < 1e-9 * delta_v);
+        assert!(
+            (transition.alpha - alpha).abs() < 1e-5 * alpha,
+            "{} vs {}",
+            transition.alpha,
+            alpha
+        );
+
+        // S_3/T grows towards T_c, roughly as (T_c - T)^-2 in the thin-wall limit
+        let thin_wall = 2f64 * opts.target * t_n / (t_c - t_n);
+        assert!(transition.beta_over_h > 0f64);
+        assert!(
+            transition.beta_over_h > 0.5 * thin_wall && transition.beta_over_h < 2f64 * thin_wall
+        );
+    }
+}
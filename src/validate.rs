@@ -0,0 +1,359 @@
+use crate::batch::BatchEvaluator;
+use crate::dataset::Dataset;
+use crate::features::Acceptance;
+use crate::grid::GridSpec;
+use crate::precision::curve_precision;
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+/// Problems found in at most this many samples are listed individually
+const MAX_EXAMPLES: usize = 10;
+
+/// Independent check of a generated dataset
+///
+/// Every curve is recomputed from its parameters with the closed forms `c1_potential` and
+/// `c1_deriv` (not the batch path of the generator) and compared within `tol`, relative to
+/// `1 + |V|`. The acceptance criteria are re-checked on the screening grid of the run.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub n_samples: usize,
+    /// Samples with a NaN or infinite parameter, value or weight
+    pub n_nonfinite: usize,
+    /// Samples whose stored curves differ from the recomputed ones
+    pub n_mismatch: usize,
+    /// Samples failing the acceptance criteria
+    pub n_rejected: usize,
+    /// Samples whose parameters repeat an earlier sample
+    pub n_duplicates: usize,
+    /// Samples with a negative weight or a per-sample grid that is not increasing in [0, 1]
+    pub n_invalid: usize,
+    /// Samples whose stored curve has a round-off above the precision tolerance; flagged
+    /// without failing the check
+    pub n_unreliable: usize,
+    pub max_err_v: f64,
+    pub max_err_dv: f64,
+    /// Problems with the file as a whole
+    pub errors: Vec<String>,
+    /// Non-fatal findings
+    pub warnings: Vec<String>,
+    /// First problems found, by row
+    pub examples: Vec<String>,
+}
+
+impl ValidationReport {
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+            && self.n_nonfinite == 0
+            && self.n_mismatch == 0
+            && self.n_rejected == 0
+            && self.n_duplicates == 0
+            && self.n_invalid == 0
+    }
+
+    fn example(&mut self, row: usize, message: String) {
+        if self.examples.len() < MAX_EXAMPLES {
+            self.examples.push(format!("row {}: {}", row, message));
+        }
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "samples:        {}", self.n_samples)?;
+        writeln!(f, "non-finite:     {}", self.n_nonfinite)?;
+        writeln!(
+            f,
+            "mismatched:     {} (max error V {:.2e}, V' {:.2e})",
+            self.n_mismatch, self.max_err_v, self.max_err_dv
+        )?;
+        writeln!(f, "rejected:       {}", self.n_rejected)?;
+        writeln!(f, "duplicates:     {}", self.n_duplicates)?;
+        writeln!(f, "invalid:        {}", self.n_invalid)?;
+        writeln!(f, "unreliable:     {}", self.n_unreliable)?;
+        for e in &self.errors {
+            writeln!(f, "error: {}", e)?;
+        }
+        for w in &self.warnings {
+            writeln!(f, "warning: {}", w)?;
+        }
+        for e in &self.examples {
+            writeln!(f, "  {}", e)?;
+        }
+        write!(f, "{}", if self.is_ok() { "OK" } else { "FAILED" })
+    }
+}
+
+/// Findings for one sample; only `nonfinite` is checked when it is set
+struct Check {
+    nonfinite: bool,
+    err_v: f64,
+    err_dv: f64,
+    accepted: bool,
+    invalid: Option<String>,
+    /// Relative round-off of the stored curve, when above the precision tolerance
+    unreliable: Option<f64>,
+}
+
+/// Validate the dataset at `path`; an unreadable file or wrong schema is an error
+///
+/// Curves whose round-off against the double-double reference exceeds `precision_tol` are
+/// counted as unreliable.
+pub fn validate(
+    path: &str,
+    tol: f64,
+    precision_tol: f64,
+    acceptance: Acceptance,
+) -> Result<ValidationReport, Box<dyn Error>> {
+    let dataset = Dataset::open(path)?;
+    let mut report = ValidationReport::default();
+
+    let grid: Option<GridSpec> = match dataset.metadata("bounce.grid").map(|g| g.parse()) {
+        Some(Ok(grid)) => Some(grid),
+        Some(Err(e)) => {
+            report.errors.push(format!("invalid bounce.grid: {}", e));
+            None
+        }
+        None => {
+            report.errors.push("missing bounce.grid".to_string());
+            None
+        }
+    };
+    if let Some(grid) = grid {
+        if grid.len() != dataset.grid_len() {
+            report.errors.push(format!(
+                "bounce.grid {} does not match the {} grid columns",
+                grid,
+                dataset.grid_len()
+            ));
+        }
+        if grid.is_shared() == dataset.has_per_sample_grid() {
+            report.errors.push(format!(
+                "bounce.grid {} does not match the grid columns",
+                grid
+            ));
+        }
+        if grid.is_shared()
+            && dataset
+                .grid()
+                .is_none_or(|nodes| nodes.iter().zip(grid.nodes()).any(|(a, b)| *a != b))
+        {
+            report
+                .errors
+                .push("bounce.grid.nodes do not match bounce.grid".to_string());
+        }
+    }
+    if !report.errors.is_empty() {
+        return Ok(report);
+    }
+    let grid = grid.unwrap();
+    let screen = BatchEvaluator::new(&grid.nodes());
+    let shared_nodes = dataset.grid().map(|g| g.to_vec());
+
+    // A file of a split run holds only part of the requested samples
+    if let (Some(n), None) = (
+        dataset.metadata("bounce.n_samples"),
+        dataset.metadata("bounce.split.part"),
+    ) {
+        if n != dataset.len().to_string() {
+            report.warnings.push(format!(
+                "{} samples requested, {} in the file",
+                n,
+                dataset.len()
+            ));
+        }
+    }
+
+    // Metropolis chains repeat their state after a rejected proposal
+    let repeats_expected = dataset.metadata("bounce.mcmc").is_some();
+    let mut n_repeated = 0;
+    let mut seen: HashSet<[u64; 4]> = HashSet::with_capacity(dataset.len());
+    let mut row = 0;
+    for records in dataset.row_groups() {
+        let records = records?;
+        let checks: Vec<Check> = records
+            .par_iter()
+            .map(|r| {
+                let s = &r.sample;
+                let phi = s.grid.as_ref().or(shared_nodes.as_ref()).unwrap();
+                let params = s.params.to_array();
+                let nonfinite = params
+                    .iter()
+                    .chain(phi)
+                    .chain(&s.v)
+                    .chain(&s.dv)
+                    .chain([&s.weight])
+                    .any(|x| !x.is_finite());
+                // The curve checks below are meaningless on NaN or infinite values
+                if nonfinite {
+                    return Check {
+                        nonfinite,
+                        err_v: 0f64,
+                        err_dv: 0f64,
+                        accepted: true,
+                        invalid: None,
+                        unreliable: None,
+                    };
+                }
+
+                let (v, dv) = (s.params.potential(), s.params.deriv());
+                let err = |stored: &[f64], f: &dyn Fn(f64) -> f64| {
+                    stored
+                        .iter()
+                        .zip(phi)
+                        .map(|(&y, &x)| {
+                            let y_ref = f(x);
+                            (y - y_ref).abs() / (1f64 + y_ref.abs())
+                        })
+                        .fold(0f64, f64::max)
+                };
+                let (err_v, err_dv) = (err(&s.v, &v), err(&s.dv, &dv));
+
+                // The generator screens on the shared grid; per-sample grids are screened on its uniform version
+                let accepted = if s.grid.is_none() {
+                    acceptance.accepts(&s.v)
+                } else {
+                    acceptance.accepts(&screen.eval(&[s.params]).v.row(0))
+                };
+
+                let invalid = if s.weight < 0f64 {
+                    Some(format!("negative weight {}", s.weight))
+                } else if s.grid.is_some()
+                    && (phi.windows(2).any(|w| w[0] >= w[1])
+                        || phi[0] < 0f64
+                        || phi[phi.len() - 1] > 1f64)
+                {
+                    Some("grid is not increasing in [0, 1]".to_string())
+                } else {
+                    None
+                };
+
+                let precision = curve_precision(&s.params, phi, &s.v, &s.dv);
+                let unreliable = (!precision.is_reliable(precision_tol))
+                    .then(|| precision.rel_err.max(precision.rel_err_deriv));
+
+                Check {
+                    nonfinite,
+                    err_v,
+                    err_dv,
+                    accepted,
+                    invalid,
+                    unreliable,
+                }
+            })
+            .collect();
+
+        for (r, check) in records.iter().zip(checks) {
+            if check.nonfinite {
+                report.n_nonfinite += 1;
+                report.example(row, "non-finite value".to_string());
+            }
+            if check.err_v.is_finite() {
+                report.max_err_v = report.max_err_v.max(check.err_v);
+            }
+            if check.err_dv.is_finite() {
+                report.max_err_dv = report.max_err_dv.max(check.err_dv);
+            }
+            if check.err_v > tol || check.err_dv > tol {
+                report.n_mismatch += 1;
+                report.example(
+                    row,
+                    format!(
+                        "curve differs by {:.2e} (V) / {:.2e} (V')",
+                        check.err_v, check.err_dv
+                    ),
+                );
+            }
+            if !check.accepted {
+                report.n_rejected += 1;
+                report.example(row, "fails the acceptance criteria".to_string());
+            }
+            if let Some(message) = check.invalid {
+                report.n_invalid += 1;
+                report.example(row, message);
+            }
+            if let Some(err) = check.unreliable {
+                report.n_unreliable += 1;
+                report.example(row, format!("round-off of the stored curve {:.2e}", err));
+            }
+            if !seen.insert(r.sample.params.to_array().map(f64::to_bits)) {
+                if repeats_expected {
+                    n_repeated += 1;
+                } else {
+                    report.n_duplicates += 1;
+                    report.example(
+                        row,
+                        format!("duplicate parameters {:?}", r.sample.params.to_array()),
+                    );
+                }
+            }
+            row += 1;
+        }
+    }
+    report.n_samples = row;
+    if n_repeated > 0 {
+        report.warnings.push(format!(
+            "{} repeated Markov chain states (rejected proposals)",
+            n_repeated
+        ));
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RunConfig;
+    use crate::generate::generate;
+    use crate::output::{Codec, SampleWriter};
+    use crate::precision::RELIABLE_TOL;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn counts_nan_sample_as_nonfinite() {
+        let dir = TempDir::new("validate-nan");
+        let clean = dir.file("clean.parquet");
+        let config = RunConfig {
+            n_samples: 20,
+            output: clean.clone(),
+            ..RunConfig::default()
+        };
+        generate(&config).unwrap();
+
+        // Copy of the dataset with one NaN inside an otherwise accepted curve
+        let dataset = Dataset::open(&clean).unwrap();
+        let mut records = dataset.load().unwrap();
+        records[3].sample.v[10] = f64::NAN;
+        let path = dir.file("nan.parquet");
+        let mut writer = SampleWriter::create(
+            &path,
+            dataset.grid_len(),
+            false,
+            dataset.labels(),
+            Codec::Uncompressed.options(),
+            100,
+        )
+        .unwrap();
+        for r in &records {
+            writer.push(&r.sample, &r.labels).unwrap();
+        }
+        let metadata = dataset
+            .metadata_entries()
+            .iter()
+            .filter(|(k, _)| k != "bounce.schema_version")
+            .cloned()
+            .collect();
+        writer.finish(metadata).unwrap();
+
+        assert!(validate(&clean, 1e-6, RELIABLE_TOL, Acceptance::default())
+            .unwrap()
+            .is_ok());
+        let report = validate(&path, 1e-6, RELIABLE_TOL, Acceptance::default()).unwrap();
+        assert_eq!(report.n_samples, 20);
+        assert_eq!(report.n_nonfinite, 1);
+        assert_eq!(report.n_rejected, 0);
+        assert_eq!(report.n_mismatch, 0);
+        assert!(!report.is_ok());
+    }
+}